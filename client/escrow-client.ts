@@ -60,6 +60,9 @@ export enum Instruction {
   // SPL Token escrow operations
   CreateTokenEscrow = 25,
   ReleaseTokensToWorker = 26,
+  // Platform configuration
+  InitPlatformConfig = 82,
+  SetFeeBps = 83,
 }
 
 // Account sizes
@@ -85,6 +88,13 @@ export function findEscrowPDA(jobIdHash: Buffer, poster: PublicKey): [PublicKey,
   );
 }
 
+export function findPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('platform_config')],
+    PROGRAM_ID
+  );
+}
+
 export function findReputationPDA(agent: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from('reputation'), agent.toBuffer()],
@@ -153,6 +163,7 @@ export function createEscrowInstruction(
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -176,6 +187,8 @@ export function assignWorkerInstruction(
     keys: [
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: false },
+      { pubkey: worker, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -227,6 +240,7 @@ export function releaseToWorkerInstruction(
       { pubkey: platformAuthority, isSigner: true, isWritable: true },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: PLATFORM_WALLET, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -251,6 +265,7 @@ export function approveWorkInstruction(
       { pubkey: poster, isSigner: true, isWritable: true },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: PLATFORM_WALLET, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -275,6 +290,7 @@ export function autoReleaseInstruction(
       { pubkey: cranker, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: PLATFORM_WALLET, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -432,6 +448,7 @@ export function releaseWithReputationInstruction(
       { pubkey: PLATFORM_WALLET, isSigner: false, isWritable: true },
       { pubkey: posterReputation, isSigner: false, isWritable: true },
       { pubkey: workerReputation, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -635,7 +652,7 @@ export function updateArbitratorAccuracyInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: disputeCase, isSigner: false, isWritable: false },
+      { pubkey: disputeCase, isSigner: false, isWritable: true },
       { pubkey: arbitratorEntry, isSigner: false, isWritable: true },
       { pubkey: vote, isSigner: false, isWritable: false },
       { pubkey: accuracyClaim, isSigner: false, isWritable: true },
@@ -753,6 +770,7 @@ export function closeArbitratorAccountInstruction(
  * 4. escrow_token_account (writable) - escrow's ATA for the token
  * 5. system_program
  * 6. token_program
+ * 7. platform_config (readonly) - creation fails while the platform is paused
  * 
  * Data: job_id_hash (32) + amount (8) + expiry_seconds (8)
  */
@@ -781,6 +799,7 @@ export function createTokenEscrowInstruction(
       { pubkey: escrowTokenAccount, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -826,6 +845,62 @@ export function releaseTokensToWorkerInstruction(
   });
 }
 
+/**
+ * Platform creates the platform config (PLATFORM_WALLET only)
+ * Accounts:
+ * 0. platform_config (PDA, writable)
+ * 1. platform_authority (signer, writable) - pays rent
+ * 2. system_program
+ *
+ * Data: fee_bps (2, optional; defaults to 100 = 1%)
+ */
+export function initPlatformConfigInstruction(
+  platformAuthority: PublicKey,
+  feeBps?: number,
+): TransactionInstruction {
+  const data = Buffer.alloc(feeBps === undefined ? 1 : 3);
+  data.writeUInt8(Instruction.InitPlatformConfig, 0);
+  if (feeBps !== undefined) {
+    data.writeUInt16LE(feeBps, 1);
+  }
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: true },
+      { pubkey: platformAuthority, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId: PROGRAM_ID,
+    data,
+  });
+}
+
+/**
+ * Platform sets the fee on SOL releases (at most 1000 = 10%)
+ * Accounts:
+ * 0. platform_config (PDA, writable)
+ * 1. platform_authority (signer)
+ *
+ * Data: fee_bps (2)
+ */
+export function setFeeBpsInstruction(
+  platformAuthority: PublicKey,
+  feeBps: number,
+): TransactionInstruction {
+  const data = Buffer.alloc(1 + 2);
+  data.writeUInt8(Instruction.SetFeeBps, 0);
+  data.writeUInt16LE(feeBps, 1);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: true },
+      { pubkey: platformAuthority, isSigner: true, isWritable: false },
+    ],
+    programId: PROGRAM_ID,
+    data,
+  });
+}
+
 // ============================================================================
 // High-Level Client Class
 // ============================================================================
@@ -871,11 +946,11 @@ export class EscrowClient {
   async assignWorker(
     poster: Keypair,
     escrow: PublicKey,
-    worker: PublicKey,
+    worker: Keypair,
   ): Promise<string> {
-    const ix = assignWorkerInstruction(escrow, poster.publicKey, worker);
+    const ix = assignWorkerInstruction(escrow, poster.publicKey, worker.publicKey);
     const tx = new Transaction().add(ix);
-    return sendAndConfirmTransaction(this.connection, tx, [poster], {
+    return sendAndConfirmTransaction(this.connection, tx, [poster, worker], {
       commitment: this.commitment,
     });
   }
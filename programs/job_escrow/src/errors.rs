@@ -100,6 +100,126 @@ pub enum EscrowError {
     NotTokenEscrow = 6044,
     /// Token account does not match escrow
     TokenAccountMismatch = 6045,
+    /// No arbitrators in the pool have opted in to this escrow's category
+    NoEligibleArbitratorsForCategory = 6046,
+    /// Platform fee token account is not owned by the platform wallet or vault
+    InvalidFeeRecipient = 6047,
+    /// Payout amounts do not sum to the escrowed amount
+    PayoutMismatch = 6048,
+    /// Instruction only supports SOL escrows
+    UnsupportedTokenEscrow = 6049,
+    /// Voting deadline has already been extended
+    VotingAlreadyExtended = 6050,
+    /// Voting extension must be positive and within the allowed maximum
+    InvalidVotingExtension = 6051,
+    /// Delegate must be an outside arbitrator not already holding a delegation
+    InvalidDelegate = 6052,
+    /// This arbitrator's vote is already delegated
+    VoteAlreadyDelegated = 6053,
+    /// Worker has not posted the required deposit
+    WorkerDepositNotPosted = 6054,
+    /// Escrow does not require a worker deposit, or it is already posted
+    WorkerDepositNotExpected = 6055,
+    /// Too late in the review window to resubmit work
+    ResubmissionWindowClosed = 6056,
+    /// Account has been closed
+    AccountClosed = 6057,
+    /// Fee or bounty basis points exceed the allowed maximum
+    InvalidFeeBps = 6058,
+    /// Submitted job_id does not hash to the escrow's job_id_hash
+    JobIdMismatch = 6059,
+    /// An account the instruction modifies was passed as read-only
+    AccountNotWritable = 6060,
+    /// Milestone index or schedule is invalid
+    InvalidMilestone = 6061,
+    /// Milestone has already been released
+    MilestoneAlreadyReleased = 6062,
+    /// Cannot cancel while the post-bid cancel grace is running
+    CancelGraceActive = 6063,
+    /// Worker chose arbitration over auto-release for this escrow
+    AutoArbitrationEnabled = 6064,
+    /// Voters still have vote fees to claim from this dispute case
+    VoteFeesUnclaimed = 6065,
+    /// Worker cannot be the default pubkey
+    InvalidWorker = 6066,
+    /// The platform config must be marked retiring first
+    PlatformNotRetiring = 6067,
+    /// Arbitrator voted on this case, so there is no absence to record
+    ArbitratorVoted = 6068,
+    /// Worker already posted a deposit; use abandon_assignment instead
+    WorkerDepositAlreadyPosted = 6069,
+    /// Disputed amount must be non-zero and at most what the escrow still holds
+    InvalidDisputedAmount = 6070,
+    /// Revision limit reached; the poster must approve or dispute
+    RevisionLimitReached = 6071,
+    /// Arbitrators have not been revealed for this dispute yet
+    ArbitratorsNotRevealed = 6072,
+    /// Arbitrators were already revealed for this dispute
+    ArbitratorsAlreadyRevealed = 6073,
+    /// The committed reveal slot has not been reached
+    RevealSlotNotReached = 6074,
+    /// Token account has not been initialized
+    TokenAccountNotInitialized = 6075,
+    /// Token account holds a different mint
+    TokenMintMismatch = 6076,
+    /// Token account is not owned by the expected authority
+    TokenAccountOwnerMismatch = 6077,
+    /// Release would pay out more than the escrow holds
+    ReleaseExceedsEscrow = 6078,
+    /// Worker has already submitted work or been paid on this escrow
+    WorkAlreadySubmitted = 6079,
+    /// Withdrawal exceeds the stake held above the locked minimum
+    InsufficientExcessStake = 6080,
+    /// The platform wallet cannot be assigned as a worker
+    PlatformWorkerNotAllowed = 6081,
+    /// Worker share must be at most 10000 basis points
+    InvalidWorkerShare = 6082,
+    /// No delivery deadline was set, it has not passed, or work was submitted
+    DeliveryDeadlineNotMissed = 6083,
+    /// Dispute is not Disputed, or its abandonment window has not passed
+    DisputeNotAbandoned = 6084,
+    /// Worker-initiated disputes must be settled through arbitration
+    WorkerDisputeNeedsArbitration = 6085,
+    /// The claim's dispute case must be closed first
+    DisputeCaseNotClosed = 6086,
+    /// Referrer is missing, does not match the escrow, or is the poster
+    InvalidReferrer = 6087,
+    /// Arbitrator has too few judged votes, or accuracy at or above the minimum
+    AccuracyAboveThreshold = 6088,
+    /// This case takes votes by commit and reveal
+    VoteCommitRequired = 6089,
+    /// Vote commitments are closed for this case
+    CommitPhaseClosed = 6090,
+    /// Votes can only be revealed once commitments have closed
+    RevealPhaseNotOpen = 6091,
+    /// No vote commitment was recorded for this arbitrator
+    VoteNotCommitted = 6092,
+    /// Revealed vote and salt do not match the commitment
+    VoteCommitmentMismatch = 6093,
+    /// Worker's reputation score is below the escrow's minimum
+    InsufficientReputation = 6094,
+    /// Dispute is not resolved, its appeal window has closed, or it was already appealed
+    NotAppealable = 6095,
+    /// The losing party may still appeal this dispute
+    AppealWindowOpen = 6096,
+    /// No appeal bond is held on this dispute
+    NoAppealBond = 6097,
+    /// The appeal bond must be settled first
+    AppealBondUnsettled = 6098,
+    /// Arbitrator recused themselves from this case
+    ArbitratorRecused = 6099,
+    /// The platform is paused: no new escrows or releases
+    ProgramPaused = 6100,
+    /// Signer is not the pending platform authority, or no transfer is pending
+    NotPendingAuthority = 6101,
+    /// The platform authority was rotated away from the platform wallet
+    AuthorityRotated = 6102,
+    /// Arbitrator still has voted cases awaiting judgement
+    ArbitratorHasOpenCases = 6103,
+    /// Escrow still holds the worker's bond
+    WorkerBondHeld = 6104,
+    /// Dispute case still has recorded votes awaiting judgement
+    VotesUnjudged = 6105,
 }
 
 impl From<EscrowError> for ProgramError {
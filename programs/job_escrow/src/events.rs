@@ -0,0 +1,885 @@
+//! Program events
+//!
+//! Events are logged with `sol_log_data` as a single segment: an 8-byte ASCII
+//! discriminator followed by the event fields packed in declaration order
+//! (pubkeys as 32 raw bytes, integers little-endian). Indexers decode them from
+//! the `Program data:` log lines.
+
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+
+/// Fixed-size event buffer writer
+struct EventWriter<const N: usize> {
+    buf: [u8; N],
+    pos: usize,
+}
+
+impl<const N: usize> EventWriter<N> {
+    #[inline(always)]
+    fn new(discriminator: &[u8; 8]) -> Self {
+        let mut writer = Self { buf: [0u8; N], pos: 0 };
+        writer.put(discriminator);
+        writer
+    }
+
+    #[inline(always)]
+    fn put(&mut self, bytes: &[u8]) -> &mut Self {
+        self.buf[self.pos..self.pos + bytes.len()].copy_from_slice(bytes);
+        self.pos += bytes.len();
+        self
+    }
+
+    #[inline(always)]
+    fn finish(self) -> [u8; N] {
+        debug_assert!(self.pos == N);
+        self.buf
+    }
+}
+
+// ============== ESCROW CREATED ==============
+
+/// Emitted when a poster creates and funds an escrow
+pub struct EscrowCreated {
+    pub escrow: Pubkey,
+    pub poster: Pubkey,
+    /// Escrowed mint (all zeroes for SOL escrows)
+    pub token_mint: Pubkey,
+    pub amount: u64,
+    pub expires_at: i64,
+    /// Bond the worker must lock (0 = none)
+    pub worker_deposit: u64,
+    pub category: u8,
+}
+
+impl EscrowCreated {
+    pub const DISCRIMINATOR: [u8; 8] = *b"EscCreat";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.poster)
+            .put(&self.token_mint)
+            .put(&self.amount.to_le_bytes())
+            .put(&self.expires_at.to_le_bytes())
+            .put(&self.worker_deposit.to_le_bytes())
+            .put(&[self.category]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== WORKER ASSIGNED ==============
+
+/// Emitted when a worker is assigned to an escrow
+pub struct WorkerAssigned {
+    pub escrow: Pubkey,
+    pub worker: Pubkey,
+    pub assigned_by: Pubkey,
+}
+
+impl WorkerAssigned {
+    pub const DISCRIMINATOR: [u8; 8] = *b"WrkAssgn";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 32;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.worker)
+            .put(&self.assigned_by);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== WORK SUBMITTED ==============
+
+/// Emitted when the worker submits work and the review window opens
+pub struct WorkSubmitted {
+    pub escrow: Pubkey,
+    pub worker: Pubkey,
+    pub submitted_at: i64,
+    /// Number of submissions so far, including this one
+    pub review_cycles: u8,
+}
+
+impl WorkSubmitted {
+    pub const DISCRIMINATOR: [u8; 8] = *b"WorkSubm";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.worker)
+            .put(&self.submitted_at.to_le_bytes())
+            .put(&[self.review_cycles]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== FUNDS RELEASED ==============
+
+/// Emitted on every payment to the worker outside dispute execution
+pub struct FundsReleased {
+    pub escrow: Pubkey,
+    pub worker: Pubkey,
+    /// Gross amount paid out of the escrow by this release
+    pub amount: u64,
+    pub worker_payment: u64,
+    /// Platform fee, including any crank bounty carved out of it
+    pub platform_fee: u64,
+    /// The escrow is now Released (false for a partial or milestone release)
+    pub fully_released: bool,
+}
+
+impl FundsReleased {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FundRels";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.worker)
+            .put(&self.amount.to_le_bytes())
+            .put(&self.worker_payment.to_le_bytes())
+            .put(&self.platform_fee.to_le_bytes())
+            .put(&[self.fully_released as u8]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== DISPUTE RAISED ==============
+
+/// Emitted when a multi-arbitrator dispute case is opened
+pub struct DisputeRaised {
+    pub escrow: Pubkey,
+    pub dispute_case: Pubkey,
+    pub raised_by: Pubkey,
+    pub reason_category: u16,
+    pub voting_deadline: i64,
+    /// How many times work was submitted before the dispute
+    pub review_cycles: u8,
+}
+
+impl DisputeRaised {
+    pub const DISCRIMINATOR: [u8; 8] = *b"DispRais";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 2 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.dispute_case)
+            .put(&self.raised_by)
+            .put(&self.reason_category.to_le_bytes())
+            .put(&self.voting_deadline.to_le_bytes())
+            .put(&[self.review_cycles]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== ARBITRATORS REVEALED ==============
+
+/// Emitted when a dispute's panel is drawn and voting opens
+pub struct ArbitratorsRevealed {
+    pub dispute_case: Pubkey,
+    pub reveal_slot: u64,
+    pub voting_deadline: i64,
+}
+
+impl ArbitratorsRevealed {
+    pub const DISCRIMINATOR: [u8; 8] = *b"ArbRevld";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 8 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.reveal_slot.to_le_bytes())
+            .put(&self.voting_deadline.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== VOTE CAST ==============
+
+/// Emitted when an arbitrator (or their delegate) votes on a dispute case
+pub struct VoteCast {
+    pub dispute_case: Pubkey,
+    /// Selected arbitrator the vote counts for
+    pub arbitrator: Pubkey,
+    /// Vote value (see state::Vote)
+    pub vote: u8,
+    pub for_worker: u8,
+    pub for_poster: u8,
+}
+
+impl VoteCast {
+    pub const DISCRIMINATOR: [u8; 8] = *b"VoteCast";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.arbitrator)
+            .put(&[self.vote])
+            .put(&[self.for_worker])
+            .put(&[self.for_poster]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== DISPUTE FINALIZED ==============
+
+/// Emitted when a dispute case's outcome is decided
+pub struct DisputeFinalized {
+    pub dispute_case: Pubkey,
+    pub escrow: Pubkey,
+    /// Outcome (see state::DisputeResolution)
+    pub resolution: u8,
+    pub for_worker: u8,
+    pub for_poster: u8,
+}
+
+impl DisputeFinalized {
+    pub const DISCRIMINATOR: [u8; 8] = *b"DispFinl";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.escrow)
+            .put(&[self.resolution])
+            .put(&[self.for_worker])
+            .put(&[self.for_poster]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== DISPUTE APPEALED ==============
+
+/// Emitted when the losing party reopens a finalized dispute for a new panel
+pub struct DisputeAppealed {
+    pub dispute_case: Pubkey,
+    pub escrow: Pubkey,
+    pub appellant: Pubkey,
+    /// Resolution being appealed (see state::DisputeResolution)
+    pub appealed_resolution: u8,
+    pub panel_size: u8,
+    pub appeal_bond: u64,
+}
+
+impl DisputeAppealed {
+    pub const DISCRIMINATOR: [u8; 8] = *b"DispAppl";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + 1 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.escrow)
+            .put(&self.appellant)
+            .put(&[self.appealed_resolution])
+            .put(&[self.panel_size])
+            .put(&self.appeal_bond.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== RESOLUTION EXECUTED ==============
+
+/// Emitted when a finalized dispute's funds are paid out
+pub struct ResolutionExecuted {
+    pub dispute_case: Pubkey,
+    pub escrow: Pubkey,
+    pub resolution: u8,
+    /// Paid to the worker, including any undisputed remainder
+    pub worker_amount: u64,
+    pub poster_amount: u64,
+    pub platform_fee: u64,
+}
+
+impl ResolutionExecuted {
+    pub const DISCRIMINATOR: [u8; 8] = *b"ResExecd";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 8 + 8 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.escrow)
+            .put(&[self.resolution])
+            .put(&self.worker_amount.to_le_bytes())
+            .put(&self.poster_amount.to_le_bytes())
+            .put(&self.platform_fee.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== EMERGENCY REFUND EXECUTED ==============
+
+/// Emitted when the platform refunds a disputed escrow without the timelock
+pub struct EmergencyRefundExecuted {
+    pub escrow: Pubkey,
+    pub poster: Pubkey,
+    pub amount: u64,
+    pub dispute_initiated_at: i64,
+    pub executed_at: i64,
+}
+
+impl EmergencyRefundExecuted {
+    pub const DISCRIMINATOR: [u8; 8] = *b"EmrgRfnd";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 8 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.poster)
+            .put(&self.amount.to_le_bytes())
+            .put(&self.dispute_initiated_at.to_le_bytes())
+            .put(&self.executed_at.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== ASSIGNMENT DECLINED ==============
+
+/// Emitted when an assigned worker declines the job
+pub struct AssignmentDeclined {
+    pub escrow: Pubkey,
+    pub worker: Pubkey,
+    pub declined_at: i64,
+}
+
+impl AssignmentDeclined {
+    pub const DISCRIMINATOR: [u8; 8] = *b"AsgnDecl";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&self.worker)
+            .put(&self.declined_at.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== EXPIRY EXTENDED ==============
+
+/// Emitted when the poster pushes back an escrow's expiry
+pub struct ExpiryExtended {
+    pub escrow: Pubkey,
+    pub expires_at: i64,
+}
+
+impl ExpiryExtended {
+    pub const DISCRIMINATOR: [u8; 8] = *b"ExpryExt";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow).put(&self.expires_at.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== POOL HEALTH ==============
+
+/// Arbitrator pool snapshot logged by the get_pool_health view
+pub struct PoolHealth {
+    pub arbitrator_count: u32,
+    pub remaining_capacity: u32,
+    pub min_stake: u64,
+    pub meets_dispute_minimum: bool,
+}
+
+impl PoolHealth {
+    pub const DISCRIMINATOR: [u8; 8] = *b"PoolHlth";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 4 + 4 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.arbitrator_count.to_le_bytes())
+            .put(&self.remaining_capacity.to_le_bytes())
+            .put(&self.min_stake.to_le_bytes())
+            .put(&[self.meets_dispute_minimum as u8]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== ARBITRATOR ELIGIBILITY ==============
+
+/// Whether an arbitrator can act on a dispute case, logged by the emit_eligibility view
+pub struct ArbitratorEligibility {
+    pub dispute_case: Pubkey,
+    pub arbitrator: Pubkey,
+    /// Arbitrator holds one of the case's slots
+    pub selected: bool,
+    /// Arbitrator entry is active
+    pub active: bool,
+    /// Case is unresolved and the voting window is open
+    pub available: bool,
+    /// Arbitrator's slot already has a vote
+    pub has_voted: bool,
+}
+
+impl ArbitratorEligibility {
+    pub const DISCRIMINATOR: [u8; 8] = *b"ArbElig_";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 1 + 1 + 1;
+
+    /// All conditions for casting a vote now
+    #[inline(always)]
+    pub fn can_vote(&self) -> bool {
+        self.selected && self.active && self.available && !self.has_voted
+    }
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case)
+            .put(&self.arbitrator)
+            .put(&[
+                self.selected as u8,
+                self.active as u8,
+                self.available as u8,
+                self.has_voted as u8,
+                self.can_vote() as u8,
+            ]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== ESCROW AUDIT ==============
+
+/// Full timeline of an escrow logged by the emit_audit view
+///
+/// Timestamps are 0 and keys zeroed for steps the escrow has not reached.
+pub struct EscrowAudit {
+    pub escrow: Pubkey,
+    pub status: u8,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub first_bid_at: i64,
+    /// Assigned worker
+    pub worker: Pubkey,
+    pub submitted_at: i64,
+    pub review_cycles: u8,
+    pub revision_count: u8,
+    /// Legacy single-party dispute
+    pub dispute_initiated_at: i64,
+    pub dispute_case: Pubkey,
+    pub dispute_raised_at: i64,
+    pub voting_deadline: i64,
+    pub resolution: u8,
+}
+
+impl EscrowAudit {
+    pub const DISCRIMINATOR: [u8; 8] = *b"EscAudit";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8 + 8 + 32 + 8 + 1 + 1 + 8 + 32 + 8 + 8 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow)
+            .put(&[self.status])
+            .put(&self.created_at.to_le_bytes())
+            .put(&self.expires_at.to_le_bytes())
+            .put(&self.first_bid_at.to_le_bytes())
+            .put(&self.worker)
+            .put(&self.submitted_at.to_le_bytes())
+            .put(&[self.review_cycles, self.revision_count])
+            .put(&self.dispute_initiated_at.to_le_bytes())
+            .put(&self.dispute_case)
+            .put(&self.dispute_raised_at.to_le_bytes())
+            .put(&self.voting_deadline.to_le_bytes())
+            .put(&[self.resolution]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== FEE QUOTE ==============
+
+/// Platform fee a release of `amount` would be charged, logged by the quote_fee view
+pub struct FeeQuote {
+    pub amount: u64,
+    pub fee_bps: u16,
+    pub platform_fee: u64,
+    pub worker_payment: u64,
+}
+
+impl FeeQuote {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FeeQuote";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 8 + 2 + 8 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.amount.to_le_bytes())
+            .put(&self.fee_bps.to_le_bytes())
+            .put(&self.platform_fee.to_le_bytes())
+            .put(&self.worker_payment.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== SELECTABLE COUNT ==============
+
+/// How many arbitrators a new dispute could draw from, logged by the emit_selectable_count view
+pub struct SelectableCount {
+    pub category: u8,
+    pub selectable: u32,
+    /// Enough selectable arbitrators to raise a dispute case
+    pub can_raise_dispute: bool,
+}
+
+impl SelectableCount {
+    pub const DISCRIMINATOR: [u8; 8] = *b"SelCount";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 1 + 4 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&[self.category])
+            .put(&self.selectable.to_le_bytes())
+            .put(&[self.can_raise_dispute as u8]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== FINALIZE PREVIEW ==============
+
+/// Whether a dispute case can be finalized now, logged by the can_finalize view
+pub struct FinalizePreview {
+    pub dispute_case: Pubkey,
+    /// finalize_dispute_case would succeed now
+    pub can_finalize: bool,
+    /// One side has the votes needed to win
+    pub majority_reached: bool,
+    /// Voting deadline has passed
+    pub deadline_passed: bool,
+    pub for_worker: u8,
+    pub for_poster: u8,
+    /// Resolution finalizing now would record (the stored one once resolved)
+    pub resolution: u8,
+    /// Votes are tied and the platform breaks ties by reputation, so the
+    /// reputation accounts decide instead of `resolution`
+    pub tie_break_pending: bool,
+}
+
+impl FinalizePreview {
+    pub const DISCRIMINATOR: [u8; 8] = *b"FinPrevw";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 1 + 1 + 1 + 1 + 1 + 1 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.dispute_case).put(&[
+            self.can_finalize as u8,
+            self.majority_reached as u8,
+            self.deadline_passed as u8,
+            self.for_worker,
+            self.for_poster,
+            self.resolution,
+            self.tie_break_pending as u8,
+        ]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== AGENT ROLE ==============
+
+/// An agent's role in an escrow, logged by the get_my_role view
+pub struct AgentRole {
+    pub escrow: Pubkey,
+    pub agent: Pubkey,
+    /// See state::EscrowRole
+    pub role: u8,
+}
+
+impl AgentRole {
+    pub const DISCRIMINATOR: [u8; 8] = *b"AgntRole";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 32 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow).put(&self.agent).put(&[self.role]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== ESCROW SNAPSHOT ==============
+
+/// Stable subset of an escrow's state, returned by the get_escrow view
+///
+/// Unlike the account itself, this layout only changes with `VERSION`, so
+/// clients can decode it without tracking `JobEscrow`'s field order.
+///
+/// Layout (little-endian):
+/// ```text
+/// [0..8]     discriminator "EscState"
+/// [8]        version
+/// [9]        status (see state::EscrowStatus)
+/// [10]       is_token (0 = SOL, 1 = SPL token)
+/// [11..19]   amount: u64
+/// [19..51]   poster: Pubkey
+/// [51..83]   worker: Pubkey (zeroed until assigned)
+/// [83..91]   created_at: i64
+/// [91..99]   expires_at: i64
+/// [99..107]  submitted_at: i64 (0 until work is submitted)
+/// ```
+pub struct EscrowSnapshot {
+    pub status: u8,
+    pub is_token: bool,
+    pub amount: u64,
+    pub poster: Pubkey,
+    pub worker: Pubkey,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub submitted_at: i64,
+}
+
+impl EscrowSnapshot {
+    pub const DISCRIMINATOR: [u8; 8] = *b"EscState";
+
+    /// Layout version; bumped only on incompatible changes
+    pub const VERSION: u8 = 1;
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 1 + 1 + 1 + 8 + 32 + 32 + 8 + 8 + 8;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&[Self::VERSION, self.status, self.is_token as u8])
+            .put(&self.amount.to_le_bytes())
+            .put(&self.poster)
+            .put(&self.worker)
+            .put(&self.created_at.to_le_bytes())
+            .put(&self.expires_at.to_le_bytes())
+            .put(&self.submitted_at.to_le_bytes());
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+// ============== PROOF VERIFIED ==============
+
+/// Whether a preimage hashes to an escrow's proof_hash, logged by the verify_proof view
+pub struct ProofVerified {
+    pub escrow: Pubkey,
+    /// The worker committed a proof hash when submitting
+    pub has_proof_hash: bool,
+    /// sha256 of the preimage equals the committed proof hash
+    pub matches: bool,
+}
+
+impl ProofVerified {
+    pub const DISCRIMINATOR: [u8; 8] = *b"PrfVerif";
+
+    /// Encoded size including discriminator
+    pub const LEN: usize = 8 + 32 + 1 + 1;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut w = EventWriter::<{ Self::LEN }>::new(&Self::DISCRIMINATOR);
+        w.put(&self.escrow).put(&[self.has_proof_hash as u8, self.matches as u8]);
+        w.finish()
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispute_raised_encoding() {
+        let event = DisputeRaised {
+            escrow: [1u8; 32],
+            dispute_case: [2u8; 32],
+            raised_by: [3u8; 32],
+            reason_category: 3,
+            voting_deadline: 1_700_000_000,
+            review_cycles: 2,
+        };
+        let bytes = event.to_bytes();
+        assert_eq!(&bytes[..8], b"DispRais");
+        assert_eq!(&bytes[8..40], &[1u8; 32]);
+        assert_eq!(&bytes[72..104], &[3u8; 32]);
+        assert_eq!(u16::from_le_bytes([bytes[104], bytes[105]]), 3);
+        assert_eq!(i64::from_le_bytes(bytes[106..114].try_into().unwrap()), 1_700_000_000);
+        assert_eq!(bytes[114], 2);
+    }
+
+    #[test]
+    fn test_lifecycle_event_layouts() {
+        let created = EscrowCreated {
+            escrow: [1u8; 32],
+            poster: [2u8; 32],
+            token_mint: [0u8; 32],
+            amount: 50_000_000,
+            expires_at: 1_700_000_000,
+            worker_deposit: 0,
+            category: 4,
+        };
+        let bytes = created.to_bytes();
+        assert_eq!(bytes.len(), EscrowCreated::LEN);
+        assert_eq!(&bytes[..8], b"EscCreat");
+        assert_eq!(u64::from_le_bytes(bytes[104..112].try_into().unwrap()), 50_000_000);
+        assert_eq!(bytes[EscrowCreated::LEN - 1], 4);
+
+        let released = FundsReleased {
+            escrow: [1u8; 32],
+            worker: [3u8; 32],
+            amount: 1_000_000,
+            worker_payment: 990_000,
+            platform_fee: 10_000,
+            fully_released: true,
+        };
+        let bytes = released.to_bytes();
+        assert_eq!(&bytes[..8], b"FundRels");
+        assert_eq!(u64::from_le_bytes(bytes[80..88].try_into().unwrap()), 990_000);
+        assert_eq!(bytes[FundsReleased::LEN - 1], 1);
+
+        let executed = ResolutionExecuted {
+            dispute_case: [5u8; 32],
+            escrow: [1u8; 32],
+            resolution: 3,
+            worker_amount: 495_000,
+            poster_amount: 495_000,
+            platform_fee: 10_000,
+        };
+        let bytes = executed.to_bytes();
+        assert_eq!(&bytes[..8], b"ResExecd");
+        assert_eq!(bytes[72], 3);
+        assert_eq!(u64::from_le_bytes(bytes[89..97].try_into().unwrap()), 10_000);
+
+        // Discriminators stay unique across the program's events
+        let discriminators = [
+            EscrowCreated::DISCRIMINATOR,
+            WorkerAssigned::DISCRIMINATOR,
+            WorkSubmitted::DISCRIMINATOR,
+            FundsReleased::DISCRIMINATOR,
+            DisputeRaised::DISCRIMINATOR,
+            VoteCast::DISCRIMINATOR,
+            DisputeFinalized::DISCRIMINATOR,
+            DisputeAppealed::DISCRIMINATOR,
+            ResolutionExecuted::DISCRIMINATOR,
+        ];
+        for (i, a) in discriminators.iter().enumerate() {
+            assert!(discriminators[i + 1..].iter().all(|b| a != b));
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 use pinocchio::{
     account_info::AccountInfo,
+    cpi::set_return_data,
     instruction::Signer,
     program_error::ProgramError,
     pubkey::{find_program_address, Pubkey},
@@ -11,20 +12,36 @@ use pinocchio::{
     sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio_system::instructions::{CreateAccount, Transfer};
+use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
     errors::EscrowError,
+    events::{
+        ArbitratorsRevealed, DisputeAppealed, DisputeFinalized, DisputeRaised, FinalizePreview,
+        ResolutionExecuted, VoteCast,
+    },
     state::{
-        ArbitratorPool, ArbitratorEntry, DisputeCase, AccuracyClaim, JobEscrow,
-        AgentReputation, EscrowStatus, Vote, DisputeResolution,
-        ARBITRATORS_PER_DISPUTE, ARBITRATION_MAJORITY, MIN_ARBITRATOR_STAKE,
+        ArbitratorPool, ArbitratorEntry, DisputeCase, AccuracyClaim, VoteFeeClaim, AbsenceRecord, JobEscrow,
+        AgentReputation, EscrowStatus, Vote, DisputeResolution, DisputeReason, PlatformConfig,
+        MAX_PANEL_SIZE, MIN_ARBITRATOR_STAKE, select_from_segments,
+        ALL_CATEGORIES, DISPUTE_REVEAL_DELAY_SLOTS, MAX_EXCLUDED_ARBITRATORS,
     },
     require, require_some,
-    PLATFORM_WALLET,
     ID,
 };
 
+use super::helpers::{
+    close_account, find_platform_vault, is_platform_fee_owner, require_writable, safe_pay_from_escrow,
+    settle_worker_deposit,
+    sha256, verify_job_id,
+    DisputePayout, TokenAccountInfo, TOKEN_PROGRAM_ID,
+};
+use super::fee_config::{
+    find_platform_config, platform_authority, require_platform_authority, require_platform_recipient,
+};
+use super::submit_work::REVIEW_WINDOW_SECONDS;
+
 /// Arbitration voting window: 48 hours
 pub const ARBITRATION_VOTING_SECONDS: i64 = 48 * 60 * 60;
 
@@ -40,28 +57,69 @@ fn transfer_lamports(
     Ok(())
 }
 
+/// Derive the pool extension PDA
+#[inline(always)]
+pub fn find_pool_extension(program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[b"arbitrator_pool_ext"], program_id)
+}
+
+/// Verify `extension` is the pool extension PDA owned by this program
+pub(crate) fn check_pool_extension(extension: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    // SECURITY FIX C-01: Verify pool extension is owned by this program
+    if *extension.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    // SECURITY FIX H-03: Verify pool extension PDA derivation
+    let (expected_pda, _) = find_pool_extension(program_id);
+    require!(extension.key() == &expected_pda, EscrowError::InvalidPda);
+    Ok(())
+}
+
+/// Pool segment that holds `agent`
+///
+/// The extension if it was passed and holds them, otherwise the (already
+/// verified) main pool, whose lookup then reports a missing arbitrator.
+pub(crate) fn pool_segment_of<'a>(
+    pool: &'a AccountInfo,
+    extension: Option<&'a AccountInfo>,
+    agent: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<&'a AccountInfo, ProgramError> {
+    if ArbitratorPool::load(&pool.try_borrow_data()?)?.contains(agent) {
+        return Ok(pool);
+    }
+    if let Some(extension) = extension {
+        check_pool_extension(extension, program_id)?;
+        if ArbitratorPool::load(&extension.try_borrow_data()?)?.contains(agent) {
+            return Ok(extension);
+        }
+    }
+    Ok(pool)
+}
+
 // ============== INIT ARBITRATOR POOL ==============
 
 pub struct InitArbitratorPoolAccounts<'a> {
     pub pool: &'a AccountInfo,
     pub authority: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    /// Platform config (PDA ["platform_config"]) - names the platform authority
+    pub platform_config: &'a AccountInfo,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for InitArbitratorPoolAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [pool, authority, system_program, ..] = accounts else {
+        let [pool, authority, system_program, platform_config, ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
         if !authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
-        require!(authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
 
-        Ok(Self { pool, authority, system_program })
+        Ok(Self { pool, authority, system_program, platform_config })
     }
 }
 
@@ -71,6 +129,7 @@ pub fn process_init_arbitrator_pool(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = InitArbitratorPoolAccounts::try_from(accounts)?;
+    require_platform_authority(ctx.authority, ctx.platform_config, program_id)?;
 
     // Derive PDA
     let (expected_pda, bump) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
@@ -105,6 +164,96 @@ pub fn process_init_arbitrator_pool(
     Ok(())
 }
 
+// ============== INIT ARBITRATOR POOL EXTENSION ==============
+
+/// Init arbitrator pool extension accounts
+/// Accounts:
+/// 0. pool_extension (PDA ["arbitrator_pool_ext"], writable) - created here
+/// 1. pool (writable) - the main pool, marked as extended
+/// 2. authority (signer, writable) - the platform authority, pays rent
+/// 3. system_program
+/// 4. platform_config (readonly) - PDA ["platform_config"], names the authority
+pub struct InitArbitratorPoolExtensionAccounts<'a> {
+    pub pool_extension: &'a AccountInfo,
+    pub pool: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for InitArbitratorPoolExtensionAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool_extension, pool, authority, system_program, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[pool_extension, pool, authority])?;
+
+        if !authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { pool_extension, pool, authority, system_program, platform_config })
+    }
+}
+
+/// Process init_arbitrator_pool_extension instruction
+///
+/// Adds a second segment of MAX_ARBITRATORS slots. Registrations fill it
+/// once the main pool is full, and selection draws from both segments.
+pub fn process_init_arbitrator_pool_extension(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = InitArbitratorPoolExtensionAccounts::try_from(accounts)?;
+    require_platform_authority(ctx.authority, ctx.platform_config, program_id)?;
+
+    // SECURITY FIX C-01: Verify pool account is owned by this program
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let (expected_pda, bump) = find_pool_extension(program_id);
+    require!(ctx.pool_extension.key() == &expected_pda, EscrowError::InvalidPda);
+
+    let pool_data = &mut ctx.pool.try_borrow_mut_data()?;
+    let pool = ArbitratorPool::load_mut(pool_data)?;
+    require!(!pool.is_extended(), EscrowError::AccountAlreadyInitialized);
+
+    let rent_lamports = Rent::get()?.minimum_balance(ArbitratorPool::SPACE);
+
+    let bump_ref = &[bump];
+    let signer_seeds = seeds!(b"arbitrator_pool_ext", bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: ctx.authority,
+        to: ctx.pool_extension,
+        lamports: rent_lamports,
+        space: ArbitratorPool::SPACE as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&[signer])?;
+
+    let extension_data = &mut ctx.pool_extension.try_borrow_mut_data()?;
+    let extension = ArbitratorPool::init(extension_data)?;
+
+    extension.authority = pool.authority;
+    extension.min_stake = pool.min_stake;
+    extension.bump = bump;
+
+    pool.has_extension = 1;
+
+    Ok(())
+}
+
 // ============== REGISTER ARBITRATOR ==============
 
 pub struct RegisterArbitratorAccounts<'a> {
@@ -112,13 +261,15 @@ pub struct RegisterArbitratorAccounts<'a> {
     pub arbitrator_account: &'a AccountInfo,
     pub agent: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    /// Pool extension (optional, writable) - takes the arbitrator once the main pool is full
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for RegisterArbitratorAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [pool, arbitrator_account, agent, system_program, ..] = accounts else {
+        let [pool, arbitrator_account, agent, system_program, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -126,16 +277,37 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RegisterArbitratorAccounts<'a> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { pool, arbitrator_account, agent, system_program })
+        Ok(Self { pool, arbitrator_account, agent, system_program, pool_extension: rest.first() })
+    }
+}
+
+/// Instruction data for RegisterArbitrator and SetArbitratorCategories
+/// Layout: [categories: u32 (optional, defaults to all categories)]
+pub struct ArbitratorCategoriesData {
+    pub categories: u32,
+}
+
+impl ArbitratorCategoriesData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let categories = match data.get(0..4) {
+            Some(bytes) => u32::from_le_bytes(bytes.try_into().unwrap()),
+            None => ALL_CATEGORIES,
+        };
+        // An empty mask would make the arbitrator unselectable
+        if categories == 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self { categories })
     }
 }
 
 pub fn process_register_arbitrator(
     accounts: &[AccountInfo],
-    _data: &[u8],
+    data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = RegisterArbitratorAccounts::try_from(accounts)?;
+    let args = ArbitratorCategoriesData::try_from_slice(data)?;
     let clock = Clock::get()?;
 
     // SECURITY FIX C-01: Verify pool account is owned by this program
@@ -154,11 +326,18 @@ pub fn process_register_arbitrator(
     );
     require!(ctx.arbitrator_account.key() == &expected_pda, EscrowError::InvalidPda);
 
-    // Load and update pool
+    // Add to the main pool, or to the extension once the main pool is full
     let pool_data = &mut ctx.pool.try_borrow_mut_data()?;
     let pool = ArbitratorPool::load_mut(pool_data)?;
 
-    pool.add(*ctx.agent.key())?;
+    match ctx.pool_extension {
+        Some(extension) if pool.remaining_capacity() == 0 => {
+            check_pool_extension(extension, program_id)?;
+            let extension_data = &mut extension.try_borrow_mut_data()?;
+            ArbitratorPool::load_mut(extension_data)?.add(*ctx.agent.key(), args.categories)?;
+        }
+        _ => pool.add(*ctx.agent.key(), args.categories)?,
+    }
 
     // Create arbitrator account with stake
     let rent = Rent::get()?;
@@ -190,24 +369,95 @@ pub fn process_register_arbitrator(
     arb.cases_correct = 0;
     arb.is_active = 1;
     arb.registered_at = clock.unix_timestamp;
+    arb.categories = args.categories;
     arb.bump = bump;
 
     Ok(())
 }
 
+// ============== SET ARBITRATOR CATEGORIES ==============
+
+pub struct SetArbitratorCategoriesAccounts<'a> {
+    pub pool: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+    /// Pool extension (optional, writable) - required if the arbitrator is in it
+    pub pool_extension: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetArbitratorCategoriesAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool, arbitrator_account, agent, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !agent.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { pool, arbitrator_account, agent, pool_extension: rest.first() })
+    }
+}
+
+pub fn process_set_arbitrator_categories(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetArbitratorCategoriesAccounts::try_from(accounts)?;
+    let args = ArbitratorCategoriesData::try_from_slice(data)?;
+
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", ctx.agent.key()],
+        program_id,
+    );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+
+    let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
+    let arb = ArbitratorEntry::load_mut(arb_data)?;
+
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+    require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+    require!(&arb.agent == ctx.agent.key(), EscrowError::Unauthorized);
+
+    // Keep the pool's copy in sync so selection doesn't need every entry account
+    let segment = pool_segment_of(ctx.pool, ctx.pool_extension, ctx.agent.key(), program_id)?;
+    let pool_data = &mut segment.try_borrow_mut_data()?;
+    let pool = ArbitratorPool::load_mut(pool_data)?;
+    pool.set_categories(ctx.agent.key(), args.categories)?;
+
+    arb.categories = args.categories;
+
+    Ok(())
+}
+
 // ============== UNREGISTER ARBITRATOR ==============
 
 pub struct UnregisterArbitratorAccounts<'a> {
     pub pool: &'a AccountInfo,
     pub arbitrator_account: &'a AccountInfo,
     pub agent: &'a AccountInfo,
+    /// Pool extension (optional, writable) - required if the arbitrator is in it
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for UnregisterArbitratorAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [pool, arbitrator_account, agent, ..] = accounts else {
+        let [pool, arbitrator_account, agent, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -215,7 +465,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for UnregisterArbitratorAccounts<'a> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { pool, arbitrator_account, agent })
+        Ok(Self { pool, arbitrator_account, agent, pool_extension: rest.first() })
     }
 }
 
@@ -248,7 +498,8 @@ pub fn process_unregister_arbitrator(
     require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
 
     // Load pool and remove
-    let pool_data = &mut ctx.pool.try_borrow_mut_data()?;
+    let segment = pool_segment_of(ctx.pool, ctx.pool_extension, ctx.agent.key(), program_id)?;
+    let pool_data = &mut segment.try_borrow_mut_data()?;
     let pool = ArbitratorPool::load_mut(pool_data)?;
     pool.remove(ctx.agent.key())?;
 
@@ -266,9 +517,9 @@ pub fn process_unregister_arbitrator(
     let account_balance = *ctx.arbitrator_account.try_borrow_lamports()?;
     let rent = Rent::get()?.minimum_balance(ArbitratorEntry::SPACE);
     let available = account_balance.saturating_sub(rent);
-    
-    // Only return what's available, up to stake
-    let return_amount = core::cmp::min(arb.stake, available);
+
+    // Return everything but the open-case reserve, up to what the account holds
+    let return_amount = arb.take_unlocked_stake(available);
     if return_amount > 0 {
         transfer_lamports(ctx.arbitrator_account, ctx.agent, return_amount)?;
     }
@@ -276,296 +527,1960 @@ pub fn process_unregister_arbitrator(
     Ok(())
 }
 
-// ============== RAISE DISPUTE CASE ==============
+// ============== DEACTIVATE LOW ACCURACY ==============
 
-pub struct RaiseDisputeCaseAccounts<'a> {
-    pub escrow: &'a AccountInfo,
-    pub dispute_case: &'a AccountInfo,
+/// Deactivate low accuracy accounts
+/// Accounts:
+/// 0. pool (writable) - PDA ["arbitrator_pool_v2"]
+/// 1. arbitrator_account (writable) - PDA ["arbitrator", agent]
+/// 2. agent (writable) - the arbitrator, receives the returned stake
+/// 3. cranker (signer)
+/// 4. pool_extension (optional, writable) - required if the arbitrator is in it
+pub struct DeactivateLowAccuracyAccounts<'a> {
     pub pool: &'a AccountInfo,
-    pub recent_slothashes: &'a AccountInfo,
-    pub initiator: &'a AccountInfo,
-    pub system_program: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+    pub cranker: &'a AccountInfo,
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for RaiseDisputeCaseAccounts<'a> {
+impl<'a> TryFrom<&'a [AccountInfo]> for DeactivateLowAccuracyAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        // SECURITY FIX H-01: Now requires recent_slothashes account for better entropy
-        let [escrow, dispute_case, pool, recent_slothashes, initiator, system_program, ..] = accounts else {
+        let [pool, arbitrator_account, agent, cranker, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        if !initiator.is_signer() {
+        require_writable(&[pool, arbitrator_account, agent])?;
+
+        if !cranker.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, dispute_case, pool, recent_slothashes, initiator, system_program })
-    }
-}
-
-/// Instruction data for RaiseDisputeCase
-pub struct RaiseDisputeCaseData<'a> {
-    pub reason: &'a str,
-}
-
-impl<'a> RaiseDisputeCaseData<'a> {
-    pub fn try_from_slice(data: &'a [u8]) -> Result<Self, ProgramError> {
-        if data.len() < 2 {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-        let len = u16::from_le_bytes([data[0], data[1]]) as usize;
-        if data.len() < 2 + len {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-        let reason = core::str::from_utf8(&data[2..2+len])
-            .map_err(|_| ProgramError::InvalidInstructionData)?;
-        Ok(Self { reason })
+        Ok(Self { pool, arbitrator_account, agent, cranker, pool_extension: rest.first() })
     }
 }
 
-/// SlotHashes sysvar ID: SysvarS1otHashes111111111111111111111111111
-const SLOT_HASHES_ID: Pubkey = [
-    0x06, 0xa7, 0xd5, 0x17, 0x19, 0x2f, 0x0a, 0xaf,
-    0xc6, 0xf2, 0x65, 0xe3, 0xfb, 0x77, 0xcc, 0x7a,
-    0xda, 0x82, 0xc5, 0x29, 0xd0, 0xbe, 0x3b, 0x13,
-    0x6e, 0x2d, 0x00, 0x55, 0x20, 0x00, 0x00, 0x00,
-];
-
-pub fn process_raise_dispute_case(
+/// Process deactivate_low_accuracy instruction
+///
+/// Permissionless crank: once an arbitrator has MIN_CASES_FOR_EVAL judged
+/// votes and their accuracy is below MIN_ACCURACY_PCT, they leave the pool as
+/// if they had unregistered. The reserve for votes not yet judged stays in
+/// the entry so update_arbitrator_accuracy can still slash it.
+pub fn process_deactivate_low_accuracy(
     accounts: &[AccountInfo],
-    data: &[u8],
+    _data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
-    let ctx = RaiseDisputeCaseAccounts::try_from(accounts)?;
-    let args = RaiseDisputeCaseData::try_from_slice(data)?;
-    let clock = Clock::get()?;
-
-    require!(args.reason.len() <= DisputeCase::MAX_REASON_LEN, EscrowError::ReasonTooLong);
-
-    // SECURITY FIX C-01: Verify escrow account is owned by this program
-    if *ctx.escrow.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
+    let ctx = DeactivateLowAccuracyAccounts::try_from(accounts)?;
 
     // SECURITY FIX C-01: Verify pool account is owned by this program
     if *ctx.pool.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // SECURITY FIX H-01: Verify recent_slothashes is the correct sysvar
-    require!(ctx.recent_slothashes.key() == &SLOT_HASHES_ID, EscrowError::InvalidPda);
-
-    // Load escrow
-    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
-    let escrow = JobEscrow::load_mut(escrow_data)?;
-
-    // SECURITY FIX C-02: Verify escrow PDA derivation
-    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
-        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
-        program_id,
-    );
-    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
-    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+    // SECURITY FIX C-01: Verify arbitrator_account is owned by this program
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
 
     // SECURITY FIX H-03: Verify pool PDA derivation
     let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
     require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
 
-    require!(
-        escrow.status == EscrowStatus::Active as u8 || escrow.status == EscrowStatus::PendingReview as u8,
-        EscrowError::EscrowNotActive
+    // SECURITY FIX C-02: Verify arbitrator PDA derivation
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", ctx.agent.key()],
+        program_id,
     );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
 
-    // Initiator must be poster or worker
-    let initiator_key = ctx.initiator.key();
-    require!(
-        initiator_key == &escrow.poster || initiator_key == &escrow.worker,
-        EscrowError::Unauthorized
-    );
+    let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
+    let arb = ArbitratorEntry::load_mut(arb_data)?;
 
-    // Load pool and check we have enough arbitrators
-    let pool_data = ctx.pool.try_borrow_data()?;
-    let pool = ArbitratorPool::load(&pool_data)?;
-    require!(pool.arbitrator_count as usize >= ARBITRATORS_PER_DISPUTE, EscrowError::NotEnoughArbitrators);
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+    require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+    require!(&arb.agent == ctx.agent.key(), EscrowError::Unauthorized);
+    require!(arb.below_accuracy_threshold(), EscrowError::AccuracyAboveThreshold);
 
-    // Verify dispute case PDA
-    let (expected_pda, bump) = find_program_address(
-        &[b"dispute", ctx.escrow.key()],
-        program_id,
-    );
-    require!(ctx.dispute_case.key() == &expected_pda, EscrowError::InvalidPda);
+    let segment = pool_segment_of(ctx.pool, ctx.pool_extension, ctx.agent.key(), program_id)?;
+    let pool_data = &mut segment.try_borrow_mut_data()?;
+    let pool = ArbitratorPool::load_mut(pool_data)?;
+    pool.remove(ctx.agent.key())?;
 
-    // SECURITY FIX H-01: Improved entropy using SlotHashes sysvar
-    // Read the first slot hash (most recent) from the SlotHashes sysvar
-    // The SlotHashes account format: [length: u64][SlotHash entries...]
-    // Each SlotHash entry: [slot: u64][hash: [u8; 32]]
-    let slothash_data = ctx.recent_slothashes.try_borrow_data()?;
-    let mut recent_hash = [0u8; 32];
-    if slothash_data.len() >= 48 {
-        // Skip 8-byte length, skip 8-byte slot, copy 32-byte hash
-        recent_hash.copy_from_slice(&slothash_data[16..48]);
-    }
-    drop(slothash_data);
+    arb.is_active = 0;
 
-    // Combine multiple entropy sources including recent blockhash
-    let escrow_key = ctx.escrow.key();
-    let initiator_bytes = ctx.initiator.key();
-    let slot_bytes = clock.slot.to_le_bytes();
-    let ts_bytes = clock.unix_timestamp.to_le_bytes();
-    let amt_bytes = escrow.amount.to_le_bytes();
+    // SECURITY FIX H-04: Verify account has enough balance and use safe transfer
+    let account_balance = *ctx.arbitrator_account.try_borrow_lamports()?;
+    let rent = Rent::get()?.minimum_balance(ArbitratorEntry::SPACE);
+    let available = account_balance.saturating_sub(rent);
 
-    // Build seed using XOR mixing with slot hash
-    let mut seed_data = [0u8; 32];
-    for i in 0..8 { 
-        seed_data[i] = escrow_key[i] ^ initiator_bytes[i] ^ recent_hash[i]; 
-    }
-    for i in 0..8 { 
-        seed_data[8 + i] = slot_bytes[i] ^ escrow_key[16 + i] ^ recent_hash[8 + i]; 
-    }
-    for i in 0..8 { 
-        seed_data[16 + i] = ts_bytes[i] ^ initiator_bytes[16 + i] ^ recent_hash[16 + i]; 
-    }
-    for i in 0..8 { 
-        seed_data[24 + i] = amt_bytes[i] ^ escrow_key[24 + i] ^ recent_hash[24 + i]; 
+    // Return everything but the open-case reserve, up to what the account holds
+    let return_amount = arb.take_unlocked_stake(available);
+    if return_amount > 0 {
+        transfer_lamports(ctx.arbitrator_account, ctx.agent, return_amount)?;
     }
 
-    let seed = u64::from_le_bytes(seed_data[0..8].try_into().unwrap());
-
-    let mut selected: [Pubkey; ARBITRATORS_PER_DISPUTE] = [[0u8; 32]; ARBITRATORS_PER_DISPUTE];
-    let mut used_indices: [usize; ARBITRATORS_PER_DISPUTE] = [usize::MAX; ARBITRATORS_PER_DISPUTE];
+    Ok(())
+}
 
-    for i in 0..ARBITRATORS_PER_DISPUTE {
-        let mut idx = ((seed.wrapping_add(i as u64).wrapping_mul(31337)) as usize) 
-            % pool.arbitrator_count as usize;
-        
-        // Linear probe to avoid duplicates
-        while used_indices.contains(&idx) {
-            idx = (idx + 1) % pool.arbitrator_count as usize;
-        }
-        used_indices[i] = idx;
-        selected[i] = pool.arbitrators[idx];
-    }
+// ============== WITHDRAW EXCESS STAKE ==============
+
+/// Withdraw excess stake accounts
+/// Accounts:
+/// 0. pool (readonly) - sets the minimum stake
+/// 1. arbitrator_account (writable)
+/// 2. agent (signer, writable) - the arbitrator, receives the stake
+pub struct WithdrawExcessStakeAccounts<'a> {
+    pub pool: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for WithdrawExcessStakeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool, arbitrator_account, agent, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[arbitrator_account, agent])?;
+
+        if !agent.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { pool, arbitrator_account, agent })
+    }
+}
+
+/// Instruction data for WithdrawExcessStake
+/// Layout: [amount: u64]
+pub struct WithdrawExcessStakeData {
+    pub amount: u64,
+}
+
+impl WithdrawExcessStakeData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let bytes = data.get(..8).ok_or(ProgramError::InvalidInstructionData)?;
+        let amount = u64::from_le_bytes(bytes.try_into().unwrap());
+        Ok(Self { amount })
+    }
+}
+
+/// Process withdraw_excess_stake instruction
+///
+/// Returns stake above the pool minimum plus STAKE_RESERVE_PER_CASE for each
+/// voted case not yet judged. Unlike unregister_arbitrator, the arbitrator
+/// stays active and selectable.
+pub fn process_withdraw_excess_stake(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = WithdrawExcessStakeAccounts::try_from(accounts)?;
+    let args = WithdrawExcessStakeData::try_from_slice(data)?;
+
+    // SECURITY FIX C-01: Verify pool account is owned by this program
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify arbitrator_account is owned by this program
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    // SECURITY FIX C-02: Verify arbitrator PDA derivation
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", ctx.agent.key()],
+        program_id,
+    );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+
+    // Never lock less than the program minimum, whatever the pool records
+    let min_stake = {
+        let pool_data = ctx.pool.try_borrow_data()?;
+        ArbitratorPool::load(&pool_data)?.min_stake.max(MIN_ARBITRATOR_STAKE)
+    };
+
+    let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
+    let arb = ArbitratorEntry::load_mut(arb_data)?;
+
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+    require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+    require!(&arb.agent == ctx.agent.key(), EscrowError::Unauthorized);
+
+    arb.withdraw_excess_stake(args.amount, min_stake)?;
+
+    // SECURITY FIX H-04: Verify account has enough balance and use safe transfer
+    let account_balance = *ctx.arbitrator_account.try_borrow_lamports()?;
+    let rent = Rent::get()?.minimum_balance(ArbitratorEntry::SPACE);
+    let available = account_balance.saturating_sub(rent);
+    require!(args.amount <= available, EscrowError::InsufficientExcessStake);
+
+    transfer_lamports(ctx.arbitrator_account, ctx.agent, args.amount)?;
+
+    Ok(())
+}
+
+// ============== MIGRATE ARBITRATOR ==============
+
+/// Migrate arbitrator accounts
+/// Accounts:
+/// 0. pool (writable)
+/// 1. old_arbitrator_account (writable) - closed after migration
+/// 2. new_arbitrator_account (writable) - PDA ["arbitrator", new_agent]
+/// 3. agent (signer, writable) - current arbitrator wallet, pays rent for the new entry
+/// 4. system_program
+/// 5. pool_extension (optional, writable) - required if the arbitrator is in it
+pub struct MigrateArbitratorAccounts<'a> {
+    pub pool: &'a AccountInfo,
+    pub old_arbitrator_account: &'a AccountInfo,
+    pub new_arbitrator_account: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub pool_extension: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MigrateArbitratorAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool, old_arbitrator_account, new_arbitrator_account, agent, system_program, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !agent.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self {
+            pool,
+            old_arbitrator_account,
+            new_arbitrator_account,
+            agent,
+            system_program,
+            pool_extension: rest.first(),
+        })
+    }
+}
+
+/// Instruction data for MigrateArbitrator
+/// Layout: [new_agent: Pubkey (32 bytes)]
+pub struct MigrateArbitratorData {
+    pub new_agent: Pubkey,
+}
+
+impl MigrateArbitratorData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 32 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let new_agent: Pubkey = data[0..32].try_into().unwrap();
+        Ok(Self { new_agent })
+    }
+}
+
+/// Move an arbitrator's stake, stats, and pool slot to a new wallet
+///
+/// Dispute cases already assigned to the old wallet still reference it, so
+/// arbitrators should finish outstanding votes before migrating.
+pub fn process_migrate_arbitrator(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = MigrateArbitratorAccounts::try_from(accounts)?;
+    let args = MigrateArbitratorData::try_from_slice(data)?;
+
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.old_arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let (expected_old_pda, expected_old_bump) = find_program_address(
+        &[b"arbitrator", ctx.agent.key()],
+        program_id,
+    );
+    require!(ctx.old_arbitrator_account.key() == &expected_old_pda, EscrowError::InvalidPda);
+
+    let (expected_new_pda, new_bump) = find_program_address(
+        &[b"arbitrator", &args.new_agent],
+        program_id,
+    );
+    require!(ctx.new_arbitrator_account.key() == &expected_new_pda, EscrowError::InvalidPda);
+
+    // Swap the key in the pool, keeping the slot and category mask
+    {
+        let segment = pool_segment_of(ctx.pool, ctx.pool_extension, ctx.agent.key(), program_id)?;
+        let pool_data = &mut segment.try_borrow_mut_data()?;
+        let pool = ArbitratorPool::load_mut(pool_data)?;
+        pool.replace(ctx.agent.key(), args.new_agent)?;
+    }
+
+    // Snapshot the old entry
+    let (stake, cases_voted, cases_correct, cases_missed, open_cases, registered_at, categories) = {
+        let arb_data = ctx.old_arbitrator_account.try_borrow_data()?;
+        let arb = ArbitratorEntry::load(&arb_data)?;
+
+        require!(arb.bump == expected_old_bump, EscrowError::InvalidPda);
+        require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+        require!(&arb.agent == ctx.agent.key(), EscrowError::Unauthorized);
+
+        (arb.stake, arb.cases_voted, arb.cases_correct, arb.cases_missed, arb.open_cases, arb.registered_at, arb.categories)
+    };
+
+    // Create the new entry with rent paid by the old wallet
+    let rent_lamports = Rent::get()?.minimum_balance(ArbitratorEntry::SPACE);
+
+    let bump_ref = &[new_bump];
+    let signer_seeds = seeds!(b"arbitrator", &args.new_agent, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: ctx.agent,
+        to: ctx.new_arbitrator_account,
+        lamports: rent_lamports,
+        space: ArbitratorEntry::SPACE as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&[signer])?;
+
+    // Move the stake (only what is actually held above rent)
+    let account_balance = *ctx.old_arbitrator_account.try_borrow_lamports()?;
+    let available = account_balance.saturating_sub(rent_lamports);
+    let moved_stake = core::cmp::min(stake, available);
+    if moved_stake > 0 {
+        transfer_lamports(ctx.old_arbitrator_account, ctx.new_arbitrator_account, moved_stake)?;
+    }
+
+    {
+        let arb_data = &mut ctx.new_arbitrator_account.try_borrow_mut_data()?;
+        let arb = ArbitratorEntry::init(arb_data)?;
+
+        arb.agent = args.new_agent;
+        arb.stake = moved_stake;
+        arb.cases_voted = cases_voted;
+        arb.cases_correct = cases_correct;
+        arb.cases_missed = cases_missed;
+        arb.open_cases = open_cases;
+        arb.is_active = 1;
+        arb.registered_at = registered_at;
+        arb.categories = categories;
+        arb.bump = new_bump;
+    }
+
+    // Close the old entry, returning its rent to the old wallet
+    close_account(ctx.old_arbitrator_account, ctx.agent)?;
+
+    Ok(())
+}
+
+// ============== RAISE DISPUTE CASE ==============
+
+/// Raise dispute case accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. dispute_case (PDA, writable) - created here
+/// 2. pool (readonly)
+/// 3. initiator (signer, writable) - pays rent and the vote fees
+/// 4. system_program
+///
+/// Raising only commits to a future slot; reveal_dispute_arbitrators seats
+/// the panel once that slot's hash is known.
+pub struct RaiseDisputeCaseAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub dispute_case: &'a AccountInfo,
+    pub pool: &'a AccountInfo,
+    pub initiator: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RaiseDisputeCaseAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, dispute_case, pool, initiator, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !initiator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, dispute_case, pool, initiator, system_program })
+    }
+}
+
+/// Instruction data for RaiseDisputeCase
+/// Layout: [reason_len: u16][reason: bytes][reason_category: u16 (optional, defaults to Unspecified)]
+///         [excluded_count: u8 (optional, up to 2)][excluded: [Pubkey; excluded_count]]
+///         [disputed_amount: u64 (optional, defaults to everything the escrow holds)]
+pub struct RaiseDisputeCaseData<'a> {
+    pub reason: &'a str,
+    pub reason_category: DisputeReason,
+    pub excluded: [Pubkey; MAX_EXCLUDED_ARBITRATORS],
+    pub excluded_count: usize,
+    pub disputed_amount: Option<u64>,
+}
+
+impl<'a> RaiseDisputeCaseData<'a> {
+    pub fn try_from_slice(data: &'a [u8]) -> Result<Self, ProgramError> {
+        if data.len() < 2 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let len = u16::from_le_bytes([data[0], data[1]]) as usize;
+        if data.len() < 2 + len {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let reason = core::str::from_utf8(&data[2..2+len])
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+        let reason_category = match data.get(2 + len..4 + len) {
+            Some(bytes) => DisputeReason::from_u16(u16::from_le_bytes([bytes[0], bytes[1]]))
+                .ok_or(ProgramError::InvalidInstructionData)?,
+            None => DisputeReason::Unspecified,
+        };
+
+        let mut excluded = [[0u8; 32]; MAX_EXCLUDED_ARBITRATORS];
+        let excluded_count = data.get(4 + len).copied().unwrap_or(0) as usize;
+        if excluded_count > MAX_EXCLUDED_ARBITRATORS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        if excluded_count > 0 {
+            let keys = data
+                .get(5 + len..5 + len + excluded_count * 32)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            for (key, bytes) in excluded.iter_mut().zip(keys.chunks_exact(32)) {
+                *key = bytes.try_into().unwrap();
+            }
+        }
+
+        let amount_offset = 5 + len + excluded_count * 32;
+        let disputed_amount = data
+            .get(amount_offset..amount_offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+
+        Ok(Self { reason, reason_category, excluded, excluded_count, disputed_amount })
+    }
+
+    /// Arbitrators the initiator asked to keep off the panel
+    #[inline(always)]
+    pub fn exclusions(&self) -> &[Pubkey] {
+        &self.excluded[..self.excluded_count]
+    }
+}
+
+/// SlotHashes sysvar ID: SysvarS1otHashes111111111111111111111111111
+const SLOT_HASHES_ID: Pubkey = [
+    0x06, 0xa7, 0xd5, 0x17, 0x19, 0x2f, 0x0a, 0xaf,
+    0xc6, 0xf2, 0x65, 0xe3, 0xfb, 0x77, 0xcc, 0x7a,
+    0xda, 0x82, 0xc5, 0x29, 0xd0, 0xbe, 0x3b, 0x13,
+    0x6e, 0x2d, 0x00, 0x55, 0x20, 0x00, 0x00, 0x00,
+];
+
+/// Commitment stored when a dispute is raised: sha256(escrow || reveal_slot)
+fn selection_commitment(escrow: &Pubkey, reveal_slot: u64) -> [u8; 32] {
+    let mut preimage = [0u8; 40];
+    preimage[..32].copy_from_slice(escrow);
+    preimage[32..].copy_from_slice(&reveal_slot.to_le_bytes());
+    sha256(&preimage)
+}
+
+/// Selection seed: first 8 bytes of sha256(commitment || slot_hash)
+fn selection_seed(commitment: &[u8; 32], slot_hash: &[u8; 32]) -> u64 {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(commitment);
+    preimage[32..].copy_from_slice(slot_hash);
+    u64::from_le_bytes(sha256(&preimage)[..8].try_into().unwrap())
+}
+
+/// Hash of the first block at or after `slot`, read from SlotHashes data
+///
+/// The sysvar is `[len: u64][(slot: u64, hash: [u8; 32]); len]`, newest first.
+/// Returns None when no block has landed at or after `slot`, or when `slot`
+/// is older than every entry and the first block after it is unknown.
+fn slot_hash_at_or_after(slothash_data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(slothash_data.get(..8)?.try_into().unwrap()) as usize;
+    let entries = slothash_data.get(8..)?.chunks_exact(40).take(len);
+    let mut found = None;
+    for entry in entries {
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        if entry_slot < slot {
+            return found;
+        }
+        found = Some(entry[8..40].try_into().unwrap());
+    }
+    // Never saw an older entry: `slot` may have aged out of the sysvar
+    None
+}
+
+pub fn process_raise_dispute_case(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RaiseDisputeCaseAccounts::try_from(accounts)?;
+    let args = RaiseDisputeCaseData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    require!(args.reason.len() <= DisputeCase::MAX_REASON_LEN, EscrowError::ReasonTooLong);
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify pool account is owned by this program
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Load escrow
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    // A worker's simple dispute escalates here instead of to a poster refund
+    require!(escrow.can_raise_dispute_case(), EscrowError::EscrowNotActive);
+
+    // Initiator must be poster or worker, unless the worker opted into
+    // auto-arbitration and the poster let the review lapse
+    let initiator_key = ctx.initiator.key();
+    require!(
+        initiator_key == &escrow.poster
+            || initiator_key == &escrow.worker
+            || escrow.can_auto_escalate(clock.unix_timestamp, REVIEW_WINDOW_SECONDS),
+        EscrowError::Unauthorized
+    );
+
+    // Only the poster may narrow the dispute, since the undisputed rest is
+    // released to the worker
+    let disputed_amount = match args.disputed_amount {
+        Some(amount) => {
+            require!(initiator_key == &escrow.poster, EscrowError::Unauthorized);
+            require!(
+                amount > 0 && amount <= escrow.remaining_amount(),
+                EscrowError::InvalidDisputedAmount
+            );
+            amount
+        }
+        None => escrow.remaining_amount(),
+    };
+
+    // Larger escrows get a larger panel; check the pool can seat it
+    let panel_size = DisputeCase::panel_size_for(escrow.amount);
+    let pool_data = ctx.pool.try_borrow_data()?;
+    let pool = ArbitratorPool::load(&pool_data)?;
+    // The extension only fills once the main pool is full, so a short main
+    // pool means a short pool
+    require!(
+        pool.arbitrator_count as usize >= panel_size || pool.is_extended(),
+        EscrowError::NotEnoughArbitrators
+    );
+
+    // Verify dispute case PDA
+    let (expected_pda, bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_pda, EscrowError::InvalidPda);
+
+    // SECURITY FIX H-01: Commit to a future slot instead of seeding selection
+    // from values the initiator controls; the panel is drawn at reveal
+    let reveal_slot = clock.slot.saturating_add(DISPUTE_REVEAL_DELAY_SLOTS);
+    let commitment = selection_commitment(ctx.escrow.key(), reveal_slot);
 
     // Drop pool borrow before creating account
     drop(pool_data);
 
-    // Create dispute case account
-    let rent = Rent::get()?;
-    let rent_lamports = rent.minimum_balance(DisputeCase::SPACE);
+    // Create dispute case account; the initiator also pays in the vote fees,
+    // scaled to the disputed amount, which the case holds until voters claim them
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(DisputeCase::SPACE);
+    let vote_fee_pot = DisputeCase::vote_fee_pot_for(disputed_amount, panel_size);
+
+    let bump_ref = &[bump];
+    let signer_seeds = seeds!(b"dispute", ctx.escrow.key(), bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: ctx.initiator,
+        to: ctx.dispute_case,
+        lamports: rent_lamports
+            .checked_add(vote_fee_pot)
+            .ok_or(EscrowError::ArithmeticOverflow)?,
+        space: DisputeCase::SPACE as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&[signer])?;
+
+    // Initialize dispute case
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::init(dispute_data)?;
+
+    dispute.escrow = *ctx.escrow.key();
+    dispute.raised_by = *ctx.initiator.key();
+    dispute.panel_size = panel_size as u8;
+    dispute.commit_reveal = 1;
+    dispute.votes = [Vote::None as u8; MAX_PANEL_SIZE];
+    // Provisional until reveal, so an unrevealed case can still be reclaimed
+    dispute.voting_deadline = clock.unix_timestamp + ARBITRATION_VOTING_SECONDS;
+    dispute.resolution = DisputeResolution::Pending as u8;
+    dispute.created_at = clock.unix_timestamp;
+    dispute.disputed_amount = disputed_amount;
+    dispute.bump = bump;
+    dispute.reason_category = args.reason_category as u16;
+    dispute.set_reason(args.reason)?;
+    dispute.set_exclusions(args.exclusions())?;
+    dispute.commit_selection(reveal_slot, commitment);
+    dispute.fund_vote_fees();
+
+    DisputeRaised {
+        escrow: dispute.escrow,
+        dispute_case: *ctx.dispute_case.key(),
+        raised_by: dispute.raised_by,
+        reason_category: dispute.reason_category,
+        voting_deadline: dispute.voting_deadline,
+        review_cycles: escrow.review_cycles,
+    }
+    .emit();
+
+    // Update escrow status
+    escrow.status = EscrowStatus::InArbitration as u8;
+    escrow.dispute_case = *ctx.dispute_case.key();
+    escrow.has_dispute_case = 1;
+
+    Ok(())
+}
+
+// ============== REVEAL DISPUTE ARBITRATORS ==============
+
+/// Reveal dispute arbitrators accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. escrow (readonly)
+/// 2. pool (readonly)
+/// 3. recent_slothashes - SlotHashes sysvar
+/// 4. pool_extension (readonly) - required once the pool is extended
+pub struct RevealDisputeArbitratorsAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+    pub pool: &'a AccountInfo,
+    pub recent_slothashes: &'a AccountInfo,
+    pub pool_extension: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RevealDisputeArbitratorsAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, escrow, pool, recent_slothashes, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[dispute_case])?;
+
+        Ok(Self { dispute_case, escrow, pool, recent_slothashes, pool_extension: rest.first() })
+    }
+}
+
+/// Process reveal_dispute_arbitrators instruction (permissionless)
+///
+/// Once the committed slot has passed, selects the panel seeded by that
+/// slot's hash and opens voting for ARBITRATION_VOTING_SECONDS. If the slot
+/// has aged out of SlotHashes, the case commits to a new future slot instead.
+pub fn process_reveal_dispute_arbitrators(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RevealDisputeArbitratorsAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify accounts are owned by this program
+    if *ctx.dispute_case.owner() != ID
+        || *ctx.escrow.owner() != ID
+        || *ctx.pool.owner() != ID
+    {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX H-01: Verify recent_slothashes is the correct sysvar
+    require!(ctx.recent_slothashes.key() == &SLOT_HASHES_ID, EscrowError::InvalidPda);
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    require!(!dispute.is_revealed(), EscrowError::ArbitratorsAlreadyRevealed);
+    require!(!dispute.is_resolved(), EscrowError::DisputeAlreadyResolved);
+    require!(clock.slot > dispute.reveal_slot, EscrowError::RevealSlotNotReached);
+
+    let slothash_data = ctx.recent_slothashes.try_borrow_data()?;
+    let slot_hash = slot_hash_at_or_after(&slothash_data, dispute.reveal_slot);
+    drop(slothash_data);
+
+    let Some(slot_hash) = slot_hash else {
+        // Too late to verify the committed hash; commit to a fresh slot
+        let reveal_slot = clock.slot.saturating_add(DISPUTE_REVEAL_DELAY_SLOTS);
+        dispute.commit_selection(reveal_slot, selection_commitment(ctx.escrow.key(), reveal_slot));
+        return Ok(());
+    };
+    let seed = selection_seed(&dispute.selection_commitment, &slot_hash);
+
+    // Only arbitrators who opted in to the escrow's category are eligible,
+    // neither party may judge their own case, and the initiator may rule out
+    // a couple of arbitrators as long as enough remain. An appeal goes to a
+    // fresh panel, so the appealed round's arbitrators are ruled out too.
+    let mut excluded = [[0u8; 32]; 2 + MAX_EXCLUDED_ARBITRATORS + MAX_PANEL_SIZE];
+    excluded[0] = escrow.poster;
+    excluded[1] = escrow.worker;
+    let mut excluded_len = 2 + dispute.exclusions().len();
+    excluded[2..excluded_len].copy_from_slice(dispute.exclusions());
+    let prior = &dispute.prior_arbitrators[..dispute.prior_panel_len()];
+    excluded[excluded_len..excluded_len + prior.len()].copy_from_slice(prior);
+    excluded_len += prior.len();
+
+    // Once extended, both segments must be drawn from; leaving the extension
+    // out would let the revealer pick between two panels
+    let pool_data = ctx.pool.try_borrow_data()?;
+    let pool = ArbitratorPool::load(&pool_data)?;
+    let extension_data = match (pool.is_extended(), ctx.pool_extension) {
+        (false, _) => None,
+        (true, Some(extension)) => {
+            check_pool_extension(extension, program_id)?;
+            Some(extension.try_borrow_data()?)
+        }
+        (true, None) => return Err(ProgramError::NotEnoughAccountKeys),
+    };
+    let selected = match &extension_data {
+        Some(extension_data) => select_from_segments(
+            &[pool, ArbitratorPool::load(extension_data)?],
+            escrow.category,
+            &excluded[..excluded_len],
+            seed,
+            dispute.panel_len(),
+        )?,
+        None => pool.select_arbitrators(
+            escrow.category,
+            &excluded[..excluded_len],
+            seed,
+            dispute.panel_len(),
+        )?,
+    };
+
+    let voting_deadline = clock.unix_timestamp + ARBITRATION_VOTING_SECONDS;
+    dispute.reveal_arbitrators(selected, voting_deadline)?;
+
+    ArbitratorsRevealed {
+        dispute_case: *ctx.dispute_case.key(),
+        reveal_slot: dispute.reveal_slot,
+        voting_deadline,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// ============== CAST ARBITRATION VOTE ==============
+
+/// Cast arbitration vote accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. arbitrator_account (writable) - entry of the slot owner (the delegator when voting as delegate)
+/// 2. voter (signer) - selected arbitrator or their delegate
+pub struct CastArbitrationVoteAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub voter: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CastArbitrationVoteAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator_account, voter, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !voter.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, arbitrator_account, voter })
+    }
+}
+
+/// Instruction data for CastArbitrationVote
+pub struct CastArbitrationVoteData {
+    pub vote: Vote,
+}
+
+impl CastArbitrationVoteData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let vote = Vote::from_u8(data[0]).ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Self { vote })
+    }
+}
+
+pub fn process_cast_arbitration_vote(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = CastArbitrationVoteAccounts::try_from(accounts)?;
+    let args = CastArbitrationVoteData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    // Validate vote is ForWorker or ForPoster (not None)
+    require!(args.vote != Vote::None, EscrowError::AlreadyVoted);
+
+    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify arbitrator_account is owned by this program
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Load dispute case
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    require!(!dispute.is_resolved(), EscrowError::DisputeAlreadyResolved);
+    dispute.require_revealed()?;
+    require!(dispute.is_voting_open(clock.unix_timestamp), EscrowError::VotingDeadlinePassed);
+    // Open votes would let later arbitrators follow the earlier ones
+    require!(!dispute.requires_commit_reveal(), EscrowError::VoteCommitRequired);
+
+    let (position, slot_owner) = voting_slot(dispute, ctx.voter, ctx.arbitrator_account, program_id)?;
+
+    // Cast vote (each slot votes once, whether by owner or delegate)
+    dispute.record_vote(position, args.vote)?;
+    credit_vote(ctx.dispute_case, dispute, ctx.arbitrator_account, slot_owner, args.vote)
+}
+
+/// Find the slot a voter votes on and check its owner's arbitrator entry
+///
+/// Returns (position, slot_owner). The entry is always the slot owner's, so
+/// delegated votes count towards the delegator's record (matching how
+/// accuracy is credited).
+fn voting_slot(
+    dispute: &DisputeCase,
+    voter: &AccountInfo,
+    arbitrator_account: &AccountInfo,
+    program_id: &Pubkey,
+) -> Result<(usize, Pubkey), ProgramError> {
+    // Find the voter's position: their own slot, or one delegated to them
+    let position = require_some!(
+        dispute.voting_position(voter.key()),
+        EscrowError::NotSelectedArbitrator
+    );
+    let slot_owner = dispute.arbitrators[position];
+
+    // SECURITY FIX C-02: Verify arbitrator PDA derivation
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", &slot_owner],
+        program_id,
+    );
+    require!(arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+
+    let arb_data = arbitrator_account.try_borrow_data()?;
+    let arb = ArbitratorEntry::load(&arb_data)?;
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+    require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+    require!(arb.agent == slot_owner, EscrowError::Unauthorized);
+
+    Ok((position, slot_owner))
+}
+
+/// Announce a recorded vote and count it on the slot owner's entry
+fn credit_vote(
+    dispute_case: &AccountInfo,
+    dispute: &DisputeCase,
+    arbitrator_account: &AccountInfo,
+    slot_owner: Pubkey,
+    vote: Vote,
+) -> ProgramResult {
+    let (for_worker, for_poster) = dispute.count_votes();
+    VoteCast {
+        dispute_case: *dispute_case.key(),
+        arbitrator: slot_owner,
+        vote: vote as u8,
+        for_worker,
+        for_poster,
+    }
+    .emit();
+
+    let arb_data = &mut arbitrator_account.try_borrow_mut_data()?;
+    let arb = ArbitratorEntry::load_mut(arb_data)?;
+    // SECURITY FIX H-05: Use checked arithmetic
+    arb.cases_voted = arb.cases_voted.saturating_add(1);
+    // Lock a stake reserve until the vote is judged
+    arb.open_cases = arb.open_cases.saturating_add(1);
+
+    Ok(())
+}
+
+/// Hidden vote: sha256(vote || salt || arbitrator)
+///
+/// Binding the slot owner stops another arbitrator from copying a
+/// commitment and then replaying its reveal.
+fn vote_commitment(vote: Vote, salt: &[u8; 32], arbitrator: &Pubkey) -> [u8; 32] {
+    let mut preimage = [0u8; 65];
+    preimage[0] = vote as u8;
+    preimage[1..33].copy_from_slice(salt);
+    preimage[33..].copy_from_slice(arbitrator);
+    sha256(&preimage)
+}
+
+// ============== COMMIT VOTE ==============
+
+/// Commit vote accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. arbitrator_account - entry of the slot owner (the delegator when voting as delegate)
+/// 2. voter (signer) - selected arbitrator or their delegate
+pub struct CommitVoteAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub voter: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CommitVoteAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator_account, voter, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !voter.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, arbitrator_account, voter })
+    }
+}
+
+/// Instruction data for CommitVote
+/// Layout: [commitment: [u8; 32]] - sha256(vote || salt || slot_owner)
+pub struct CommitVoteData {
+    pub commitment: [u8; 32],
+}
+
+impl CommitVoteData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 32 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        Ok(Self { commitment: data[0..32].try_into().unwrap() })
+    }
+}
+
+/// Record a hidden vote on a commit-reveal case before the commit deadline
+pub fn process_commit_vote(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = CommitVoteAccounts::try_from(accounts)?;
+    let args = CommitVoteData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify account ownership
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.require_revealed()?;
+    require!(dispute.requires_commit_reveal(), EscrowError::Unauthorized);
+
+    let (position, _) = voting_slot(dispute, ctx.voter, ctx.arbitrator_account, program_id)?;
+    dispute.commit_vote(position, args.commitment, clock.unix_timestamp)
+}
+
+// ============== REVEAL VOTE ==============
+
+/// Reveal vote accounts (same as commit_vote; arbitrator_account is writable)
+pub type RevealVoteAccounts<'a> = CommitVoteAccounts<'a>;
+
+/// Instruction data for RevealVote
+/// Layout: [vote: u8, salt: [u8; 32]]
+pub struct RevealVoteData {
+    pub vote: Vote,
+    pub salt: [u8; 32],
+}
+
+impl RevealVoteData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 33 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let vote = Vote::from_u8(data[0]).ok_or(ProgramError::InvalidInstructionData)?;
+        require!(vote != Vote::None, ProgramError::InvalidInstructionData);
+        Ok(Self { vote, salt: data[1..33].try_into().unwrap() })
+    }
+}
+
+/// Reveal a committed vote between the commit deadline and the voting deadline
+///
+/// Only revealed votes are counted; a commitment left unrevealed at the
+/// deadline counts as not voting.
+pub fn process_reveal_vote(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RevealVoteAccounts::try_from(accounts)?;
+    let args = RevealVoteData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify account ownership
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.require_revealed()?;
+
+    let (position, slot_owner) = voting_slot(dispute, ctx.voter, ctx.arbitrator_account, program_id)?;
+    let commitment = vote_commitment(args.vote, &args.salt, &slot_owner);
+    dispute.reveal_vote(position, args.vote, &commitment, clock.unix_timestamp)?;
+    credit_vote(ctx.dispute_case, dispute, ctx.arbitrator_account, slot_owner, args.vote)
+}
+
+// ============== DELEGATE VOTE ==============
+
+/// Delegate vote accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. arbitrator_account - delegator's ArbitratorEntry
+/// 2. delegate_arbitrator_account - delegate's ArbitratorEntry (must be active)
+/// 3. arbitrator (signer) - the selected arbitrator delegating their vote
+/// 4. escrow - the disputed escrow, whose parties cannot be delegates
+pub struct DelegateVoteAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub delegate_arbitrator_account: &'a AccountInfo,
+    pub arbitrator: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DelegateVoteAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator_account, delegate_arbitrator_account, arbitrator, escrow, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !arbitrator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, arbitrator_account, delegate_arbitrator_account, arbitrator, escrow })
+    }
+}
+
+/// Hand a selected arbitrator's vote on one case to another active arbitrator
+pub fn process_delegate_vote(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = DelegateVoteAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.delegate_arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Delegator must be an active arbitrator
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", ctx.arbitrator.key()],
+        program_id,
+    );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+    {
+        let arb_data = ctx.arbitrator_account.try_borrow_data()?;
+        let arb = ArbitratorEntry::load(&arb_data)?;
+        require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+        require!(arb.is_active(), EscrowError::ArbitratorNotActive);
+        require!(&arb.agent == ctx.arbitrator.key(), EscrowError::Unauthorized);
+    }
+
+    // Delegate must be an active arbitrator too
+    let delegate = {
+        let delegate_data = ctx.delegate_arbitrator_account.try_borrow_data()?;
+        let delegate_arb = ArbitratorEntry::load(&delegate_data)?;
+        let (expected_delegate_pda, expected_delegate_bump) = find_program_address(
+            &[b"arbitrator", &delegate_arb.agent],
+            program_id,
+        );
+        require!(ctx.delegate_arbitrator_account.key() == &expected_delegate_pda, EscrowError::InvalidPda);
+        require!(delegate_arb.bump == expected_delegate_bump, EscrowError::InvalidPda);
+        require!(delegate_arb.is_active(), EscrowError::ArbitratorNotActive);
+        delegate_arb.agent
+    };
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.require_revealed()?;
+    require!(dispute.is_voting_open(clock.unix_timestamp), EscrowError::VotingDeadlinePassed);
+
+    // The escrow's parties are barred from delegated votes as from the panel
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    require!(ctx.escrow.key() == &dispute.escrow, EscrowError::EscrowMismatch);
+    let parties = {
+        let escrow_data = ctx.escrow.try_borrow_data()?;
+        let escrow = JobEscrow::load(&escrow_data)?;
+        [escrow.poster, escrow.worker]
+    };
+
+    dispute.delegate_vote(ctx.arbitrator.key(), &delegate, &parties)?;
+
+    Ok(())
+}
+
+// ============== RECUSE ARBITRATOR ==============
+
+/// Recuse arbitrator accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. arbitrator (signer) - the selected arbitrator stepping down
+pub struct RecuseArbitratorAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RecuseArbitratorAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[dispute_case])?;
+
+        if !arbitrator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, arbitrator })
+    }
+}
+
+/// Process recuse_arbitrator instruction
+///
+/// A selected arbitrator with a conflict of interest steps down before
+/// voting. Their slot no longer counts towards the majority, cannot vote,
+/// and is not penalized as absent.
+pub fn process_recuse_arbitrator(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RecuseArbitratorAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.require_revealed()?;
+    dispute.recuse(ctx.arbitrator.key(), clock.unix_timestamp)
+}
+
+// ============== EXTEND VOTING ==============
+
+/// Extend voting accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. escrow
+/// 2. requester (signer) - poster or worker of the escrow
+/// 3. platform_authority (signer) - approves the extension
+/// 4. platform_config (readonly) - PDA ["platform_config"], names the authority
+pub struct ExtendVotingAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+    pub requester: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ExtendVotingAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, escrow, requester, platform_authority, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !requester.is_signer() || !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, escrow, requester, platform_authority, platform_config })
+    }
+}
+
+/// Instruction data for ExtendVoting
+/// Layout: [extension_seconds: i64]
+pub struct ExtendVotingData {
+    pub extension_seconds: i64,
+}
+
+impl ExtendVotingData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let extension_seconds = i64::from_le_bytes(data[0..8].try_into().unwrap());
+        Ok(Self { extension_seconds })
+    }
+}
+
+/// Push a dispute's voting deadline forward once, requested by either party
+/// and approved by the platform
+pub fn process_extend_voting(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ExtendVotingAccounts::try_from(accounts)?;
+    let args = ExtendVotingData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+    require!(&escrow.dispute_case == ctx.dispute_case.key(), EscrowError::EscrowMismatch);
+
+    let requester_key = ctx.requester.key();
+    require!(
+        requester_key == &escrow.poster || requester_key == &escrow.worker,
+        EscrowError::Unauthorized
+    );
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.extend_voting(args.extension_seconds, clock.unix_timestamp)?;
+
+    Ok(())
+}
+
+// ============== RECORD JOB ID ==============
+
+/// Record job id accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. escrow
+/// 2. party (signer) - poster or worker of the escrow
+pub struct RecordJobIdAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+    pub party: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RecordJobIdAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, escrow, party, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !party.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, escrow, party })
+    }
+}
+
+/// Anchor a dispute to the plaintext job_id
+///
+/// Instruction data is the raw job_id. It must hash (SHA-256) to the
+/// escrow's job_id_hash; a prefix is then stored on the dispute case.
+pub fn process_record_job_id(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RecordJobIdAccounts::try_from(accounts)?;
+
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+    require!(&escrow.dispute_case == ctx.dispute_case.key(), EscrowError::EscrowMismatch);
+
+    let party_key = ctx.party.key();
+    require!(
+        party_key == &escrow.poster || party_key == &escrow.worker,
+        EscrowError::Unauthorized
+    );
+
+    verify_job_id(data, &escrow.job_id_hash)?;
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    dispute.record_job_id(data);
+
+    Ok(())
+}
+
+// ============== FINALIZE DISPUTE CASE ==============
+
+/// Finalize dispute case accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. escrow (writable)
+/// 2. finalizer (signer)
+/// 3. platform_config (readonly) - PDA ["platform_config"]; may be uninitialized
+/// 4. worker_reputation (optional, readonly) - required to break a tie by reputation
+/// 5. poster_reputation (optional, readonly) - required to break a tie by reputation
+pub struct FinalizeDisputeCaseAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+    pub finalizer: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub reputations: Option<(&'a AccountInfo, &'a AccountInfo)>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for FinalizeDisputeCaseAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, escrow, finalizer, platform_config, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !finalizer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let reputations = match rest {
+            [worker_reputation, poster_reputation, ..] => Some((worker_reputation, poster_reputation)),
+            _ => None,
+        };
+
+        Ok(Self { dispute_case, escrow, finalizer, platform_config, reputations })
+    }
+}
+
+pub fn process_finalize_dispute_case(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = FinalizeDisputeCaseAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Load dispute case
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    require!(!dispute.is_resolved(), EscrowError::DisputeAlreadyResolved);
+    dispute.require_revealed()?;
+
+    // Early finalization may need more than a bare majority of votes cast
+    let settings = FinalizeSettings::load(ctx.platform_config, program_id)?;
+    require!(
+        dispute.can_finalize(clock.unix_timestamp, settings.min_early_votes),
+        EscrowError::VotingNotComplete
+    );
+
+    // Load and update escrow
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+
+    require!(&escrow.dispute_case == ctx.dispute_case.key(), EscrowError::EscrowMismatch);
+
+    // Determine resolution. A case that ended without a quorum of votes gets
+    // the configured no-quorum resolution rather than whatever a vote or two
+    // (or none) would give; quorate ties may be broken by the parties' reputation
+    let (for_worker, for_poster, _, projected) = dispute.summary();
+    let quorate = dispute.has_quorum();
+    let party_scores = if quorate && settings.reputation_tie_break && for_worker == for_poster {
+        let (worker_rep, poster_rep) = require_some!(ctx.reputations, ProgramError::NotEnoughAccountKeys);
+        Some((
+            party_score(worker_rep, &escrow.worker, clock.unix_timestamp, program_id)?,
+            party_score(poster_rep, &escrow.poster, clock.unix_timestamp, program_id)?,
+        ))
+    } else {
+        None
+    };
+    let resolution = match party_scores {
+        Some(scores) => DisputeResolution::from_votes(for_worker, for_poster, Some(scores)),
+        None => match projected {
+            Some(projected) if quorate => projected,
+            _ => settings.no_quorum_resolution,
+        },
+    };
+
+    dispute.resolution = resolution as u8;
+    dispute.finalized_at = clock.unix_timestamp;
+
+    escrow.status = match resolution {
+        DisputeResolution::WorkerWins => EscrowStatus::DisputeWorkerWins as u8,
+        DisputeResolution::PosterWins => EscrowStatus::DisputePosterWins as u8,
+        DisputeResolution::Split => EscrowStatus::DisputeSplit as u8,
+        _ => unreachable!(),
+    };
+
+    DisputeFinalized {
+        dispute_case: *ctx.dispute_case.key(),
+        escrow: *ctx.escrow.key(),
+        resolution: resolution as u8,
+        for_worker,
+        for_poster,
+    }
+    .emit();
+
+    Ok(())
+}
+
+/// Platform config settings that apply to finalization
+struct FinalizeSettings {
+    /// Votes that must be cast before finalizing ahead of the deadline
+    min_early_votes: u8,
+    /// Break ties by the parties' reputation instead of splitting
+    reputation_tie_break: bool,
+    /// Resolution when the deadline passed without a quorum
+    no_quorum_resolution: DisputeResolution,
+}
+
+impl Default for FinalizeSettings {
+    fn default() -> Self {
+        Self {
+            min_early_votes: 0,
+            reputation_tie_break: false,
+            no_quorum_resolution: DisputeResolution::PosterWins,
+        }
+    }
+}
+
+impl FinalizeSettings {
+    /// Read the settings from the platform config
+    ///
+    /// The account must be the config PDA; until the platform creates it, the
+    /// defaults apply (a majority is enough, ties split, no quorum refunds the poster).
+    fn load(platform_config: &AccountInfo, program_id: &Pubkey) -> Result<Self, ProgramError> {
+        let (expected_pda, _) = find_platform_config(program_id);
+        require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+        if *platform_config.owner() != ID {
+            return Ok(Self::default());
+        }
+        let config_data = platform_config.try_borrow_data()?;
+        let config = PlatformConfig::load(&config_data)?;
+        Ok(Self {
+            min_early_votes: config.min_early_votes,
+            reputation_tie_break: config.reputation_tie_break != 0,
+            no_quorum_resolution: config.no_quorum_resolution(),
+        })
+    }
+}
+
+/// Current reputation score of a dispute party (0 if they have no account yet)
+fn party_score(
+    reputation: &AccountInfo,
+    agent: &Pubkey,
+    now: i64,
+    program_id: &Pubkey,
+) -> Result<i64, ProgramError> {
+    // SECURITY FIX C-03: Verify reputation PDA derivation
+    let (expected_rep, _) = find_program_address(&[b"reputation", agent], program_id);
+    require!(reputation.key() == &expected_rep, EscrowError::InvalidPda);
+    if *reputation.owner() != ID {
+        return Ok(0);
+    }
+    let rep_data = reputation.try_borrow_data()?;
+    Ok(AgentReputation::load(&rep_data)?.effective_score(now))
+}
+
+// ============== CAN FINALIZE (View) ==============
+
+/// Can finalize accounts
+/// Accounts:
+/// 0. dispute_case
+/// 1. platform_config (readonly) - PDA ["platform_config"]; may be uninitialized
+pub struct CanFinalizeAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CanFinalizeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { dispute_case, platform_config })
+    }
+}
+
+/// Work out what finalize_dispute_case would do with `dispute` at `now`
+fn finalize_preview(
+    dispute_case: &Pubkey,
+    dispute: &DisputeCase,
+    now: i64,
+    settings: &FinalizeSettings,
+) -> FinalizePreview {
+    let (for_worker, for_poster, majority_reached, projected) = dispute.summary();
+    let resolved = dispute.is_resolved();
+    let quorate = dispute.has_quorum();
+    let resolution = match projected {
+        Some(projected) if quorate || resolved => projected,
+        _ => settings.no_quorum_resolution,
+    } as u8;
+    FinalizePreview {
+        dispute_case: *dispute_case,
+        can_finalize: !resolved
+            && dispute.is_revealed()
+            && dispute.can_finalize(now, settings.min_early_votes),
+        majority_reached,
+        deadline_passed: !dispute.is_voting_open(now),
+        for_worker,
+        for_poster,
+        resolution,
+        tie_break_pending: !resolved
+            && quorate
+            && settings.reputation_tie_break
+            && for_worker == for_poster,
+    }
+}
+
+/// Process can_finalize instruction
+///
+/// Read-only: lets keepers check whether cranking finalize_dispute_case
+/// would succeed, and with which resolution, without sending it.
+pub fn process_can_finalize(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = CanFinalizeAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = ctx.dispute_case.try_borrow_data()?;
+    let dispute = DisputeCase::load(&dispute_data)?;
+
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    let settings = FinalizeSettings::load(ctx.platform_config, program_id)?;
+    let preview = finalize_preview(ctx.dispute_case.key(), dispute, clock.unix_timestamp, &settings);
+    preview.emit();
+    set_return_data(&preview.to_bytes());
+
+    Ok(())
+}
+
+// ============== APPEAL DISPUTE ==============
+
+/// Appeal dispute accounts
+/// Accounts:
+/// 0. dispute_case (writable)
+/// 1. escrow (writable)
+/// 2. pool (readonly)
+/// 3. appellant (signer, writable) - the losing party; pays the bond and the new panel's vote fees
+/// 4. system_program
+///
+/// Like raising, the appeal only commits to a future slot; the new panel is
+/// seated by reveal_dispute_arbitrators.
+pub struct AppealDisputeAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub escrow: &'a AccountInfo,
+    pub pool: &'a AccountInfo,
+    pub appellant: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AppealDisputeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, escrow, pool, appellant, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[dispute_case, escrow, appellant])?;
+
+        if !appellant.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, escrow, pool, appellant, system_program })
+    }
+}
+
+/// Process appeal_dispute instruction
+///
+/// Within APPEAL_WINDOW_SECONDS of finalization, the losing party can post a
+/// bond and send the case to a larger panel that excludes the first one.
+/// Execution waits for the new panel; the bond is refunded if the outcome
+/// changes and goes to the platform otherwise.
+pub fn process_appeal_dispute(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = AppealDisputeAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-01: Verify pool account is owned by this program
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_escrow_pda, expected_escrow_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
+
+    let mut dispute_data = ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(&mut dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", ctx.escrow.key()],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+    require!(&escrow.dispute_case == ctx.dispute_case.key(), EscrowError::EscrowMismatch);
+
+    // Only the losing party may appeal, and only before execution
+    let status = EscrowStatus::from_u8(escrow.status);
+    require!(
+        matches!(
+            status,
+            Some(EscrowStatus::DisputeWorkerWins)
+                | Some(EscrowStatus::DisputePosterWins)
+                | Some(EscrowStatus::DisputeSplit)
+        ),
+        EscrowError::NotAppealable
+    );
+    let appellant_key = ctx.appellant.key();
+    let by_worker = appellant_key == &escrow.worker;
+    require!(by_worker || appellant_key == &escrow.poster, EscrowError::Unauthorized);
+    let appealed = require_some!(dispute.get_resolution(), EscrowError::NotAppealable);
+    require!(appealed.appealable_by(by_worker), EscrowError::Unauthorized);
+
+    let bond = DisputeCase::appeal_bond_for(dispute.disputed_amount);
+    dispute.start_appeal(appellant_key, bond, clock.unix_timestamp)?;
+
+    // The larger panel must still be seatable from the pool
+    let pool_data = ctx.pool.try_borrow_data()?;
+    let pool = ArbitratorPool::load(&pool_data)?;
+    require!(
+        pool.arbitrator_count as usize >= dispute.panel_len() || pool.is_extended(),
+        EscrowError::NotEnoughArbitrators
+    );
+    drop(pool_data);
+
+    // SECURITY FIX H-01: Commit to a future slot, as when the case was raised
+    let reveal_slot = clock.slot.saturating_add(DISPUTE_REVEAL_DELAY_SLOTS);
+    dispute.commit_selection(reveal_slot, selection_commitment(ctx.escrow.key(), reveal_slot));
+    // Provisional until reveal, so an unrevealed appeal can still be reclaimed
+    dispute.voting_deadline = clock.unix_timestamp + ARBITRATION_VOTING_SECONDS;
 
-    let bump_ref = &[bump];
-    let signer_seeds = seeds!(b"dispute", ctx.escrow.key(), bump_ref);
-    let signer = Signer::from(&signer_seeds);
+    // Every seat on the new panel is funded at the case's vote fee
+    let new_fees = (dispute.vote_fee as u64)
+        .checked_mul(dispute.panel_len() as u64)
+        .ok_or(EscrowError::ArithmeticOverflow)?;
+    dispute.vote_fee_pot = dispute.vote_fee_pot
+        .checked_add(new_fees)
+        .ok_or(EscrowError::ArithmeticOverflow)?;
 
-    CreateAccount {
-        from: ctx.initiator,
-        to: ctx.dispute_case,
-        lamports: rent_lamports,
-        space: DisputeCase::SPACE as u64,
-        owner: program_id,
-    }
-    .invoke_signed(&[signer])?;
+    let panel_size = dispute.panel_size;
 
-    // Initialize dispute case
-    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
-    let dispute = DisputeCase::init(dispute_data)?;
+    // Drop dispute borrow before transferring into it
+    drop(dispute_data);
 
-    dispute.escrow = *ctx.escrow.key();
-    dispute.raised_by = *ctx.initiator.key();
-    dispute.arbitrators = selected;
-    dispute.votes = [Vote::None as u8; ARBITRATORS_PER_DISPUTE];
-    dispute.voting_deadline = clock.unix_timestamp + ARBITRATION_VOTING_SECONDS;
-    dispute.resolution = DisputeResolution::Pending as u8;
-    dispute.created_at = clock.unix_timestamp;
-    dispute.bump = bump;
-    dispute.set_reason(args.reason)?;
+    Transfer {
+        from: ctx.appellant,
+        to: ctx.dispute_case,
+        lamports: bond.checked_add(new_fees).ok_or(EscrowError::ArithmeticOverflow)?,
+    }
+    .invoke()?;
 
-    // Update escrow status
     escrow.status = EscrowStatus::InArbitration as u8;
-    escrow.dispute_case = *ctx.dispute_case.key();
-    escrow.has_dispute_case = 1;
+
+    DisputeAppealed {
+        dispute_case: *ctx.dispute_case.key(),
+        escrow: *ctx.escrow.key(),
+        appellant: *appellant_key,
+        appealed_resolution: appealed as u8,
+        panel_size,
+        appeal_bond: bond,
+    }
+    .emit();
 
     Ok(())
 }
 
-// ============== CAST ARBITRATION VOTE ==============
+// ============== SETTLE APPEAL BOND ==============
 
-pub struct CastArbitrationVoteAccounts<'a> {
+/// Settle appeal bond accounts
+/// Accounts:
+/// 0. dispute_case (writable) - holds the bond
+/// 1. appellant (writable) - refunded if the appeal changed the outcome
+/// 2. platform (writable) - the platform authority, receives a failed appeal's bond
+/// 3. platform_config (readonly) - PDA ["platform_config"], names the authority
+pub struct SettleAppealBondAccounts<'a> {
     pub dispute_case: &'a AccountInfo,
-    pub arbitrator_account: &'a AccountInfo,
-    pub voter: &'a AccountInfo,
+    pub appellant: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for CastArbitrationVoteAccounts<'a> {
+impl<'a> TryFrom<&'a [AccountInfo]> for SettleAppealBondAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [dispute_case, arbitrator_account, voter, ..] = accounts else {
+        let [dispute_case, appellant, platform, platform_config, ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        if !voter.is_signer() {
-            return Err(ProgramError::MissingRequiredSignature);
-        }
-
-        Ok(Self { dispute_case, arbitrator_account, voter })
-    }
-}
-
-/// Instruction data for CastArbitrationVote
-pub struct CastArbitrationVoteData {
-    pub vote: Vote,
-}
+        require_writable(&[dispute_case, appellant, platform])?;
 
-impl CastArbitrationVoteData {
-    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
-        if data.is_empty() {
-            return Err(ProgramError::InvalidInstructionData);
-        }
-        let vote = Vote::from_u8(data[0]).ok_or(ProgramError::InvalidInstructionData)?;
-        Ok(Self { vote })
+        Ok(Self { dispute_case, appellant, platform, platform_config })
     }
 }
 
-pub fn process_cast_arbitration_vote(
+/// Process settle_appeal_bond instruction
+///
+/// Permissionless once the appealed case is finalized again.
+pub fn process_settle_appeal_bond(
     accounts: &[AccountInfo],
-    data: &[u8],
+    _data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
-    let ctx = CastArbitrationVoteAccounts::try_from(accounts)?;
-    let args = CastArbitrationVoteData::try_from_slice(data)?;
+    let ctx = SettleAppealBondAccounts::try_from(accounts)?;
     let clock = Clock::get()?;
-
-    // Validate vote is ForWorker or ForPoster (not None)
-    require!(args.vote != Vote::None, EscrowError::AlreadyVoted);
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify dispute_case account is owned by this program
     if *ctx.dispute_case.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // SECURITY FIX C-01: Verify arbitrator_account is owned by this program
-    if *ctx.arbitrator_account.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
-
-    // SECURITY FIX C-02: Verify arbitrator PDA derivation
-    let (expected_arb_pda, expected_arb_bump) = find_program_address(
-        &[b"arbitrator", ctx.voter.key()],
-        program_id,
-    );
-    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
-
-    // Load arbitrator
-    let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
-    let arb = ArbitratorEntry::load_mut(arb_data)?;
-
-    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
-    require!(arb.is_active(), EscrowError::ArbitratorNotActive);
-    require!(&arb.agent == ctx.voter.key(), EscrowError::Unauthorized);
-
-    // Load dispute case
     let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
     let dispute = DisputeCase::load_mut(dispute_data)?;
 
@@ -577,57 +2492,89 @@ pub fn process_cast_arbitration_vote(
     require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
     require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
 
-    require!(!dispute.is_resolved(), EscrowError::DisputeAlreadyResolved);
-    require!(clock.unix_timestamp < dispute.voting_deadline, EscrowError::VotingDeadlinePassed);
-
-    // Find voter's position
-    let position = require_some!(
-        dispute.find_arbitrator_position(ctx.voter.key()),
-        EscrowError::NotSelectedArbitrator
-    );
-
-    // Check not already voted
-    require!(dispute.votes[position] == Vote::None as u8, EscrowError::AlreadyVoted);
+    require!(dispute.appeal_bond > 0, EscrowError::NoAppealBond);
+    require!(dispute.is_settled(clock.unix_timestamp), EscrowError::DisputeNotResolved);
+    require!(ctx.appellant.key() == &dispute.appellant, EscrowError::Unauthorized);
 
-    // Cast vote
-    dispute.set_vote(position, args.vote);
-    // SECURITY FIX H-05: Use checked arithmetic
-    arb.cases_voted = arb.cases_voted.saturating_add(1);
+    let bond = dispute.appeal_bond;
+    let recipient = if dispute.appeal_overturned() { ctx.appellant } else { ctx.platform };
+    dispute.appeal_bond = 0;
+    transfer_lamports(ctx.dispute_case, recipient, bond)?;
 
     Ok(())
 }
 
-// ============== FINALIZE DISPUTE CASE ==============
+// ============== EXECUTE DISPUTE RESOLUTION ==============
 
-pub struct FinalizeDisputeCaseAccounts<'a> {
+/// Execute dispute resolution accounts
+/// Accounts:
+/// 0. dispute_case (readonly)
+/// 1. escrow (PDA, writable)
+/// 2. worker (writable)
+/// 3. poster (writable)
+/// 4. platform (writable) - the platform authority, per the platform config
+/// 5. worker_reputation (writable)
+/// 6. poster_reputation (writable)
+/// 7. executor (signer)
+/// 8. platform_config (readonly) - PDA ["platform_config"], names the authority
+/// 9. escrow_token_account (token escrows only, writable)
+/// 10. worker_token_account (token escrows only, writable)
+/// 11. poster_token_account (token escrows only, writable)
+/// 12. platform_token_account (token escrows only, writable)
+/// 13. token_program (token escrows only)
+pub struct ExecuteDisputeResolutionAccounts<'a> {
     pub dispute_case: &'a AccountInfo,
     pub escrow: &'a AccountInfo,
-    pub finalizer: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub worker_reputation: &'a AccountInfo,
+    pub poster_reputation: &'a AccountInfo,
+    pub executor: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub tokens: Option<DisputeTokenAccounts<'a>>,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for FinalizeDisputeCaseAccounts<'a> {
+impl<'a> TryFrom<&'a [AccountInfo]> for ExecuteDisputeResolutionAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [dispute_case, escrow, finalizer, ..] = accounts else {
+        let [dispute_case, escrow, worker, poster, platform, worker_reputation, poster_reputation, executor, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
-        if !finalizer.is_signer() {
+        require_writable(&[escrow, worker, poster, platform, worker_reputation, poster_reputation])?;
+
+        if !executor.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { dispute_case, escrow, finalizer })
+        // Token accounts are only needed (and only parsed) for token escrows
+        let tokens = if rest.is_empty() { None } else { Some(DisputeTokenAccounts::try_from(rest)?) };
+
+        Ok(Self {
+            dispute_case,
+            escrow,
+            worker,
+            poster,
+            platform,
+            worker_reputation,
+            poster_reputation,
+            executor,
+            platform_config,
+            tokens,
+        })
     }
 }
 
-pub fn process_finalize_dispute_case(
+pub fn process_execute_dispute_resolution(
     accounts: &[AccountInfo],
     _data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
-    let ctx = FinalizeDisputeCaseAccounts::try_from(accounts)?;
+    let ctx = ExecuteDisputeResolutionAccounts::try_from(accounts)?;
     let clock = Clock::get()?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify dispute_case account is owned by this program
     if *ctx.dispute_case.owner() != ID {
@@ -639,9 +2586,17 @@ pub fn process_finalize_dispute_case(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    // SECURITY FIX C-01: Verify reputation accounts are owned by this program
+    if *ctx.worker_reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.poster_reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
     // Load dispute case
-    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
-    let dispute = DisputeCase::load_mut(dispute_data)?;
+    let dispute_data = ctx.dispute_case.try_borrow_data()?;
+    let dispute = DisputeCase::load(&dispute_data)?;
 
     // SECURITY FIX C-02: Verify dispute_case PDA derivation
     let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
@@ -651,27 +2606,19 @@ pub fn process_finalize_dispute_case(
     require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
     require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
 
-    require!(!dispute.is_resolved(), EscrowError::DisputeAlreadyResolved);
-
-    // Count votes
-    let (for_worker, for_poster) = dispute.count_votes();
-    let has_majority = for_worker >= ARBITRATION_MAJORITY || for_poster >= ARBITRATION_MAJORITY;
-    let deadline_passed = clock.unix_timestamp >= dispute.voting_deadline;
-
-    require!(has_majority || deadline_passed, EscrowError::VotingNotComplete);
-
-    // Determine resolution
-    let resolution = if for_worker > for_poster {
-        DisputeResolution::WorkerWins
-    } else if for_poster > for_worker {
-        DisputeResolution::PosterWins
-    } else {
-        DisputeResolution::Split
-    };
+    let resolution = require_some!(
+        DisputeResolution::from_u8(dispute.resolution),
+        EscrowError::DisputeNotResolved
+    );
+    require!(resolution != DisputeResolution::Pending, EscrowError::DisputeNotResolved);
+    // Nothing is paid out while the losing party may still appeal
+    require!(!dispute.is_appeal_open(clock.unix_timestamp), EscrowError::AppealWindowOpen);
+    let disputed_amount = dispute.disputed_amount;
 
-    dispute.resolution = resolution as u8;
+    // Drop dispute borrow
+    drop(dispute_data);
 
-    // Load and update escrow
+    // Load escrow
     let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
     let escrow = JobEscrow::load_mut(escrow_data)?;
 
@@ -683,71 +2630,286 @@ pub fn process_finalize_dispute_case(
     require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
 
-    require!(&escrow.dispute_case == ctx.dispute_case.key(), EscrowError::EscrowMismatch);
+    require!(
+        escrow.status == EscrowStatus::DisputeWorkerWins as u8 ||
+        escrow.status == EscrowStatus::DisputePosterWins as u8 ||
+        escrow.status == EscrowStatus::DisputeSplit as u8,
+        EscrowError::InvalidStatusForExecution
+    );
 
-    escrow.status = match resolution {
-        DisputeResolution::WorkerWins => EscrowStatus::DisputeWorkerWins as u8,
-        DisputeResolution::PosterWins => EscrowStatus::DisputePosterWins as u8,
-        DisputeResolution::Split => EscrowStatus::DisputeSplit as u8,
-        _ => unreachable!(),
-    };
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+
+    // SECURITY FIX C-03: Verify worker reputation PDA derivation
+    let (expected_worker_rep, _) = find_program_address(
+        &[b"reputation", &escrow.worker],
+        program_id,
+    );
+    require!(ctx.worker_reputation.key() == &expected_worker_rep, EscrowError::InvalidPda);
+
+    // SECURITY FIX C-03: Verify poster reputation PDA derivation
+    let (expected_poster_rep, _) = find_program_address(
+        &[b"reputation", &escrow.poster],
+        program_id,
+    );
+    require!(ctx.poster_reputation.key() == &expected_poster_rep, EscrowError::InvalidPda);
+
+    let amount = escrow.remaining_amount();
+
+    // Load reputations
+    let worker_rep_data = &mut ctx.worker_reputation.try_borrow_mut_data()?;
+    let worker_rep = AgentReputation::load_mut(worker_rep_data)?;
+
+    let poster_rep_data = &mut ctx.poster_reputation.try_borrow_mut_data()?;
+    let poster_rep = AgentReputation::load_mut(poster_rep_data)?;
+
+    let payout = DisputePayout::for_partial_dispute(resolution, amount, disputed_amount)?;
+
+    if escrow.is_token_escrow() {
+        let tokens = require_some!(ctx.tokens.as_ref(), ProgramError::NotEnoughAccountKeys);
+        pay_dispute_in_tokens(escrow, ctx.escrow, tokens, ctx.platform_config, &payout, program_id)?;
+    } else {
+        safe_pay_from_escrow(ctx.escrow, ctx.worker, payout.worker)?;
+        safe_pay_from_escrow(ctx.escrow, ctx.poster, payout.poster)?;
+        safe_pay_from_escrow(ctx.escrow, ctx.platform, payout.platform)?;
+    }
+
+    // Return a posted worker deposit, unless the worker lost the dispute
+    let deposit_recipient = if resolution.worker_keeps_deposit() { ctx.worker } else { ctx.poster };
+    settle_worker_deposit(escrow, ctx.escrow, deposit_recipient)?;
+
+    finish_dispute_payout(escrow, resolution);
+
+    ResolutionExecuted {
+        dispute_case: *ctx.dispute_case.key(),
+        escrow: *ctx.escrow.key(),
+        resolution: resolution as u8,
+        worker_amount: payout.worker,
+        poster_amount: payout.poster,
+        platform_fee: payout.platform,
+    }
+    .emit();
+
+    record_dispute_outcome(resolution, worker_rep, poster_rep, clock.unix_timestamp);
+
+    Ok(())
+}
+
+/// Terminal escrow status after paying out a resolution
+#[inline(always)]
+fn resolved_status(resolution: DisputeResolution) -> u8 {
+    match resolution {
+        DisputeResolution::PosterWins => EscrowStatus::Refunded as u8,
+        _ => EscrowStatus::Released as u8,
+    }
+}
+
+/// Mark a resolved escrow as paid out
+///
+/// Only called once every transfer has gone through. A failed transfer
+/// reverts the whole instruction, leaving the escrow in its Dispute* status
+/// with the full amount remaining, so execution can simply be retried (e.g.
+/// after a frozen token account is thawed).
+#[inline(always)]
+fn finish_dispute_payout(escrow: &mut JobEscrow, resolution: DisputeResolution) {
+    escrow.released_amount = escrow.amount;
+    escrow.status = resolved_status(resolution);
+}
+
+/// Credit the dispute win/loss to each party and refresh their scores
+///
+/// A resolved dispute counts as activity for both, restarting reputation decay.
+fn record_dispute_outcome(
+    resolution: DisputeResolution,
+    worker_rep: &mut AgentReputation,
+    poster_rep: &mut AgentReputation,
+    now: i64,
+) {
+    // SECURITY FIX H-05: Use saturating arithmetic
+    match resolution {
+        DisputeResolution::WorkerWins => {
+            worker_rep.disputes_won = worker_rep.disputes_won.saturating_add(1);
+            poster_rep.disputes_lost = poster_rep.disputes_lost.saturating_add(1);
+        }
+        DisputeResolution::PosterWins => {
+            poster_rep.disputes_won = poster_rep.disputes_won.saturating_add(1);
+            worker_rep.disputes_lost = worker_rep.disputes_lost.saturating_add(1);
+        }
+        _ => {}
+    }
+
+    worker_rep.last_active_at = now;
+    poster_rep.last_active_at = now;
+    worker_rep.update_score();
+    poster_rep.update_score();
+}
+
+// ============== EXECUTE TOKEN DISPUTE RESOLUTION ==============
+
+/// Token accounts for paying out a token escrow's dispute
+/// Accounts:
+/// 0. escrow_token_account (writable) - escrow's token account
+/// 1. worker_token_account (writable) - owned by the worker
+/// 2. poster_token_account (writable) - owned by the poster
+/// 3. platform_token_account (writable) - platform's ATA, or a platform vault token account
+/// 4. token_program
+pub struct DisputeTokenAccounts<'a> {
+    pub escrow_token_account: &'a AccountInfo,
+    pub worker_token_account: &'a AccountInfo,
+    pub poster_token_account: &'a AccountInfo,
+    pub platform_token_account: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DisputeTokenAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow_token_account, worker_token_account, poster_token_account, platform_token_account, token_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // Verify token program ID
+        if token_program.key() != &TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self {
+            escrow_token_account,
+            worker_token_account,
+            poster_token_account,
+            platform_token_account,
+            token_program,
+        })
+    }
+}
+
+/// Pay a resolved token escrow's dispute out of its token account
+///
+/// Destination accounts must hold the escrow's mint and belong to the worker,
+/// the poster, and the platform (authority or vault) respectively.
+fn pay_dispute_in_tokens(
+    escrow: &JobEscrow,
+    escrow_account: &AccountInfo,
+    tokens: &DisputeTokenAccounts,
+    platform_config: &AccountInfo,
+    payout: &DisputePayout,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    require!(tokens.escrow_token_account.key() == &escrow.escrow_token_account, EscrowError::TokenAccountMismatch);
+
+    let worker_token = TokenAccountInfo::from_account(tokens.worker_token_account)?;
+    require!(worker_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    require!(worker_token.owner == escrow.worker, EscrowError::WorkerMismatch);
+
+    let poster_token = TokenAccountInfo::from_account(tokens.poster_token_account)?;
+    require!(poster_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    require!(poster_token.owner == escrow.poster, EscrowError::PosterMismatch);
+
+    let platform_token = TokenAccountInfo::from_account(tokens.platform_token_account)?;
+    require!(platform_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    let authority = platform_authority(platform_config, program_id)?;
+    let (platform_vault, _) = find_platform_vault(program_id);
+    require!(
+        is_platform_fee_owner(&platform_token.owner, &authority, &platform_vault),
+        EscrowError::InvalidFeeRecipient
+    );
+
+    // Create PDA signer for token transfers
+    let bump_ref = &[escrow.bump];
+    let signer_seeds = seeds!(b"escrow", &escrow.job_id_hash, &escrow.poster, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    for (to, amount) in [
+        (tokens.worker_token_account, payout.worker),
+        (tokens.poster_token_account, payout.poster),
+        (tokens.platform_token_account, payout.platform),
+    ] {
+        if amount > 0 {
+            TokenTransfer {
+                from: tokens.escrow_token_account,
+                to,
+                authority: escrow_account,
+                amount,
+            }
+            .invoke_signed(core::slice::from_ref(&signer))?;
+        }
+    }
 
     Ok(())
 }
 
-// ============== EXECUTE DISPUTE RESOLUTION ==============
-
-pub struct ExecuteDisputeResolutionAccounts<'a> {
+/// Execute token dispute resolution accounts
+/// Accounts:
+/// 0. dispute_case (readonly)
+/// 1. escrow (PDA, writable)
+/// 2. worker_reputation (writable)
+/// 3. poster_reputation (writable)
+/// 4. executor (signer)
+/// 5. platform_config (readonly) - PDA ["platform_config"], names the fee recipient
+/// 6. escrow_token_account (writable)
+/// 7. worker_token_account (writable)
+/// 8. poster_token_account (writable)
+/// 9. platform_token_account (writable)
+/// 10. token_program
+/// 11. bond_recipient (writable, optional) - the worker, or the poster if the
+///     worker lost; required if the escrow holds a worker bond
+pub struct ExecuteTokenDisputeResolutionAccounts<'a> {
     pub dispute_case: &'a AccountInfo,
     pub escrow: &'a AccountInfo,
-    pub worker: &'a AccountInfo,
-    pub poster: &'a AccountInfo,
-    pub platform: &'a AccountInfo,
     pub worker_reputation: &'a AccountInfo,
     pub poster_reputation: &'a AccountInfo,
     pub executor: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub tokens: DisputeTokenAccounts<'a>,
+    pub bond_recipient: Option<&'a AccountInfo>,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for ExecuteDisputeResolutionAccounts<'a> {
+impl<'a> TryFrom<&'a [AccountInfo]> for ExecuteTokenDisputeResolutionAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [dispute_case, escrow, worker, poster, platform, worker_reputation, poster_reputation, executor, ..] = accounts else {
+        let [dispute_case, escrow, worker_reputation, poster_reputation, executor, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker_reputation, poster_reputation])?;
+
         if !executor.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(platform.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
-        Ok(Self { dispute_case, escrow, worker, poster, platform, worker_reputation, poster_reputation, executor })
+        Ok(Self {
+            dispute_case,
+            escrow,
+            worker_reputation,
+            poster_reputation,
+            executor,
+            platform_config,
+            tokens: DisputeTokenAccounts::try_from(rest)?,
+            bond_recipient: rest.get(5),
+        })
     }
 }
 
-pub fn process_execute_dispute_resolution(
+/// Process execute_token_dispute_resolution instruction
+///
+/// Token-only form of execute_dispute_resolution that skips the SOL
+/// worker/poster/platform accounts; only a held worker bond needs its
+/// recipient passed.
+pub fn process_execute_token_dispute_resolution(
     accounts: &[AccountInfo],
     _data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
-    let ctx = ExecuteDisputeResolutionAccounts::try_from(accounts)?;
-
-    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
-    if *ctx.dispute_case.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
-
-    // SECURITY FIX C-01: Verify escrow account is owned by this program
-    if *ctx.escrow.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
+    let ctx = ExecuteTokenDisputeResolutionAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
 
-    // SECURITY FIX C-01: Verify reputation accounts are owned by this program
-    if *ctx.worker_reputation.owner() != ID {
+    // SECURITY FIX C-01: Verify program-owned accounts
+    if *ctx.dispute_case.owner() != ID || *ctx.escrow.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
-    if *ctx.poster_reputation.owner() != ID {
+    if *ctx.worker_reputation.owner() != ID || *ctx.poster_reputation.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
 
@@ -768,6 +2930,9 @@ pub fn process_execute_dispute_resolution(
         EscrowError::DisputeNotResolved
     );
     require!(resolution != DisputeResolution::Pending, EscrowError::DisputeNotResolved);
+    // Nothing is paid out while the losing party may still appeal
+    require!(!dispute.is_appeal_open(clock.unix_timestamp), EscrowError::AppealWindowOpen);
+    let disputed_amount = dispute.disputed_amount;
 
     // Drop dispute borrow
     drop(dispute_data);
@@ -784,6 +2949,9 @@ pub fn process_execute_dispute_resolution(
     require!(ctx.escrow.key() == &expected_escrow_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_escrow_bump, EscrowError::InvalidPda);
 
+    // Must be a token escrow
+    require!(escrow.is_token_escrow(), EscrowError::NotTokenEscrow);
+
     require!(
         escrow.status == EscrowStatus::DisputeWorkerWins as u8 ||
         escrow.status == EscrowStatus::DisputePosterWins as u8 ||
@@ -791,101 +2959,101 @@ pub fn process_execute_dispute_resolution(
         EscrowError::InvalidStatusForExecution
     );
 
-    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
-    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
-
-    // SECURITY FIX C-03: Verify worker reputation PDA derivation
+    // SECURITY FIX C-03: Verify reputation PDA derivations
     let (expected_worker_rep, _) = find_program_address(
         &[b"reputation", &escrow.worker],
         program_id,
     );
     require!(ctx.worker_reputation.key() == &expected_worker_rep, EscrowError::InvalidPda);
-
-    // SECURITY FIX C-03: Verify poster reputation PDA derivation
     let (expected_poster_rep, _) = find_program_address(
         &[b"reputation", &escrow.poster],
         program_id,
     );
     require!(ctx.poster_reputation.key() == &expected_poster_rep, EscrowError::InvalidPda);
 
-    let amount = escrow.amount;
+    let payout = DisputePayout::for_partial_dispute(resolution, escrow.remaining_amount(), disputed_amount)?;
+    pay_dispute_in_tokens(escrow, ctx.escrow, &ctx.tokens, ctx.platform_config, &payout, program_id)?;
+
+    // Return the worker bond, unless the worker lost the dispute
+    if escrow.holds_worker_bond() {
+        let recipient = require_some!(ctx.bond_recipient, ProgramError::NotEnoughAccountKeys);
+        let expected = if resolution.worker_keeps_deposit() { &escrow.worker } else { &escrow.poster };
+        require!(recipient.key() == expected, EscrowError::Unauthorized);
+        require_writable(&[recipient])?;
+        settle_worker_deposit(escrow, ctx.escrow, recipient)?;
+    }
 
-    // Load reputations
     let worker_rep_data = &mut ctx.worker_reputation.try_borrow_mut_data()?;
     let worker_rep = AgentReputation::load_mut(worker_rep_data)?;
-
     let poster_rep_data = &mut ctx.poster_reputation.try_borrow_mut_data()?;
     let poster_rep = AgentReputation::load_mut(poster_rep_data)?;
+    record_dispute_outcome(resolution, worker_rep, poster_rep, clock.unix_timestamp);
 
-    match resolution {
-        DisputeResolution::WorkerWins => {
-            // SECURITY FIX H-05: Use checked arithmetic
-            let platform_fee = amount.checked_div(100).unwrap_or(0);
-            let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
-
-            transfer_lamports(ctx.escrow, ctx.worker, worker_payment)?;
-            transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
-
-            // SECURITY FIX H-05: Use saturating arithmetic
-            worker_rep.disputes_won = worker_rep.disputes_won.saturating_add(1);
-            poster_rep.disputes_lost = poster_rep.disputes_lost.saturating_add(1);
-
-            escrow.status = EscrowStatus::Released as u8;
-        }
-        DisputeResolution::PosterWins => {
-            transfer_lamports(ctx.escrow, ctx.poster, amount)?;
-
-            // SECURITY FIX H-05: Use saturating arithmetic
-            poster_rep.disputes_won = poster_rep.disputes_won.saturating_add(1);
-            worker_rep.disputes_lost = worker_rep.disputes_lost.saturating_add(1);
+    finish_dispute_payout(escrow, resolution);
 
-            escrow.status = EscrowStatus::Refunded as u8;
-        }
-        DisputeResolution::Split => {
-            // SECURITY FIX H-05: Use checked arithmetic
-            let platform_fee = amount.checked_div(100).unwrap_or(0);
-            let remaining = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
-            let worker_half = remaining.checked_div(2).unwrap_or(0);
-            let poster_half = remaining.checked_sub(worker_half).ok_or(EscrowError::ArithmeticOverflow)?;
-
-            transfer_lamports(ctx.escrow, ctx.worker, worker_half)?;
-            transfer_lamports(ctx.escrow, ctx.poster, poster_half)?;
-            transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
-
-            escrow.status = EscrowStatus::Released as u8;
-        }
-        _ => {}
+    ResolutionExecuted {
+        dispute_case: *ctx.dispute_case.key(),
+        escrow: *ctx.escrow.key(),
+        resolution: resolution as u8,
+        worker_amount: payout.worker,
+        poster_amount: payout.poster,
+        platform_fee: payout.platform,
     }
-
-    worker_rep.update_score();
-    poster_rep.update_score();
+    .emit();
 
     Ok(())
 }
 
 // ============== UPDATE ARBITRATOR ACCURACY ==============
 
+/// Update arbitrator accuracy accounts
+/// Accounts:
+/// 0. dispute_case (writable) - counts the judged vote
+/// 1. arbitrator_account (writable)
+/// 2. accuracy_claim (PDA, writable) - created here
+/// 3. caller (signer, writable) - pays for the claim
+/// 4. system_program
+/// 5. platform (writable) - the platform authority, receives slashed stake
+/// 6. pool (writable) - caches the arbitrator's new accuracy for selection
+/// 7. platform_config (readonly) - PDA ["platform_config"], names the authority
+/// 8. pool_extension (optional, writable) - required if the arbitrator is in it
 pub struct UpdateArbitratorAccuracyAccounts<'a> {
     pub dispute_case: &'a AccountInfo,
     pub arbitrator_account: &'a AccountInfo,
     pub accuracy_claim: &'a AccountInfo,
     pub caller: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub pool: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for UpdateArbitratorAccuracyAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [dispute_case, arbitrator_account, accuracy_claim, caller, system_program, ..] = accounts else {
+        let [dispute_case, arbitrator_account, accuracy_claim, caller, system_program, platform, pool, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[dispute_case, arbitrator_account, platform, pool])?;
+
         if !caller.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { dispute_case, arbitrator_account, accuracy_claim, caller, system_program })
+        Ok(Self {
+            dispute_case,
+            arbitrator_account,
+            accuracy_claim,
+            caller,
+            system_program,
+            platform,
+            pool,
+            platform_config,
+            pool_extension: rest.first(),
+        })
     }
 }
 
@@ -896,6 +3064,7 @@ pub fn process_update_arbitrator_accuracy(
 ) -> ProgramResult {
     let ctx = UpdateArbitratorAccuracyAccounts::try_from(accounts)?;
     let clock = Clock::get()?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify dispute_case account is owned by this program
     if *ctx.dispute_case.owner() != ID {
@@ -907,6 +3076,15 @@ pub fn process_update_arbitrator_accuracy(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    // SECURITY FIX C-01: Verify pool account is owned by this program
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX H-03: Verify pool PDA derivation
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
     // Load dispute case
     let dispute_data = ctx.dispute_case.try_borrow_data()?;
     let dispute = DisputeCase::load(&dispute_data)?;
@@ -924,6 +3102,8 @@ pub fn process_update_arbitrator_accuracy(
         EscrowError::DisputeNotResolved
     );
     require!(resolution != DisputeResolution::Pending, EscrowError::DisputeNotResolved);
+    // Votes are judged against the final outcome, after any appeal
+    require!(!dispute.is_appeal_open(clock.unix_timestamp), EscrowError::AppealWindowOpen);
 
     // Load arbitrator
     let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
@@ -937,20 +3117,16 @@ pub fn process_update_arbitrator_accuracy(
     require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
     require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
 
-    // Find this arbitrator's position and vote
-    let position = require_some!(
-        dispute.find_arbitrator_position(&arb.agent),
-        EscrowError::NotSelectedArbitrator
-    );
-
+    // Find this arbitrator's vote, on the final panel or the appealed one
     let vote = require_some!(
-        Vote::from_u8(dispute.votes[position]),
-        EscrowError::ArbitratorDidNotVote
+        dispute.recorded_vote(&arb.agent),
+        EscrowError::NotSelectedArbitrator
     );
     require!(vote != Vote::None, EscrowError::ArbitratorDidNotVote);
 
     // Drop dispute borrow
     let arb_agent = arb.agent;
+    let disputed_amount = dispute.disputed_amount;
     drop(dispute_data);
 
     // Verify and create accuracy claim PDA
@@ -977,27 +3153,304 @@ pub fn process_update_arbitrator_accuracy(
     }
     .invoke_signed(&[signer])?;
 
-    // Initialize accuracy claim
+    // Determine if vote was correct
+    let voted_correctly = match (vote, resolution) {
+        (Vote::ForWorker, DisputeResolution::WorkerWins) => true,
+        (Vote::ForPoster, DisputeResolution::PosterWins) => true,
+        (_, DisputeResolution::Split) => true, // Split = both considered correct
+        _ => false,
+    };
+
+    // Slashes never take the entry below its rent-exempt minimum
+    let account_balance = *ctx.arbitrator_account.try_borrow_lamports()?;
+    let available = account_balance.saturating_sub(rent.minimum_balance(ArbitratorEntry::SPACE));
+
+    // Initialize accuracy claim and judge the vote, releasing its stake reserve
     let claim_data = &mut ctx.accuracy_claim.try_borrow_mut_data()?;
-    let claim = AccuracyClaim::init(claim_data)?;
+    let slash = arb.judge_vote(claim_data, voted_correctly, disputed_amount, available)?;
 
+    let claim = AccuracyClaim::load_mut(claim_data)?;
     claim.dispute_case = *ctx.dispute_case.key();
     claim.arbitrator = arb_agent;
     claim.claimed_at = clock.unix_timestamp;
     claim.bump = bump;
+    claim.rent_payer = *ctx.caller.key();
 
-    // Determine if vote was correct
-    let voted_correctly = match (vote, resolution) {
-        (Vote::ForWorker, DisputeResolution::WorkerWins) => true,
-        (Vote::ForPoster, DisputeResolution::PosterWins) => true,
-        (_, DisputeResolution::Split) => true, // Split = both considered correct
-        _ => false,
+    {
+        let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+        DisputeCase::load_mut(dispute_data)?.record_judged_vote();
+    }
+
+    if slash > 0 {
+        let arb_lamports = &mut *ctx.arbitrator_account.try_borrow_mut_lamports()?;
+        *arb_lamports = arb_lamports.checked_sub(slash).ok_or(EscrowError::ArithmeticOverflow)?;
+        let platform_lamports = &mut *ctx.platform.try_borrow_mut_lamports()?;
+        *platform_lamports = platform_lamports.checked_add(slash).ok_or(EscrowError::ArithmeticOverflow)?;
+    }
+
+    // Refresh the accuracy used to weight dispute selection
+    let segment = pool_segment_of(ctx.pool, ctx.pool_extension, &arb_agent, program_id)?;
+    let pool_data = &mut segment.try_borrow_mut_data()?;
+    let pool = ArbitratorPool::load_mut(pool_data)?;
+    pool.set_accuracy(&arb_agent, arb.accuracy_bps());
+
+    Ok(())
+}
+
+// ============== PENALIZE ABSENT ARBITRATOR ==============
+
+/// Penalize absent arbitrator accounts
+/// Accounts:
+/// 0. dispute_case (readonly) - must be resolved
+/// 1. arbitrator_account (writable) - entry of a selected arbitrator who did not vote
+/// 2. absence_record (PDA, writable) - ["absence", dispute_case, arbitrator], created here
+/// 3. caller (signer, writable) - pays for the record
+/// 4. system_program
+pub struct PenalizeAbsentArbitratorAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+    pub absence_record: &'a AccountInfo,
+    pub caller: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PenalizeAbsentArbitratorAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator_account, absence_record, caller, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[arbitrator_account, absence_record, caller])?;
+
+        if !caller.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, arbitrator_account, absence_record, caller, system_program })
+    }
+}
+
+/// Process penalize_absent_arbitrator instruction
+///
+/// Permissionless: anyone can record that a selected arbitrator let a
+/// resolved case pass without voting. The absence record PDA makes it count
+/// only once per case.
+pub fn process_penalize_absent_arbitrator(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = PenalizeAbsentArbitratorAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify program-owned accounts
+    if *ctx.dispute_case.owner() != ID || *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = ctx.dispute_case.try_borrow_data()?;
+    let dispute = DisputeCase::load(&dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    let arb_data = &mut ctx.arbitrator_account.try_borrow_mut_data()?;
+    let arb = ArbitratorEntry::load_mut(arb_data)?;
+
+    // SECURITY FIX C-02: Verify arbitrator PDA derivation
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", &arb.agent],
+        program_id,
+    );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+
+    let position = require_some!(
+        dispute.find_arbitrator_position(&arb.agent),
+        EscrowError::NotSelectedArbitrator
+    );
+    dispute.require_absent(position)?;
+    require!(!dispute.is_appeal_open(clock.unix_timestamp), EscrowError::AppealWindowOpen);
+
+    let arb_agent = arb.agent;
+    drop(dispute_data);
+
+    // Verify and create the absence record (prevents counting twice)
+    let (expected_pda, bump) = find_program_address(
+        &[b"absence", ctx.dispute_case.key(), &arb_agent],
+        program_id,
+    );
+    require!(ctx.absence_record.key() == &expected_pda, EscrowError::InvalidPda);
+
+    let rent_lamports = Rent::get()?.minimum_balance(AbsenceRecord::SPACE);
+
+    let bump_ref = &[bump];
+    let signer_seeds = seeds!(b"absence", ctx.dispute_case.key(), &arb_agent, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: ctx.caller,
+        to: ctx.absence_record,
+        lamports: rent_lamports,
+        space: AbsenceRecord::SPACE as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&[signer])?;
+
+    let record_data = &mut ctx.absence_record.try_borrow_mut_data()?;
+    arb.record_missed_case(record_data)?;
+
+    let record = AbsenceRecord::load_mut(record_data)?;
+
+    record.dispute_case = *ctx.dispute_case.key();
+    record.arbitrator = arb_agent;
+    record.claimed_at = clock.unix_timestamp;
+    record.bump = bump;
+    record.rent_payer = *ctx.caller.key();
+
+    Ok(())
+}
+
+// ============== CLAIM VOTE FEE ==============
+
+/// Claim vote fee accounts
+/// Accounts:
+/// 0. dispute_case (writable) - holds the vote fee pot
+/// 1. vote_fee_claim (PDA, writable) - ["vote_fee_claim", dispute_case, arbitrator], created here
+/// 2. arbitrator (signer, writable) - selected arbitrator who voted; pays for the claim, receives the share
+/// 3. system_program
+/// 4. arbitrator_account (optional, readonly) - the arbitrator's entry; scales the share by accuracy
+pub struct ClaimVoteFeeAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub vote_fee_claim: &'a AccountInfo,
+    pub arbitrator: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub arbitrator_account: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimVoteFeeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, vote_fee_claim, arbitrator, system_program, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[dispute_case, vote_fee_claim, arbitrator])?;
+
+        if !arbitrator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { dispute_case, vote_fee_claim, arbitrator, system_program, arbitrator_account: rest.first() })
+    }
+}
+
+/// Process claim_vote_fee instruction
+///
+/// Pays a voting arbitrator their share of the vote fee pot once the case is
+/// resolved. The claim PDA makes a second claim fail. With their entry
+/// attached, accurate arbitrators earn up to 1.5x from shares forfeited by
+/// non-voters.
+pub fn process_claim_vote_fee(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ClaimVoteFeeAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify dispute_case account is owned by this program
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = &mut ctx.dispute_case.try_borrow_mut_data()?;
+    let dispute = DisputeCase::load_mut(dispute_data)?;
+
+    // SECURITY FIX C-02: Verify dispute_case PDA derivation
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    require!(!dispute.is_appeal_open(clock.unix_timestamp), EscrowError::AppealWindowOpen);
+    dispute.claim_vote_fee(ctx.arbitrator.key())?;
+
+    // Accuracy multiplier, 1x unless the arbitrator's entry is attached
+    let multiplier_bps = match ctx.arbitrator_account {
+        Some(arbitrator_account) => {
+            // SECURITY FIX C-01: Verify arbitrator_account is owned by this program
+            if *arbitrator_account.owner() != ID {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            let arb_data = arbitrator_account.try_borrow_data()?;
+            let arb = ArbitratorEntry::load(&arb_data)?;
+
+            // SECURITY FIX C-02: Verify arbitrator PDA derivation
+            let (expected_arb_pda, expected_arb_bump) = find_program_address(
+                &[b"arbitrator", ctx.arbitrator.key()],
+                program_id,
+            );
+            require!(arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+            require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+            arb.reward_multiplier_bps()
+        }
+        None => 10_000,
     };
 
-    if voted_correctly {
-        // SECURITY FIX H-05: Use saturating arithmetic
-        arb.cases_correct = arb.cases_correct.saturating_add(1);
+    // Verify and create the claim PDA (prevents duplicate claims)
+    let (expected_pda, bump) = find_program_address(
+        &[b"vote_fee_claim", ctx.dispute_case.key(), ctx.arbitrator.key()],
+        program_id,
+    );
+    require!(ctx.vote_fee_claim.key() == &expected_pda, EscrowError::InvalidPda);
+
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(VoteFeeClaim::SPACE);
+
+    let bump_ref = &[bump];
+    let signer_seeds = seeds!(b"vote_fee_claim", ctx.dispute_case.key(), ctx.arbitrator.key(), bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: ctx.arbitrator,
+        to: ctx.vote_fee_claim,
+        lamports: rent_lamports,
+        space: VoteFeeClaim::SPACE as u64,
+        owner: program_id,
     }
+    .invoke_signed(&[signer])?;
+
+    let claim_data = &mut ctx.vote_fee_claim.try_borrow_mut_data()?;
+    let claim = VoteFeeClaim::init(claim_data)?;
+
+    claim.dispute_case = *ctx.dispute_case.key();
+    claim.arbitrator = *ctx.arbitrator.key();
+    claim.claimed_at = clock.unix_timestamp;
+    claim.bump = bump;
+    claim.rent_payer = *ctx.arbitrator.key();
+
+    // The share must come out of the funded pot, never the case's rent or
+    // an appeal bond
+    let case_rent = rent.minimum_balance(DisputeCase::SPACE);
+    let available = ctx.dispute_case.lamports()
+        .saturating_sub(case_rent)
+        .saturating_sub(dispute.appeal_bond);
+    let share = dispute.vote_reward(multiplier_bps, available);
+    require!(share <= available, EscrowError::InsufficientFunds);
+
+    *ctx.dispute_case.try_borrow_mut_lamports()? -= share;
+    *ctx.arbitrator.try_borrow_mut_lamports()? += share;
 
     Ok(())
 }
@@ -1009,13 +3462,17 @@ pub struct RemoveArbitratorAccounts<'a> {
     pub arbitrator_account: &'a AccountInfo,
     pub arbitrator_agent: &'a AccountInfo,
     pub authority: &'a AccountInfo,
+    /// Platform config (PDA ["platform_config"]) - names the platform authority
+    pub platform_config: &'a AccountInfo,
+    /// Pool extension (optional, writable) - required if the arbitrator is in it
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for RemoveArbitratorAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [pool, arbitrator_account, arbitrator_agent, authority, ..] = accounts else {
+        let [pool, arbitrator_account, arbitrator_agent, authority, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -1023,9 +3480,14 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RemoveArbitratorAccounts<'a> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
-        Ok(Self { pool, arbitrator_account, arbitrator_agent, authority })
+        Ok(Self {
+            pool,
+            arbitrator_account,
+            arbitrator_agent,
+            authority,
+            platform_config,
+            pool_extension: rest.first(),
+        })
     }
 }
 
@@ -1035,6 +3497,7 @@ pub fn process_remove_arbitrator(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = RemoveArbitratorAccounts::try_from(accounts)?;
+    require_platform_authority(ctx.authority, ctx.platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify pool account is owned by this program
     if *ctx.pool.owner() != ID {
@@ -1066,7 +3529,8 @@ pub fn process_remove_arbitrator(
     require!(&arb.agent == ctx.arbitrator_agent.key(), EscrowError::Unauthorized);
 
     // Load pool and remove
-    let pool_data = &mut ctx.pool.try_borrow_mut_data()?;
+    let segment = pool_segment_of(ctx.pool, ctx.pool_extension, &arb.agent, program_id)?;
+    let pool_data = &mut segment.try_borrow_mut_data()?;
     let pool = ArbitratorPool::load_mut(pool_data)?;
     pool.remove(&arb.agent)?;
 
@@ -1076,12 +3540,147 @@ pub fn process_remove_arbitrator(
     let account_balance = *ctx.arbitrator_account.try_borrow_lamports()?;
     let rent = Rent::get()?.minimum_balance(ArbitratorEntry::SPACE);
     let available = account_balance.saturating_sub(rent);
-    
-    // Only return what's available, up to stake
-    let return_amount = core::cmp::min(arb.stake, available);
+
+    // Return everything but the open-case reserve, up to what the account holds
+    let return_amount = arb.take_unlocked_stake(available);
     if return_amount > 0 {
         transfer_lamports(ctx.arbitrator_account, ctx.arbitrator_agent, return_amount)?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raise_dispute_data_exclusions_are_optional() {
+        let mut data = std::vec![3u8, 0];
+        data.extend_from_slice(b"bad");
+        let args = RaiseDisputeCaseData::try_from_slice(&data).unwrap();
+        assert!(args.exclusions().is_empty());
+
+        data.extend_from_slice(&(DisputeReason::Quality as u16).to_le_bytes());
+        let args = RaiseDisputeCaseData::try_from_slice(&data).unwrap();
+        assert!(args.exclusions().is_empty());
+
+        data.push(1);
+        data.extend_from_slice(&[9u8; 32]);
+        let args = RaiseDisputeCaseData::try_from_slice(&data).unwrap();
+        assert_eq!(args.exclusions(), &[[9u8; 32]]);
+
+        // More than two exclusions are rejected
+        data[4 + 3] = 3;
+        assert!(RaiseDisputeCaseData::try_from_slice(&data).is_err());
+    }
+
+    fn slot_hashes(slots: &[u64]) -> std::vec::Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for &slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn test_reveal_uses_first_block_at_or_after_committed_slot() {
+        // Newest first; slot 103 was skipped
+        let data = slot_hashes(&[106, 105, 104, 102, 101]);
+        assert_eq!(slot_hash_at_or_after(&data, 104), Some([104u8; 32]));
+        assert_eq!(slot_hash_at_or_after(&data, 103), Some([104u8; 32]));
+        // Nothing has landed at or after the slot yet
+        assert_eq!(slot_hash_at_or_after(&data, 107), None);
+        // Older than every entry: the first block after it is unknown
+        assert_eq!(slot_hash_at_or_after(&data, 90), None);
+        assert_eq!(slot_hash_at_or_after(&[], 90), None);
+
+        // The seed is bound to both the commitment and the slot hash
+        let commitment = selection_commitment(&[7u8; 32], 104);
+        assert_ne!(commitment, selection_commitment(&[7u8; 32], 105));
+        assert_ne!(
+            selection_seed(&commitment, &[104u8; 32]),
+            selection_seed(&commitment, &[105u8; 32])
+        );
+    }
+
+    #[test]
+    fn test_finalize_preview() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        dispute.reveal_arbitrators([[1u8; 32]; MAX_PANEL_SIZE], 1_000).unwrap();
+        let settings = FinalizeSettings { reputation_tie_break: true, ..FinalizeSettings::default() };
+
+        // Nobody voted by the deadline: the poster is refunded, not split
+        let preview = finalize_preview(&[2u8; 32], dispute, 1_000, &settings);
+        assert!(preview.can_finalize && !preview.tie_break_pending);
+        assert_eq!(preview.resolution, DisputeResolution::PosterWins as u8);
+
+        // Open vote without a majority cannot be finalized yet
+        dispute.votes[0] = Vote::ForWorker as u8;
+        dispute.votes[1] = Vote::ForPoster as u8;
+        let preview = finalize_preview(&[2u8; 32], dispute, 500, &settings);
+        assert!(!preview.can_finalize);
+        assert!(!preview.deadline_passed);
+
+        // Two votes at the deadline fall short of quorum: refunded, not split
+        let preview = finalize_preview(&[2u8; 32], dispute, 1_000, &settings);
+        assert!(preview.can_finalize && !preview.tie_break_pending);
+        assert_eq!(preview.resolution, DisputeResolution::PosterWins as u8);
+
+        // Past the deadline a quorate tie is finalizable, pending the reputation tie-break
+        dispute.votes[2] = Vote::ForWorker as u8;
+        dispute.votes[3] = Vote::ForPoster as u8;
+        let preview = finalize_preview(&[2u8; 32], dispute, 1_000, &settings);
+        assert!(preview.can_finalize);
+        assert!(preview.tie_break_pending);
+        assert_eq!(preview.resolution, DisputeResolution::Split as u8);
+
+        // Majority finalizes early
+        dispute.votes[4] = Vote::ForWorker as u8;
+        let preview = finalize_preview(&[2u8; 32], dispute, 500, &settings);
+        assert!(preview.can_finalize && preview.majority_reached);
+        assert_eq!((preview.for_worker, preview.for_poster), (3, 2));
+        assert_eq!(preview.resolution, DisputeResolution::WorkerWins as u8);
+
+        // Nothing left to do once resolved
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        let preview = finalize_preview(&[2u8; 32], dispute, 500, &settings);
+        assert!(!preview.can_finalize);
+        assert_eq!(preview.resolution, DisputeResolution::WorkerWins as u8);
+    }
+
+    #[test]
+    fn test_vote_commitment_binds_vote_salt_and_arbitrator() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        dispute.commit_reveal = 1;
+        let mut arbitrators = [[0u8; 32]; MAX_PANEL_SIZE];
+        arbitrators[0] = [1u8; 32];
+        arbitrators[1] = [2u8; 32];
+        dispute.reveal_arbitrators(arbitrators, 100_000).unwrap();
+
+        let salt = [9u8; 32];
+        let commitment = vote_commitment(Vote::ForPoster, &salt, &[1u8; 32]);
+        dispute.commit_vote(0, commitment, 0).unwrap();
+        // Copying the commitment does not let another arbitrator replay the reveal
+        dispute.commit_vote(1, commitment, 0).unwrap();
+
+        let reveal_at = dispute.commit_deadline();
+        assert_eq!(
+            dispute.reveal_vote(0, Vote::ForWorker, &vote_commitment(Vote::ForWorker, &salt, &[1u8; 32]), reveal_at),
+            Err(EscrowError::VoteCommitmentMismatch.into())
+        );
+        assert_eq!(
+            dispute.reveal_vote(1, Vote::ForPoster, &vote_commitment(Vote::ForPoster, &salt, &[2u8; 32]), reveal_at),
+            Err(EscrowError::VoteCommitmentMismatch.into())
+        );
+        dispute.reveal_vote(0, Vote::ForPoster, &vote_commitment(Vote::ForPoster, &salt, &[1u8; 32]), reveal_at).unwrap();
+        assert_eq!(dispute.count_votes(), (0, 1));
+
+        let args = RevealVoteData::try_from_slice(&[2u8; 33]).unwrap();
+        assert_eq!((args.vote, args.salt), (Vote::ForPoster, [2u8; 32]));
+        assert!(RevealVoteData::try_from_slice(&[0u8; 33]).is_err());
+    }
+}
@@ -1,42 +1,94 @@
-//! AssignWorker instruction
+//! AssignWorker instructions
 //!
-//! Assigns a worker to an active escrow.
+//! Assigns a worker to an active escrow against a bond, lets an assigned
+//! worker decline or abandon the job before submitting work, lets the poster
+//! or platform unassign a worker who has not started, and records bids on
+//! open jobs.
 
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
     pubkey::{find_program_address, Pubkey},
+    sysvars::{clock::Clock, Sysvar},
     ProgramResult,
 };
+use pinocchio_system::instructions::Transfer;
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus},
-    require,
+    events::{AssignmentDeclined, WorkerAssigned},
+    state::{JobEscrow, EscrowStatus, AgentReputation},
+    require, require_some,
     PLATFORM_WALLET,
     ID,
 };
 
+use super::fee_config::platform_authority;
+use super::helpers::{require_writable, settle_worker_deposit};
+
+/// Verify a worker reputation account is the program-owned PDA for `worker`
+fn verify_worker_reputation(
+    worker_reputation: &AccountInfo,
+    worker: &Pubkey,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    if *worker_reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_rep, _) = find_program_address(&[b"reputation", worker], program_id);
+    require!(worker_reputation.key() == &expected_rep, EscrowError::InvalidPda);
+    Ok(())
+}
+
+// ============== ASSIGN WORKER ==============
+
 /// Assign worker instruction accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. initiator (signer) - poster or platform
+/// 2. worker (signer, writable) - the worker being assigned, pays the bond
+/// 3. system_program
+/// 4. worker_reputation (writable, optional) - counts the assignment; required
+///    if the escrow sets a minimum worker score
+/// 5. platform_config (optional) - PDA ["platform_config"]; required when the
+///    platform authority initiates
 pub struct AssignWorkerAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub initiator: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub worker_reputation: Option<&'a AccountInfo>,
+    pub platform_config: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for AssignWorkerAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, initiator, ..] = accounts else {
+        let [escrow, initiator, worker, system_program, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker])?;
+
         // Initiator must be signer
         if !initiator.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, initiator })
+        // The worker accepts the job by locking the bond
+        if !worker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self {
+            escrow,
+            initiator,
+            worker,
+            system_program,
+            worker_reputation: rest.first(),
+            platform_config: rest.get(1),
+        })
     }
 }
 
@@ -52,11 +104,20 @@ impl AssignWorkerData {
             return Err(ProgramError::InvalidInstructionData);
         }
         let worker: Pubkey = data[0..32].try_into().unwrap();
+        // The default pubkey would read as "unassigned"
+        require!(worker != JobEscrow::DEFAULT_PUBKEY, EscrowError::InvalidWorker);
+        // A platform-held worker would route both the payment and the fee
+        // back to the platform, which could be used to wash transactions
+        require!(worker != PLATFORM_WALLET, EscrowError::PlatformWorkerNotAllowed);
         Ok(Self { worker })
     }
 }
 
 /// Process assign_worker instruction
+///
+/// The worker co-signs and locks a bond of worker_bond_for_assignment() in
+/// the escrow. It comes back on completion, unassignment or a decline, and is
+/// forfeited to the poster on abandonment or when the poster is refunded.
 pub fn process_assign_worker(
     accounts: &[AccountInfo],
     data: &[u8],
@@ -70,7 +131,127 @@ pub fn process_assign_worker(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // Load and validate escrow
+    let bond = {
+        // Load and validate escrow
+        let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+        let escrow = JobEscrow::load_mut(escrow_data)?;
+
+        // SECURITY FIX C-02: Verify escrow PDA derivation
+        let (expected_pda, expected_bump) = find_program_address(
+            &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+            program_id,
+        );
+        require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+        require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+        // Must be active
+        require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+
+        // No worker assigned yet
+        require!(!escrow.has_worker(), EscrowError::WorkerAlreadyAssigned);
+
+        // Initiator must be poster or platform
+        let initiator_key = ctx.initiator.key();
+        let is_poster = initiator_key == &escrow.poster;
+        let is_platform = match ctx.platform_config {
+            Some(platform_config) => initiator_key == &platform_authority(platform_config, program_id)?,
+            None => false,
+        };
+        require!(is_poster || is_platform, EscrowError::Unauthorized);
+
+        require!(ctx.worker.key() == &args.worker, EscrowError::WorkerMismatch);
+
+        // Assign the worker
+        escrow.worker = args.worker;
+
+        // Count the assignment towards the worker's completion rate
+        if let Some(worker_reputation) = ctx.worker_reputation {
+            verify_worker_reputation(worker_reputation, &args.worker, program_id)?;
+            let rep_data = &mut worker_reputation.try_borrow_mut_data()?;
+            let rep = AgentReputation::load_mut(rep_data)?;
+            require!(
+                escrow.meets_min_worker_score(rep.reputation_score),
+                EscrowError::InsufficientReputation
+            );
+            rep.record_assignment();
+        } else {
+            // Leaving the reputation out must not skip the poster's minimum
+            require!(escrow.min_worker_score == 0, EscrowError::InsufficientReputation);
+        }
+
+        WorkerAssigned {
+            escrow: *ctx.escrow.key(),
+            worker: args.worker,
+            assigned_by: *initiator_key,
+        }
+        .emit();
+
+        // Lock the worker bond in the escrow
+        let bond = escrow.worker_bond_for_assignment();
+        escrow.lock_worker_bond(bond);
+        bond
+    };
+
+    // Paid once the escrow borrow is released
+    Transfer {
+        from: ctx.worker,
+        to: ctx.escrow,
+        lamports: bond,
+    }
+    .invoke()?;
+
+    Ok(())
+}
+
+// ============== ABANDON ASSIGNMENT ==============
+
+/// Abandon assignment instruction accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. worker (signer) - the currently assigned worker
+/// 2. worker_reputation (writable) - records the abandonment
+/// 3. poster (writable, optional) - required if the escrow holds a worker deposit or bond, which is forfeited
+pub struct AbandonAssignmentAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub worker_reputation: &'a AccountInfo,
+    pub poster: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AbandonAssignmentAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, worker, worker_reputation, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !worker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, worker, worker_reputation, poster: rest.first() })
+    }
+}
+
+/// Process abandon_assignment instruction
+///
+/// Unassigns the worker so the poster can assign someone else, and records
+/// the abandonment against the worker's reputation, scaled up by the
+/// escrow's worker cancellation penalty. A posted deposit and the assignment
+/// bond are the worker's commitment stake and go to the poster as compensation.
+pub fn process_abandon_assignment(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = AbandonAssignmentAccounts::try_from(accounts)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
     let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
     let escrow = JobEscrow::load_mut(escrow_data)?;
 
@@ -82,20 +263,265 @@ pub fn process_assign_worker(
     require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
 
-    // Must be active
+    // Only an assigned worker who has not submitted work can abandon
     require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    // No worker assigned yet
-    require!(!escrow.has_worker(), EscrowError::WorkerAlreadyAssigned);
+    verify_worker_reputation(ctx.worker_reputation, &escrow.worker, program_id)?;
+    let rep_data = &mut ctx.worker_reputation.try_borrow_mut_data()?;
+    let rep = AgentReputation::load_mut(rep_data)?;
+    rep.record_abandonment(escrow.worker_cancellation_penalty_bps);
+
+    // A posted deposit and the bond are forfeited to the poster
+    if escrow.worker_deposit_posted != 0 || escrow.holds_worker_bond() {
+        let poster = require_some!(ctx.poster, ProgramError::NotEnoughAccountKeys);
+        require!(poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+        require_writable(&[poster])?;
+        settle_worker_deposit(escrow, ctx.escrow, poster)?;
+    }
+
+    escrow.worker = JobEscrow::DEFAULT_PUBKEY;
+
+    Ok(())
+}
+
+// ============== DECLINE ASSIGNMENT ==============
+
+/// Decline assignment instruction accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. worker (signer, writable) - the currently assigned worker, gets the bond back
+pub struct DeclineAssignmentAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DeclineAssignmentAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, worker, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, worker])?;
+
+        if !worker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, worker })
+    }
+}
+
+/// Process decline_assignment instruction
+///
+/// Lets an assigned worker turn the job down before starting it. Unlike
+/// abandon_assignment this does not count against their reputation and the
+/// bond is returned; the poster can then reassign or cancel.
+pub fn process_decline_assignment(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = DeclineAssignmentAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    escrow.decline_assignment(ctx.worker.key())?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
+
+    AssignmentDeclined {
+        escrow: *ctx.escrow.key(),
+        worker: *ctx.worker.key(),
+        declined_at: clock.unix_timestamp,
+    }
+    .emit();
+
+    Ok(())
+}
+
+// ============== UNASSIGN WORKER ==============
+
+/// Unassign worker instruction accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. initiator (signer) - poster or platform
+/// 2. worker (writable, optional) - required if the escrow holds a worker deposit or bond, which is returned
+/// 3. platform_config (optional) - PDA ["platform_config"]; required when the
+///    platform authority initiates
+pub struct UnassignWorkerAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub initiator: &'a AccountInfo,
+    pub worker: Option<&'a AccountInfo>,
+    pub platform_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UnassignWorkerAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, initiator, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow])?;
+
+        if !initiator.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, initiator, worker: rest.first(), platform_config: rest.get(1) })
+    }
+}
+
+/// Process unassign_worker instruction
+///
+/// Clears a worker who has not submitted work so assign_worker can be called
+/// again, keeping the escrowed funds in place. A posted deposit and the bond
+/// go back to the worker, and their reputation is untouched.
+pub fn process_unassign_worker(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = UnassignWorkerAccounts::try_from(accounts)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
 
     // Initiator must be poster or platform
     let initiator_key = ctx.initiator.key();
     let is_poster = initiator_key == &escrow.poster;
-    let is_platform = initiator_key == &PLATFORM_WALLET;
+    let is_platform = match ctx.platform_config {
+        Some(platform_config) => initiator_key == &platform_authority(platform_config, program_id)?,
+        None => false,
+    };
     require!(is_poster || is_platform, EscrowError::Unauthorized);
 
-    // Assign the worker
-    escrow.worker = args.worker;
+    let previous_worker = escrow.worker;
+    escrow.unassign_worker()?;
+
+    // A posted deposit and the bond are returned to the worker
+    if escrow.worker_deposit_posted != 0 || escrow.holds_worker_bond() {
+        let worker = require_some!(ctx.worker, ProgramError::NotEnoughAccountKeys);
+        require!(worker.key() == &previous_worker, EscrowError::WorkerMismatch);
+        require_writable(&[worker])?;
+        settle_worker_deposit(escrow, ctx.escrow, worker)?;
+    }
 
     Ok(())
 }
+
+// ============== PLACE BID ==============
+
+/// Place bid instruction accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. bidder (signer) - any agent other than the poster
+pub struct PlaceBidAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub bidder: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PlaceBidAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, bidder, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow])?;
+
+        if !bidder.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, bidder })
+    }
+}
+
+/// Process place_bid instruction
+///
+/// Records a bid on an open job. The first bid starts the cancel grace,
+/// during which the poster cannot cancel the escrow.
+pub fn process_place_bid(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = PlaceBidAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    // Only open jobs take bids, and not from the poster
+    require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    require!(!escrow.has_worker(), EscrowError::WorkerAlreadyAssigned);
+    require!(ctx.bidder.key() != &escrow.poster, EscrowError::Unauthorized);
+
+    escrow.record_bid(clock.unix_timestamp);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_rejects_default_and_platform_worker() {
+        assert_eq!(
+            AssignWorkerData::try_from_slice(&JobEscrow::DEFAULT_PUBKEY).err(),
+            Some(ProgramError::from(EscrowError::InvalidWorker))
+        );
+        assert_eq!(
+            AssignWorkerData::try_from_slice(&PLATFORM_WALLET).err(),
+            Some(ProgramError::from(EscrowError::PlatformWorkerNotAllowed))
+        );
+        assert_eq!(AssignWorkerData::try_from_slice(&[7u8; 32]).unwrap().worker, [7u8; 32]);
+    }
+}
@@ -1,34 +1,25 @@
 //! Close instructions
 //!
-//! Handles closing escrow, dispute case, and arbitrator accounts to reclaim rent.
+//! Handles closing escrow, dispute case, arbitrator, and accuracy claim accounts
+//! to reclaim rent.
 
 use pinocchio::{
     account_info::AccountInfo,
     program_error::ProgramError,
     pubkey::{find_program_address, Pubkey},
+    sysvars::{clock::Clock, Sysvar},
     ProgramResult,
 };
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus, DisputeCase, ArbitratorPool, ArbitratorEntry},
-    require,
+    state::{is_closed, JobEscrow, EscrowStatus, DisputeCase, ArbitratorPool, ArbitratorEntry, AccuracyClaim},
+    require, require_some,
     ID,
 };
 
-/// Transfer all lamports and close account
-#[inline(always)]
-fn close_account(account: &AccountInfo, recipient: &AccountInfo) -> ProgramResult {
-    let lamports = *account.try_borrow_lamports()?;
-    *account.try_borrow_mut_lamports()? = 0;
-    *recipient.try_borrow_mut_lamports()? += lamports;
-    
-    // Zero out data to mark as closed
-    let mut data = account.try_borrow_mut_data()?;
-    data.fill(0);
-    
-    Ok(())
-}
+use super::arbitrator::check_pool_extension;
+use super::helpers::{close_account, require_writable};
 
 // ============== CLOSE ESCROW ==============
 
@@ -90,6 +81,9 @@ pub fn process_close_escrow(
     // Must be poster
     require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
 
+    // Closing hands the lamports to the poster, so any bond must be settled first
+    require!(!escrow.holds_worker_bond(), EscrowError::WorkerBondHeld);
+
     // Drop borrow before closing
     drop(escrow_data);
 
@@ -159,6 +153,13 @@ pub fn process_close_dispute_case(
     // Must be initiator
     require!(ctx.initiator.key() == &dispute.raised_by, EscrowError::Unauthorized);
 
+    // Voters keep their vote fees until they claim or the claim window ends
+    let clock = Clock::get()?;
+    require!(dispute.vote_fees_settled(clock.unix_timestamp), EscrowError::VoteFeesUnclaimed);
+    require!(dispute.appeal_bond == 0, EscrowError::AppealBondUnsettled);
+    // Each voter's stake reserve is released when their vote is judged
+    require!(dispute.all_votes_judged(), EscrowError::VotesUnjudged);
+
     // Verify escrow is in terminal state
     let escrow_data = ctx.escrow.try_borrow_data()?;
     let escrow = JobEscrow::load(&escrow_data)?;
@@ -193,13 +194,15 @@ pub struct CloseArbitratorAccountAccounts<'a> {
     pub pool: &'a AccountInfo,
     pub arbitrator_account: &'a AccountInfo,
     pub agent: &'a AccountInfo,
+    /// Pool extension - required once the pool is extended
+    pub pool_extension: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CloseArbitratorAccountAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [pool, arbitrator_account, agent, ..] = accounts else {
+        let [pool, arbitrator_account, agent, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -207,7 +210,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CloseArbitratorAccountAccounts<'a> {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { pool, arbitrator_account, agent })
+        Ok(Self { pool, arbitrator_account, agent, pool_extension: rest.first() })
     }
 }
 
@@ -249,6 +252,9 @@ pub fn process_close_arbitrator_account(
     // Must not be active
     require!(!arb.is_active(), EscrowError::ArbitratorStillActive);
 
+    // The open-case reserve stays until update_arbitrator_accuracy judges it
+    require!(arb.open_cases == 0, EscrowError::ArbitratorHasOpenCases);
+
     // Must be the agent
     require!(ctx.agent.key() == &arb.agent, EscrowError::Unauthorized);
 
@@ -257,6 +263,15 @@ pub fn process_close_arbitrator_account(
     let pool = ArbitratorPool::load(&pool_data)?;
     require!(!pool.contains(ctx.agent.key()), EscrowError::ArbitratorStillInPool);
 
+    // ...including the extension segment
+    if pool.is_extended() {
+        let extension = require_some!(ctx.pool_extension, ProgramError::NotEnoughAccountKeys);
+        check_pool_extension(extension, program_id)?;
+        let extension_data = extension.try_borrow_data()?;
+        let extension_pool = ArbitratorPool::load(&extension_data)?;
+        require!(!extension_pool.contains(ctx.agent.key()), EscrowError::ArbitratorStillInPool);
+    }
+
     // Drop borrows
     drop(arb_data);
     drop(pool_data);
@@ -266,3 +281,77 @@ pub fn process_close_arbitrator_account(
 
     Ok(())
 }
+
+// ============== CLOSE ACCURACY CLAIM ==============
+
+/// Close accuracy claim accounts
+/// Accounts:
+/// 0. accuracy_claim (writable) - PDA ["accuracy_claim", dispute_case, arbitrator]
+/// 1. dispute_case - the claim's dispute case, already closed
+/// 2. rent_payer (signer, writable) - whoever paid for the claim, receives the rent
+pub struct CloseAccuracyClaimAccounts<'a> {
+    pub accuracy_claim: &'a AccountInfo,
+    pub dispute_case: &'a AccountInfo,
+    pub rent_payer: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseAccuracyClaimAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [accuracy_claim, dispute_case, rent_payer, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[accuracy_claim, rent_payer])?;
+
+        if !rent_payer.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { accuracy_claim, dispute_case, rent_payer })
+    }
+}
+
+/// Process close_accuracy_claim instruction
+///
+/// The claim is what stops an arbitrator's accuracy being recorded twice for
+/// a case, so it may only go once the dispute case itself is closed and
+/// update_arbitrator_accuracy can no longer run against it.
+pub fn process_close_accuracy_claim(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = CloseAccuracyClaimAccounts::try_from(accounts)?;
+
+    // SECURITY FIX C-01: Verify accuracy_claim account is owned by this program
+    if *ctx.accuracy_claim.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let claim_data = ctx.accuracy_claim.try_borrow_data()?;
+    let claim = AccuracyClaim::load(&claim_data)?;
+
+    // SECURITY FIX C-02: Verify accuracy_claim PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"accuracy_claim", &claim.dispute_case, &claim.arbitrator],
+        program_id,
+    );
+    require!(ctx.accuracy_claim.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(claim.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(ctx.dispute_case.key() == &claim.dispute_case, EscrowError::EscrowMismatch);
+    require!(ctx.rent_payer.key() == &claim.rent_payer, EscrowError::Unauthorized);
+
+    // A closed case is either still marked closed in this slot or already
+    // purged and no longer owned by the program
+    let case_closed = *ctx.dispute_case.owner() != ID || is_closed(&ctx.dispute_case.try_borrow_data()?);
+    require!(case_closed, EscrowError::DisputeCaseNotClosed);
+
+    drop(claim_data);
+
+    close_account(ctx.accuracy_claim, ctx.rent_payer)?;
+
+    Ok(())
+}
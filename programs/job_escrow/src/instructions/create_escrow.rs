@@ -1,6 +1,9 @@
-//! CreateEscrow instruction
+//! CreateEscrow instructions
 //!
-//! Creates a new escrow account and deposits SOL.
+//! Creates a new escrow account and deposits SOL. Bilateral escrows also
+//! require the worker to lock a bond when accepting the job. Posters can top
+//! up an active escrow, SOL or token, if the job's scope grows, and push back
+//! its expiry.
 
 use pinocchio::{
     account_info::AccountInfo,
@@ -11,14 +14,21 @@ use pinocchio::{
     sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio_system::instructions::{CreateAccount, Transfer};
+use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus},
-    require,
+    events::{EscrowCreated, ExpiryExtended},
+    state::{JobEscrow, EscrowStatus, MAX_CATEGORIES, MAX_JOB_ID_LEN},
+    require, require_some,
+    ID,
 };
 
+use super::fee_config::require_not_paused;
+use super::helpers::{require_writable, verify_job_id, TOKEN_PROGRAM_ID};
+use super::reputation::create_reputation_account;
+
 /// Minimum escrow amount (0.001 SOL)
 pub const MIN_ESCROW_AMOUNT: u64 = 1_000_000;
 
@@ -26,17 +36,26 @@ pub const MIN_ESCROW_AMOUNT: u64 = 1_000_000;
 pub const DEFAULT_EXPIRY_SECONDS: i64 = 30 * 24 * 60 * 60;
 
 /// Create escrow instruction accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer, writable) - pays the escrow amount and rent
+/// 2. system_program
+/// 3. platform_config (readonly) - PDA ["platform_config"]; creation fails while paused
+/// 4. poster_reputation (optional, writable) - PDA ["reputation", poster];
+///    created alongside the escrow if it does not exist yet
 pub struct CreateEscrowAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub poster: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub poster_reputation: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CreateEscrowAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, poster, system_program, ..] = accounts else {
+        let [escrow, poster, system_program, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -49,16 +68,32 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateEscrowAccounts<'a> {
             escrow,
             poster,
             system_program,
+            platform_config,
+            poster_reputation: rest.first(),
         })
     }
 }
 
 /// Instruction data for CreateEscrow
-/// Layout: [job_id_hash: [u8; 32], amount: u64, expiry_seconds: i64 (0 = default)]
+/// Layout: [job_id_hash: [u8; 32], amount: u64, expiry_seconds: i64 (0 = default),
+///          category: u8 (optional, 0 = default),
+///          max_dispute_duration: i64 (optional, 0 = no cap),
+///          delivery_seconds: i64 (optional, 0 = no delivery deadline),
+///          referrer: Pubkey (optional, default = no referrer),
+///          worker_cancellation_penalty_bps: u16 (optional, 0 = none),
+///          min_worker_score: i64 (optional, 0 = no minimum),
+///          job_id_len: u8 (optional, 0 = not stored), job_id: [u8; job_id_len]]
 pub struct CreateEscrowData {
     pub job_id_hash: [u8; 32],
     pub amount: u64,
     pub expiry_seconds: i64,
+    pub category: u8,
+    pub max_dispute_duration: i64,
+    pub delivery_seconds: i64,
+    pub referrer: Pubkey,
+    pub worker_cancellation_penalty_bps: u16,
+    pub min_worker_score: i64,
+    pub job_id: JobIdField,
 }
 
 impl CreateEscrowData {
@@ -70,15 +105,127 @@ impl CreateEscrowData {
         let job_id_hash: [u8; 32] = data[0..32].try_into().unwrap();
         let amount = u64::from_le_bytes(data[32..40].try_into().unwrap());
         let expiry_seconds = i64::from_le_bytes(data[40..48].try_into().unwrap());
+        let category = data.get(48).copied().unwrap_or(0);
+        if category >= MAX_CATEGORIES {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let max_dispute_duration = read_optional_duration(data, 49)?;
+        let delivery_seconds = read_optional_duration(data, 57)?;
+        let referrer = read_optional_pubkey(data, 65);
+        let worker_cancellation_penalty_bps = read_optional_bps(data, 97)?;
+        let min_worker_score = read_optional_score(data, 99);
+        let job_id = read_optional_job_id(data, 107, &job_id_hash)?;
         
         Ok(Self {
             job_id_hash,
             amount,
             expiry_seconds,
+            category,
+            max_dispute_duration,
+            delivery_seconds,
+            referrer,
+            worker_cancellation_penalty_bps,
+            min_worker_score,
+            job_id,
         })
     }
 }
 
+/// Parse an optional trailing duration in seconds at `offset` (absent = 0)
+pub(crate) fn read_optional_duration(data: &[u8], offset: usize) -> Result<i64, ProgramError> {
+    let Some(bytes) = data.get(offset..offset + 8) else {
+        return Ok(0);
+    };
+    let duration = i64::from_le_bytes(bytes.try_into().unwrap());
+    if duration < 0 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+    Ok(duration)
+}
+
+/// Parse an optional trailing pubkey at `offset` (absent = default)
+fn read_optional_pubkey(data: &[u8], offset: usize) -> Pubkey {
+    data.get(offset..offset + 32)
+        .map_or(JobEscrow::DEFAULT_PUBKEY, |bytes| bytes.try_into().unwrap())
+}
+
+/// Parse an optional trailing basis-point value at `offset` (absent = 0)
+fn read_optional_bps(data: &[u8], offset: usize) -> Result<u16, ProgramError> {
+    let Some(bytes) = data.get(offset..offset + 2) else {
+        return Ok(0);
+    };
+    let bps = u16::from_le_bytes(bytes.try_into().unwrap());
+    require!(bps <= 10_000, EscrowError::InvalidFeeBps);
+    Ok(bps)
+}
+
+/// Parse an optional trailing reputation score at `offset` (absent = 0)
+pub(crate) fn read_optional_score(data: &[u8], offset: usize) -> i64 {
+    data.get(offset..offset + 8)
+        .map_or(0, |bytes| i64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+/// Plaintext job_id parsed from instruction data
+#[derive(Clone, Copy)]
+pub struct JobIdField {
+    pub bytes: [u8; MAX_JOB_ID_LEN],
+    pub len: u8,
+}
+
+impl JobIdField {
+    /// No job_id stored
+    pub const EMPTY: Self = Self { bytes: [0u8; MAX_JOB_ID_LEN], len: 0 };
+}
+
+/// Parse an optional trailing `[len: u8][job_id: [u8; len]]` at `offset`
+/// (absent or len 0 = not stored)
+///
+/// A stored job_id must hash to `job_id_hash`, so it cannot misdescribe the
+/// escrow's PDA seed.
+pub(crate) fn read_optional_job_id(
+    data: &[u8],
+    offset: usize,
+    job_id_hash: &[u8; 32],
+) -> Result<JobIdField, ProgramError> {
+    let len = data.get(offset).copied().unwrap_or(0) as usize;
+    if len == 0 {
+        return Ok(JobIdField::EMPTY);
+    }
+    require!(len <= MAX_JOB_ID_LEN, EscrowError::JobIdTooLong);
+    let job_id = data
+        .get(offset + 1..offset + 1 + len)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    verify_job_id(job_id, job_id_hash)?;
+
+    let mut field = JobIdField::EMPTY;
+    field.bytes[..len].copy_from_slice(job_id);
+    field.len = len as u8;
+    Ok(field)
+}
+
+/// Delivery deadline for a requested `delivery_seconds` (0 = none)
+///
+/// The deadline must fall within the escrow's `expiry`; past it, the poster
+/// could reclaim through claim_expired anyway.
+pub(crate) fn resolve_delivery_deadline(now: i64, expiry: i64, delivery_seconds: i64) -> Result<i64, ProgramError> {
+    match delivery_seconds {
+        0 => Ok(0),
+        seconds if seconds <= expiry => Ok(now + seconds),
+        _ => Err(EscrowError::InvalidExpiry.into()),
+    }
+}
+
+/// Escrow lifetime for a requested `expiry_seconds` (0 = default)
+///
+/// Negative values are malformed input and rejected rather than defaulted.
+pub(crate) fn resolve_expiry(expiry_seconds: i64) -> Result<i64, ProgramError> {
+    match expiry_seconds {
+        0 => Ok(DEFAULT_EXPIRY_SECONDS),
+        seconds if seconds > 0 => Ok(seconds),
+        _ => Err(EscrowError::InvalidExpiry.into()),
+    }
+}
+
 /// Process create_escrow instruction
 pub fn process_create_escrow(
     accounts: &[AccountInfo],
@@ -87,20 +234,28 @@ pub fn process_create_escrow(
 ) -> ProgramResult {
     let ctx = CreateEscrowAccounts::try_from(accounts)?;
     let args = CreateEscrowData::try_from_slice(data)?;
+    create_sol_escrow(&ctx, &args, 0, program_id)
+}
+
+/// Create and fund a SOL escrow, optionally requiring a worker deposit
+fn create_sol_escrow(
+    ctx: &CreateEscrowAccounts,
+    args: &CreateEscrowData,
+    worker_deposit: u64,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    require_not_paused(ctx.platform_config, program_id)?;
 
     // Validate amount
     require!(args.amount >= MIN_ESCROW_AMOUNT, EscrowError::AmountTooLow);
+    // A poster referring their own job would just rebate the platform fee
+    require!(&args.referrer != ctx.poster.key(), EscrowError::InvalidReferrer);
 
     // Get clock for timestamps
     let clock = Clock::get()?;
     
     // Calculate expiry
-    let expiry = if args.expiry_seconds > 0 {
-        args.expiry_seconds
-    } else {
-        DEFAULT_EXPIRY_SECONDS
-    };
-    require!(expiry > 0, EscrowError::InvalidExpiry);
+    let expiry = resolve_expiry(args.expiry_seconds)?;
 
     // Derive PDA and verify
     let (expected_pda, bump) = find_program_address(
@@ -131,17 +286,60 @@ pub fn process_create_escrow(
     }
     .invoke_signed(&[signer])?;
 
-    // Initialize escrow data
-    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
-    let escrow = JobEscrow::init(escrow_data)?;
+    {
+        let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+        initialize_escrow(
+            escrow_data,
+            args,
+            ctx.poster.key(),
+            bump,
+            clock.unix_timestamp,
+            expiry,
+            worker_deposit,
+        )?;
+    }
+
+    EscrowCreated {
+        escrow: *ctx.escrow.key(),
+        poster: *ctx.poster.key(),
+        token_mint: JobEscrow::DEFAULT_PUBKEY,
+        amount: args.amount,
+        expires_at: clock.unix_timestamp + expiry,
+        worker_deposit,
+        category: args.category,
+    }
+    .emit();
+
+    // Bootstrap the poster's reputation in the same transaction; an
+    // existing account is left untouched
+    if let Some(reputation) = ctx.poster_reputation {
+        if reputation.data_is_empty() {
+            create_reputation_account(reputation, ctx.poster, ctx.poster, program_id, false)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write a fresh SOL escrow record into newly created account data
+fn initialize_escrow(
+    data: &mut [u8],
+    args: &CreateEscrowData,
+    poster: &Pubkey,
+    bump: u8,
+    now: i64,
+    expiry: i64,
+    worker_deposit: u64,
+) -> ProgramResult {
+    let escrow = JobEscrow::init(data)?;
 
     escrow.job_id_hash = args.job_id_hash;
-    escrow.poster = *ctx.poster.key();
+    escrow.poster = *poster;
     escrow.worker = JobEscrow::DEFAULT_PUBKEY;
     escrow.amount = args.amount;
     escrow.status = EscrowStatus::Active as u8;
-    escrow.created_at = clock.unix_timestamp;
-    escrow.expires_at = clock.unix_timestamp + expiry;
+    escrow.created_at = now;
+    escrow.expires_at = now + expiry;
     escrow.dispute_initiated_at = 0;
     escrow.submitted_at = 0;
     escrow.proof_hash = [0u8; 32];
@@ -153,6 +351,549 @@ pub fn process_create_escrow(
     escrow.is_token_escrow = 0;
     escrow.token_mint = JobEscrow::DEFAULT_PUBKEY;
     escrow.escrow_token_account = JobEscrow::DEFAULT_PUBKEY;
+    escrow.category = args.category;
+    escrow.worker_deposit = worker_deposit;
+    escrow.worker_deposit_posted = 0;
+    escrow.max_dispute_duration = args.max_dispute_duration;
+    escrow.delivery_deadline = resolve_delivery_deadline(now, expiry, args.delivery_seconds)?;
+    escrow.referrer = args.referrer;
+    escrow.worker_cancellation_penalty_bps = args.worker_cancellation_penalty_bps;
+    escrow.min_worker_score = args.min_worker_score;
+    escrow.job_id = args.job_id.bytes;
+    escrow.job_id_len = args.job_id.len;
+
+    Ok(())
+}
+
+// ============== CREATE BILATERAL ESCROW ==============
+
+/// Instruction data for CreateBilateralEscrow
+/// Layout: [job_id_hash: [u8; 32], amount: u64, expiry_seconds: i64 (0 = default),
+///          worker_deposit: u64, category: u8 (optional, 0 = default),
+///          max_dispute_duration: i64 (optional, 0 = no cap),
+///          delivery_seconds: i64 (optional, 0 = no delivery deadline),
+///          referrer: Pubkey (optional, default = no referrer),
+///          worker_cancellation_penalty_bps: u16 (optional, 0 = none),
+///          min_worker_score: i64 (optional, 0 = no minimum),
+///          job_id_len: u8 (optional, 0 = not stored), job_id: [u8; job_id_len]]
+pub struct CreateBilateralEscrowData {
+    pub escrow: CreateEscrowData,
+    pub worker_deposit: u64,
+}
+
+impl CreateBilateralEscrowData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 56 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let worker_deposit = u64::from_le_bytes(data[48..56].try_into().unwrap());
+        let category = data.get(56).copied().unwrap_or(0);
+        if category >= MAX_CATEGORIES {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let job_id_hash: [u8; 32] = data[0..32].try_into().unwrap();
+
+        Ok(Self {
+            escrow: CreateEscrowData {
+                job_id_hash,
+                amount: u64::from_le_bytes(data[32..40].try_into().unwrap()),
+                expiry_seconds: i64::from_le_bytes(data[40..48].try_into().unwrap()),
+                category,
+                max_dispute_duration: read_optional_duration(data, 57)?,
+                delivery_seconds: read_optional_duration(data, 65)?,
+                referrer: read_optional_pubkey(data, 73),
+                worker_cancellation_penalty_bps: read_optional_bps(data, 105)?,
+                min_worker_score: read_optional_score(data, 107),
+                job_id: read_optional_job_id(data, 115, &job_id_hash)?,
+            },
+            worker_deposit,
+        })
+    }
+}
+
+/// Process create_bilateral_escrow instruction
+///
+/// Same accounts as create_escrow. The worker must post `worker_deposit`
+/// after assignment; it is returned on completion and forfeited to the
+/// poster if the worker loses a dispute or the poster is otherwise refunded.
+pub fn process_create_bilateral_escrow(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = CreateEscrowAccounts::try_from(accounts)?;
+    let args = CreateBilateralEscrowData::try_from_slice(data)?;
+
+    require!(args.worker_deposit > 0, EscrowError::AmountTooLow);
+
+    create_sol_escrow(&ctx, &args.escrow, args.worker_deposit, program_id)
+}
+
+// ============== POST WORKER DEPOSIT ==============
+
+/// Post worker deposit accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. worker (signer, writable) - the assigned worker
+/// 2. system_program
+pub struct PostWorkerDepositAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PostWorkerDepositAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, worker, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !worker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, worker, system_program })
+    }
+}
+
+/// Process post_worker_deposit instruction (worker accepts a bilateral escrow)
+pub fn process_post_worker_deposit(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = PostWorkerDepositAccounts::try_from(accounts)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let deposit = {
+        let escrow_data = ctx.escrow.try_borrow_data()?;
+        let escrow = JobEscrow::load(&escrow_data)?;
+
+        // SECURITY FIX C-02: Verify escrow PDA derivation
+        let (expected_pda, expected_bump) = find_program_address(
+            &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+            program_id,
+        );
+        require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+        require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+        require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+        require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+        require!(escrow.awaiting_worker_deposit(), EscrowError::WorkerDepositNotExpected);
+
+        escrow.worker_deposit
+    };
+
+    Transfer {
+        from: ctx.worker,
+        to: ctx.escrow,
+        lamports: deposit,
+    }
+    .invoke()?;
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+    escrow.worker_deposit_posted = 1;
+
+    Ok(())
+}
+
+// ============== TOP UP ESCROW ==============
+
+/// Top up escrow accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer, writable)
+/// 2. system_program
+/// 3. poster_token_account (token escrows only, writable) - source of the tokens
+/// 4. escrow_token_account (token escrows only, writable)
+/// 5. token_program (token escrows only)
+pub struct TopUpEscrowAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_accounts: Option<(&'a AccountInfo, &'a AccountInfo, &'a AccountInfo)>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TopUpEscrowAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, poster, system_program, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, poster])?;
+
+        if !poster.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let token_accounts = match rest {
+            [poster_token_account, escrow_token_account, token_program, ..] => {
+                require_writable(&[poster_token_account, escrow_token_account])?;
+                if token_program.key() != &TOKEN_PROGRAM_ID {
+                    return Err(ProgramError::IncorrectProgramId);
+                }
+                Some((poster_token_account, escrow_token_account, token_program))
+            }
+            _ => None,
+        };
+
+        Ok(Self { escrow, poster, system_program, token_accounts })
+    }
+}
+
+/// Instruction data for TopUpEscrow
+/// Layout: [amount: u64]
+pub struct TopUpEscrowData {
+    pub amount: u64,
+}
+
+impl TopUpEscrowData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let bytes = data.get(..8).ok_or(ProgramError::InvalidInstructionData)?;
+        let amount = u64::from_le_bytes(bytes.try_into().unwrap());
+        Ok(Self { amount })
+    }
+}
+
+/// Process top_up_escrow instruction
+///
+/// Adds funds to an active escrow without touching the worker assignment.
+/// SOL escrows receive lamports from the poster; token escrows receive tokens
+/// into their escrow token account.
+pub fn process_top_up_escrow(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = TopUpEscrowAccounts::try_from(accounts)?;
+    let args = TopUpEscrowData::try_from_slice(data)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let is_token_escrow = {
+        let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+        let escrow = JobEscrow::load_mut(escrow_data)?;
+
+        // SECURITY FIX C-02: Verify escrow PDA derivation
+        let (expected_pda, expected_bump) = find_program_address(
+            &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+            program_id,
+        );
+        require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+        require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+        require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+        if let Some((_, escrow_token_account, _)) = ctx.token_accounts {
+            require!(escrow.is_token_escrow(), EscrowError::NotTokenEscrow);
+            require!(
+                escrow_token_account.key() == &escrow.escrow_token_account,
+                EscrowError::TokenAccountMismatch
+            );
+        }
+
+        escrow.top_up(args.amount)?;
+        escrow.is_token_escrow()
+    };
+
+    if is_token_escrow {
+        let (poster_token_account, escrow_token_account, _) =
+            require_some!(ctx.token_accounts, ProgramError::NotEnoughAccountKeys);
+        TokenTransfer {
+            from: poster_token_account,
+            to: escrow_token_account,
+            authority: ctx.poster,
+            amount: args.amount,
+        }
+        .invoke()?;
+    } else {
+        Transfer {
+            from: ctx.poster,
+            to: ctx.escrow,
+            lamports: args.amount,
+        }
+        .invoke()?;
+    }
 
     Ok(())
 }
+
+// ============== EXTEND EXPIRY ==============
+
+/// Extend expiry accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer)
+pub struct ExtendExpiryAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ExtendExpiryAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, poster, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow])?;
+
+        if !poster.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, poster })
+    }
+}
+
+/// Instruction data for ExtendExpiry
+/// Layout: [additional_seconds: i64]
+pub struct ExtendExpiryData {
+    pub additional_seconds: i64,
+}
+
+impl ExtendExpiryData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let bytes = data.get(..8).ok_or(ProgramError::InvalidInstructionData)?;
+        let additional_seconds = i64::from_le_bytes(bytes.try_into().unwrap());
+        Ok(Self { additional_seconds })
+    }
+}
+
+/// Process extend_expiry instruction
+///
+/// Gives a slow worker more time without a claim_expired/recreate cycle.
+pub fn process_extend_expiry(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ExtendExpiryAccounts::try_from(accounts)?;
+    let args = ExtendExpiryData::try_from_slice(data)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+
+    let expires_at = escrow.extend_expiry(args.additional_seconds)?;
+
+    ExpiryExtended {
+        escrow: *ctx.escrow.key(),
+        expires_at,
+    }
+    .emit();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::helpers::sha256;
+    use crate::instructions::fee_config::check_not_paused;
+    use crate::instructions::reputation::initialize_reputation;
+    use crate::state::{AgentReputation, PlatformConfig};
+
+    #[test]
+    fn test_create_escrow_bootstraps_poster_reputation() {
+        let poster = [3u8; 32];
+        let now = 1_700_000_000;
+        let mut data = [0u8; 57];
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        let args = CreateEscrowData::try_from_slice(&data).unwrap();
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        initialize_escrow(&mut escrow_data, &args, &poster, 255, now, DEFAULT_EXPIRY_SECONDS, 0).unwrap();
+        let mut rep_data = std::vec![0u8; AgentReputation::SPACE];
+        initialize_reputation(&mut rep_data, &poster, 254, now, false).unwrap();
+
+        let escrow = JobEscrow::load(&escrow_data).unwrap();
+        assert_eq!(escrow.poster, poster);
+        assert!(escrow.is_active());
+        assert_eq!(escrow.expires_at, now + DEFAULT_EXPIRY_SECONDS);
+
+        // The reputation record belongs to the poster and is self-funded
+        let rep = AgentReputation::load(&rep_data).unwrap();
+        assert_eq!(rep.agent, escrow.poster);
+        assert_eq!(rep.created_at, now);
+        assert!(!rep.is_sponsored());
+    }
+
+    #[test]
+    fn test_create_fails_while_paused_and_succeeds_after_unpause() {
+        let mut data = [0u8; 57];
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        let args = CreateEscrowData::try_from_slice(&data).unwrap();
+        let mut config_data = std::vec![0u8; PlatformConfig::SPACE];
+        PlatformConfig::init(&mut config_data).unwrap().paused = 1;
+
+        // The steps of create_sol_escrow, against the config's data
+        let create = |config_data: &[u8], escrow_data: &mut [u8]| -> ProgramResult {
+            check_not_paused(Some(config_data))?;
+            initialize_escrow(escrow_data, &args, &[3u8; 32], 255, 0, DEFAULT_EXPIRY_SECONDS, 0)
+        };
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        assert_eq!(
+            create(&config_data, &mut escrow_data),
+            Err(ProgramError::from(EscrowError::ProgramPaused))
+        );
+        assert!(JobEscrow::load(&escrow_data).is_err());
+
+        PlatformConfig::load_mut(&mut config_data).unwrap().paused = 0;
+        create(&config_data, &mut escrow_data).unwrap();
+        assert!(JobEscrow::load(&escrow_data).unwrap().is_active());
+    }
+
+    #[test]
+    fn test_job_id_stored_when_it_matches_hash() {
+        let job_id = b"job-42";
+        let mut data = [0u8; 107 + 1 + 6];
+        data[..32].copy_from_slice(&sha256(job_id));
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        data[107] = job_id.len() as u8;
+        data[108..].copy_from_slice(job_id);
+        let args = CreateEscrowData::try_from_slice(&data).unwrap();
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        initialize_escrow(&mut escrow_data, &args, &[3u8; 32], 255, 0, DEFAULT_EXPIRY_SECONDS, 0).unwrap();
+        assert_eq!(JobEscrow::load(&escrow_data).unwrap().job_id(), job_id);
+
+        // Omitted, it is simply not stored
+        let args = CreateEscrowData::try_from_slice(&data[..107]).unwrap();
+        assert_eq!(args.job_id.len, 0);
+    }
+
+    #[test]
+    fn test_mismatched_job_id_rejected() {
+        let mut data = [0u8; 107 + 1 + 6];
+        data[..32].copy_from_slice(&sha256(b"job-42"));
+        data[107] = 6;
+        data[108..].copy_from_slice(b"job-43");
+        assert_eq!(
+            CreateEscrowData::try_from_slice(&data).err(),
+            Some(ProgramError::from(EscrowError::JobIdMismatch))
+        );
+
+        // Truncated or over-long job_ids are malformed
+        data[107] = 7;
+        assert_eq!(CreateEscrowData::try_from_slice(&data).err(), Some(ProgramError::InvalidInstructionData));
+        data[107] = MAX_JOB_ID_LEN as u8 + 1;
+        assert_eq!(
+            CreateEscrowData::try_from_slice(&data).err(),
+            Some(ProgramError::from(EscrowError::JobIdTooLong))
+        );
+    }
+
+    #[test]
+    fn test_delivery_deadline_within_expiry() {
+        let now = 1_700_000_000;
+        assert_eq!(resolve_delivery_deadline(now, DEFAULT_EXPIRY_SECONDS, 0), Ok(0));
+        assert_eq!(resolve_delivery_deadline(now, DEFAULT_EXPIRY_SECONDS, 3600), Ok(now + 3600));
+        assert_eq!(
+            resolve_delivery_deadline(now, DEFAULT_EXPIRY_SECONDS, DEFAULT_EXPIRY_SECONDS + 1),
+            Err(ProgramError::from(EscrowError::InvalidExpiry))
+        );
+
+        // Parsed from the optional trailing field
+        let mut data = [0u8; 65];
+        data[57..65].copy_from_slice(&3600i64.to_le_bytes());
+        assert_eq!(CreateEscrowData::try_from_slice(&data).unwrap().delivery_seconds, 3600);
+        assert_eq!(CreateEscrowData::try_from_slice(&data[..57]).unwrap().delivery_seconds, 0);
+    }
+
+    #[test]
+    fn test_referrer_recorded_on_escrow() {
+        let mut data = [0u8; 97];
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        data[65..97].copy_from_slice(&[7u8; 32]);
+        let args = CreateEscrowData::try_from_slice(&data).unwrap();
+        assert_eq!(args.referrer, [7u8; 32]);
+        assert_eq!(CreateEscrowData::try_from_slice(&data[..65]).unwrap().referrer, JobEscrow::DEFAULT_PUBKEY);
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        initialize_escrow(&mut escrow_data, &args, &[3u8; 32], 255, 0, DEFAULT_EXPIRY_SECONDS, 0).unwrap();
+        let escrow = JobEscrow::load(&escrow_data).unwrap();
+        assert!(escrow.has_referrer());
+        assert_eq!(escrow.referrer, [7u8; 32]);
+    }
+
+    #[test]
+    fn test_worker_cancellation_penalty_recorded_on_escrow() {
+        let mut data = [0u8; 113];
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        data[105..107].copy_from_slice(&2_500u16.to_le_bytes());
+        let args = CreateBilateralEscrowData::try_from_slice(&data).unwrap();
+        assert_eq!(args.escrow.worker_cancellation_penalty_bps, 2_500);
+        assert_eq!(
+            CreateBilateralEscrowData::try_from_slice(&data[..105]).unwrap().escrow.worker_cancellation_penalty_bps,
+            0
+        );
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        initialize_escrow(&mut escrow_data, &args.escrow, &[3u8; 32], 255, 0, DEFAULT_EXPIRY_SECONDS, 1_000).unwrap();
+        assert_eq!(JobEscrow::load(&escrow_data).unwrap().worker_cancellation_penalty_bps, 2_500);
+
+        // At most the whole penalty
+        data[105..107].copy_from_slice(&10_001u16.to_le_bytes());
+        assert!(CreateBilateralEscrowData::try_from_slice(&data).is_err());
+        let mut sol = [0u8; 99];
+        sol[97..99].copy_from_slice(&10_001u16.to_le_bytes());
+        assert_eq!(
+            CreateEscrowData::try_from_slice(&sol).err(),
+            Some(ProgramError::from(EscrowError::InvalidFeeBps))
+        );
+    }
+
+    #[test]
+    fn test_min_worker_score_recorded_on_escrow() {
+        let mut data = [0u8; 107];
+        data[32..40].copy_from_slice(&MIN_ESCROW_AMOUNT.to_le_bytes());
+        data[99..107].copy_from_slice(&40i64.to_le_bytes());
+        let args = CreateEscrowData::try_from_slice(&data).unwrap();
+        assert_eq!(args.min_worker_score, 40);
+        assert_eq!(CreateEscrowData::try_from_slice(&data[..99]).unwrap().min_worker_score, 0);
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        initialize_escrow(&mut escrow_data, &args, &[3u8; 32], 255, 0, DEFAULT_EXPIRY_SECONDS, 0).unwrap();
+        let escrow = JobEscrow::load(&escrow_data).unwrap();
+        assert_eq!(escrow.min_worker_score, 40);
+        assert!(!escrow.meets_min_worker_score(39));
+    }
+
+    #[test]
+    fn test_negative_expiry_rejected() {
+        assert_eq!(resolve_expiry(0), Ok(DEFAULT_EXPIRY_SECONDS));
+        assert_eq!(resolve_expiry(3600), Ok(3600));
+        assert_eq!(resolve_expiry(-1), Err(ProgramError::from(EscrowError::InvalidExpiry)));
+        assert_eq!(resolve_expiry(i64::MIN), Err(ProgramError::from(EscrowError::InvalidExpiry)));
+    }
+}
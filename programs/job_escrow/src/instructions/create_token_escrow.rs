@@ -1,6 +1,8 @@
 //! CreateTokenEscrow instruction
 //!
-//! Creates a new escrow account for SPL tokens and deposits tokens.
+//! Creates a new escrow account for SPL tokens and deposits tokens. The escrow's
+//! token account can be created in the same transaction by passing the
+//! Associated Token Account program.
 
 use pinocchio::{
     account_info::AccountInfo,
@@ -12,19 +14,26 @@ use pinocchio::{
     ProgramResult,
 };
 use pinocchio_system::instructions::CreateAccount;
+use pinocchio_associated_token_account::instructions::CreateIdempotent;
 use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus},
+    events::EscrowCreated,
+    state::{JobEscrow, EscrowStatus, MAX_CATEGORIES},
     require,
 };
 
 /// Minimum token escrow amount (1 token unit - actual minimum depends on decimals)
 pub const MIN_TOKEN_ESCROW_AMOUNT: u64 = 1;
 
-// Use DEFAULT_EXPIRY_SECONDS from create_escrow
-use super::create_escrow::DEFAULT_EXPIRY_SECONDS;
+// Expiry defaults and validation come from create_escrow
+use super::create_escrow::{
+    read_optional_duration, read_optional_job_id, read_optional_score, resolve_delivery_deadline, resolve_expiry,
+    JobIdField,
+};
+use super::fee_config::require_not_paused;
+use super::helpers::{TokenAccountInfo, ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID};
 
 /// Create token escrow instruction accounts
 /// Accounts:
@@ -35,6 +44,9 @@ use super::create_escrow::DEFAULT_EXPIRY_SECONDS;
 /// 4. escrow_token_account (writable) - escrow's ATA for the token
 /// 5. system_program
 /// 6. token_program
+/// 7. platform_config (readonly) - PDA ["platform_config"]; creation fails while paused
+/// 8. associated_token_program (optional) - if passed, the escrow's ATA is
+///    created here (no-op if it already exists)
 pub struct CreateTokenEscrowAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub poster: &'a AccountInfo,
@@ -43,13 +55,15 @@ pub struct CreateTokenEscrowAccounts<'a> {
     pub escrow_token_account: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub associated_token_program: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CreateTokenEscrowAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, poster, token_mint, poster_token_account, escrow_token_account, system_program, token_program, ..] = accounts else {
+        let [escrow, poster, token_mint, poster_token_account, escrow_token_account, system_program, token_program, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
@@ -69,6 +83,13 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateTokenEscrowAccounts<'a> {
             return Err(ProgramError::IncorrectProgramId);
         }
 
+        let associated_token_program = rest.first();
+        if let Some(program) = associated_token_program {
+            if program.key() != &ASSOCIATED_TOKEN_PROGRAM_ID {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+        }
+
         Ok(Self {
             escrow,
             poster,
@@ -77,16 +98,28 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateTokenEscrowAccounts<'a> {
             escrow_token_account,
             system_program,
             token_program,
+            platform_config,
+            associated_token_program,
         })
     }
 }
 
 /// Instruction data for CreateTokenEscrow
-/// Layout: [job_id_hash: [u8; 32], amount: u64, expiry_seconds: i64 (0 = default)]
+/// Layout: [job_id_hash: [u8; 32], amount: u64, expiry_seconds: i64 (0 = default),
+///          category: u8 (optional, 0 = default),
+///          max_dispute_duration: i64 (optional, 0 = no cap),
+///          delivery_seconds: i64 (optional, 0 = no delivery deadline),
+///          min_worker_score: i64 (optional, 0 = no minimum),
+///          job_id_len: u8 (optional, 0 = not stored), job_id: [u8; job_id_len]]
 pub struct CreateTokenEscrowData {
     pub job_id_hash: [u8; 32],
     pub amount: u64,
     pub expiry_seconds: i64,
+    pub category: u8,
+    pub max_dispute_duration: i64,
+    pub delivery_seconds: i64,
+    pub min_worker_score: i64,
+    pub job_id: JobIdField,
 }
 
 impl CreateTokenEscrowData {
@@ -98,15 +131,43 @@ impl CreateTokenEscrowData {
         let job_id_hash: [u8; 32] = data[0..32].try_into().unwrap();
         let amount = u64::from_le_bytes(data[32..40].try_into().unwrap());
         let expiry_seconds = i64::from_le_bytes(data[40..48].try_into().unwrap());
+        let category = data.get(48).copied().unwrap_or(0);
+        if category >= MAX_CATEGORIES {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let max_dispute_duration = read_optional_duration(data, 49)?;
+        let delivery_seconds = read_optional_duration(data, 57)?;
+        let min_worker_score = read_optional_score(data, 65);
+        let job_id = read_optional_job_id(data, 73, &job_id_hash)?;
         
         Ok(Self {
             job_id_hash,
             amount,
             expiry_seconds,
+            category,
+            max_dispute_duration,
+            delivery_seconds,
+            min_worker_score,
+            job_id,
         })
     }
 }
 
+/// Check that the escrow token account can hold this escrow's tokens
+///
+/// It must be an initialized token account for `mint` owned by the escrow
+/// PDA; otherwise the deposited tokens could not be released or refunded.
+fn check_escrow_token_account(data: &[u8], mint: &Pubkey, escrow: &Pubkey) -> ProgramResult {
+    let info = TokenAccountInfo::unpack(data)?;
+    require!(
+        info.state == TokenAccountInfo::STATE_INITIALIZED,
+        EscrowError::TokenAccountNotInitialized
+    );
+    require!(&info.mint == mint, EscrowError::TokenMintMismatch);
+    require!(&info.owner == escrow, EscrowError::TokenAccountOwnerMismatch);
+    Ok(())
+}
+
 /// Process create_token_escrow instruction
 pub fn process_create_token_escrow(
     accounts: &[AccountInfo],
@@ -116,6 +177,8 @@ pub fn process_create_token_escrow(
     let ctx = CreateTokenEscrowAccounts::try_from(accounts)?;
     let args = CreateTokenEscrowData::try_from_slice(data)?;
 
+    require_not_paused(ctx.platform_config, program_id)?;
+
     // Validate amount
     require!(args.amount >= MIN_TOKEN_ESCROW_AMOUNT, EscrowError::AmountTooLow);
 
@@ -123,12 +186,8 @@ pub fn process_create_token_escrow(
     let clock = Clock::get()?;
     
     // Calculate expiry
-    let expiry = if args.expiry_seconds > 0 {
-        args.expiry_seconds
-    } else {
-        DEFAULT_EXPIRY_SECONDS
-    };
-    require!(expiry > 0, EscrowError::InvalidExpiry);
+    let expiry = resolve_expiry(args.expiry_seconds)?;
+    let delivery_deadline = resolve_delivery_deadline(clock.unix_timestamp, expiry, args.delivery_seconds)?;
 
     // Derive PDA and verify
     let (expected_pda, bump) = find_program_address(
@@ -158,6 +217,30 @@ pub fn process_create_token_escrow(
     }
     .invoke_signed(&[signer])?;
 
+    // Create the escrow's ATA in the same transaction if requested. The ATA
+    // program derives and checks the address, and skips creation if it exists.
+    if ctx.associated_token_program.is_some() {
+        CreateIdempotent {
+            funding_account: ctx.poster,
+            account: ctx.escrow_token_account,
+            wallet: ctx.escrow,
+            mint: ctx.token_mint,
+            system_program: ctx.system_program,
+            token_program: ctx.token_program,
+        }
+        .invoke()?;
+    }
+
+    // The escrow token account must be a token account the escrow controls
+    if *ctx.escrow_token_account.owner() != TOKEN_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    check_escrow_token_account(
+        &ctx.escrow_token_account.try_borrow_data()?,
+        ctx.token_mint.key(),
+        ctx.escrow.key(),
+    )?;
+
     // Transfer tokens from poster to escrow token account
     TokenTransfer {
         from: ctx.poster_token_account,
@@ -189,6 +272,52 @@ pub fn process_create_token_escrow(
     escrow.is_token_escrow = 1;
     escrow.token_mint = *ctx.token_mint.key();
     escrow.escrow_token_account = *ctx.escrow_token_account.key();
+    escrow.category = args.category;
+    escrow.max_dispute_duration = args.max_dispute_duration;
+    escrow.delivery_deadline = delivery_deadline;
+    escrow.min_worker_score = args.min_worker_score;
+    escrow.job_id = args.job_id.bytes;
+    escrow.job_id_len = args.job_id.len;
+
+    EscrowCreated {
+        escrow: *ctx.escrow.key(),
+        poster: *ctx.poster.key(),
+        token_mint: *ctx.token_mint.key(),
+        amount: args.amount,
+        expires_at: escrow.expires_at,
+        worker_deposit: 0,
+        category: args.category,
+    }
+    .emit();
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::helpers::token_account_bytes;
+
+    #[test]
+    fn test_escrow_token_account_validation() {
+        let (mint, escrow) = ([1u8; 32], [2u8; 32]);
+
+        assert!(check_escrow_token_account(&token_account_bytes(mint, escrow, 0), &mint, &escrow).is_ok());
+        let mut uninitialized = token_account_bytes(mint, escrow, 0);
+        uninitialized[108] = 0;
+        assert_eq!(
+            check_escrow_token_account(&uninitialized, &mint, &escrow),
+            Err(ProgramError::from(EscrowError::TokenAccountNotInitialized))
+        );
+        assert_eq!(
+            check_escrow_token_account(&token_account_bytes([3u8; 32], escrow, 0), &mint, &escrow),
+            Err(ProgramError::from(EscrowError::TokenMintMismatch))
+        );
+        // Owned by the poster instead of the escrow PDA
+        assert_eq!(
+            check_escrow_token_account(&token_account_bytes(mint, [4u8; 32], 0), &mint, &escrow),
+            Err(ProgramError::from(EscrowError::TokenAccountOwnerMismatch))
+        );
+        assert!(check_escrow_token_account(&[0u8; 64], &mint, &escrow).is_err());
+    }
+}
@@ -1,40 +1,46 @@
 //! Dispute instructions
 //!
-//! Handles dispute initiation, refunds, and expired claims.
+//! Handles dispute initiation, refunds (SOL and SPL token), emergency refunds,
+//! expired claims, abandoned-dispute claims by the worker, and cancellation (alone before assignment, or by mutual
+//! agreement afterwards).
 
 use pinocchio::{
     account_info::AccountInfo,
+    instruction::Signer,
+    log::sol_log,
     program_error::ProgramError,
     pubkey::{find_program_address, Pubkey},
+    seeds,
     sysvars::{clock::Clock, Sysvar},
     ProgramResult,
 };
+use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus, DisputeCase},
+    events::{EmergencyRefundExecuted, FundsReleased},
+    state::{JobEscrow, EscrowStatus, DisputeCase, DisputeInitiator},
     require, require_some,
-    PLATFORM_WALLET,
     ID,
 };
 
+use super::helpers::{
+    require_writable, safe_pay_from_escrow, settle_worker_deposit, split_fee, verify_payout, DisputePayout,
+    RefundFunds, TokenAccountInfo, TOKEN_PROGRAM_ID,
+};
+use super::fee_config::{
+    abandoned_dispute_seconds, platform_authority, platform_fee_bps, require_platform_authority,
+    require_platform_recipient,
+};
+
 /// Minimum timelock for refunds after dispute: 24 hours
 pub const REFUND_TIMELOCK_SECONDS: i64 = 24 * 60 * 60;
 
 /// Grace period after arbitration expiry before emergency release (48 hours)
 pub const ARBITRATION_GRACE_PERIOD: i64 = 48 * 60 * 60;
 
-/// Transfer lamports
-#[inline(always)]
-fn transfer_lamports(
-    from: &AccountInfo,
-    to: &AccountInfo,
-    amount: u64,
-) -> ProgramResult {
-    *from.try_borrow_mut_lamports()? -= amount;
-    *to.try_borrow_mut_lamports()? += amount;
-    Ok(())
-}
+/// How long the poster cannot cancel after the first bid is placed (6 hours)
+pub const CANCEL_GRACE_SECONDS: i64 = 6 * 60 * 60;
 
 // ============== INITIATE DISPUTE ==============
 
@@ -42,21 +48,26 @@ fn transfer_lamports(
 pub struct InitiateDisputeAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub initiator: &'a AccountInfo,
+    /// Platform config (PDA ["platform_config"]) - required when the platform
+    /// authority initiates
+    pub platform_config: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for InitiateDisputeAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, initiator, ..] = accounts else {
+        let [escrow, initiator, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow])?;
+
         if !initiator.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, initiator })
+        Ok(Self { escrow, initiator, platform_config: rest.first() })
     }
 }
 
@@ -85,48 +96,82 @@ pub fn process_initiate_dispute(
     require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
 
-    // Must be Active or PendingReview
-    require!(
-        escrow.status == EscrowStatus::Active as u8 || escrow.status == EscrowStatus::PendingReview as u8,
-        EscrowError::EscrowNotActive
-    );
-
-    // Initiator must be poster or platform
+    // Initiator must be poster, platform, or the assigned worker
     let initiator_key = ctx.initiator.key();
-    let is_poster = initiator_key == &escrow.poster;
-    let is_platform = initiator_key == &PLATFORM_WALLET;
-    require!(is_poster || is_platform, EscrowError::Unauthorized);
-
-    escrow.status = EscrowStatus::Disputed as u8;
-    escrow.dispute_initiated_at = clock.unix_timestamp;
-
-    Ok(())
+    let is_platform = match ctx.platform_config {
+        Some(platform_config) => initiator_key == &platform_authority(platform_config, program_id)?,
+        None => false,
+    };
+    let initiator = if initiator_key == &escrow.poster {
+        DisputeInitiator::Poster
+    } else if is_platform {
+        DisputeInitiator::Platform
+    } else if escrow.has_worker() && initiator_key == &escrow.worker {
+        DisputeInitiator::Worker
+    } else {
+        return Err(EscrowError::Unauthorized.into());
+    };
+
+    escrow.initiate_dispute(initiator, clock.unix_timestamp)
 }
 
 // ============== REFUND TO POSTER ==============
 
 /// Refund to poster accounts
+///
+/// Token escrows pass their RefundTokenAccounts after the platform config.
 pub struct RefundToPosterAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub platform_authority: &'a AccountInfo,
     pub poster: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub tokens: Option<RefundTokenAccounts<'a>>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for RefundToPosterAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, platform_authority, poster, ..] = accounts else {
+        let [escrow, platform_authority, poster, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, poster])?;
+
         if !platform_authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(platform_authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
+        let tokens = RefundTokenAccounts::from_trailing(rest)?;
 
-        Ok(Self { escrow, platform_authority, poster })
+        Ok(Self { escrow, platform_authority, poster, platform_config, tokens })
+    }
+}
+
+impl<'a> RefundToPosterAccounts<'a> {
+    /// Parse the refund_tokens_to_poster layout
+    /// Accounts:
+    /// 0. escrow (PDA, writable)
+    /// 1. platform_authority (signer) - the platform authority, per the platform config
+    /// 2. poster (writable) - gets any worker bond
+    /// 3. escrow_token_account (writable) - escrow's token account
+    /// 4. poster_token_account (writable) - poster's ATA
+    /// 5. token_program
+    /// 6. platform_config (readonly) - PDA ["platform_config"], names the authority
+    pub fn from_token_layout(accounts: &'a [AccountInfo]) -> Result<Self, ProgramError> {
+        let [escrow, platform_authority, poster, _, _, _, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, poster])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        let tokens = RefundTokenAccounts::try_from(&accounts[3..6])?;
+
+        Ok(Self { escrow, platform_authority, poster, platform_config, tokens: Some(tokens) })
     }
 }
 
@@ -136,9 +181,27 @@ pub fn process_refund_to_poster(
     _data: &[u8],
     program_id: &Pubkey,
 ) -> ProgramResult {
-    let ctx = RefundToPosterAccounts::try_from(accounts)?;
+    refund_to_poster(&RefundToPosterAccounts::try_from(accounts)?, program_id)
+}
+
+/// Process refund_tokens_to_poster instruction
+///
+/// Token escrow entry point for refund_to_poster with the token accounts
+/// ahead of the platform config; the refund itself is the same.
+pub fn process_refund_tokens_to_poster(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    refund_to_poster(&RefundToPosterAccounts::from_token_layout(accounts)?, program_id)
+}
+
+/// Refund a disputed or cancelled escrow to its poster
+fn refund_to_poster(ctx: &RefundToPosterAccounts, program_id: &Pubkey) -> ProgramResult {
     let clock = Clock::get()?;
 
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
     // SECURITY FIX C-01: Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
@@ -164,8 +227,9 @@ pub fn process_refund_to_poster(
     // Verify poster
     require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
 
-    // If disputed, check timelock
+    // If disputed, check timelock; a worker's dispute must go to arbitration
     if escrow.status == EscrowStatus::Disputed as u8 {
+        require!(!escrow.disputed_by_worker(), EscrowError::WorkerDisputeNeedsArbitration);
         let dispute_time = require_some!(escrow.get_dispute_initiated_at(), EscrowError::NoDisputeTime);
         require!(
             clock.unix_timestamp >= dispute_time + REFUND_TIMELOCK_SECONDS,
@@ -173,10 +237,211 @@ pub fn process_refund_to_poster(
         );
     }
 
-    let amount = escrow.amount;
     escrow.status = EscrowStatus::Refunded as u8;
 
-    transfer_lamports(ctx.escrow, ctx.poster, amount)?;
+    refund_escrow_funds(escrow, ctx.escrow, ctx.poster, ctx.tokens.as_ref())?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.poster)?;
+
+    Ok(())
+}
+
+/// Token accounts a refund needs for a token escrow
+/// Accounts:
+/// 0. escrow_token_account (writable) - escrow's token account
+/// 1. poster_token_account (writable) - poster's ATA
+/// 2. token_program
+pub struct RefundTokenAccounts<'a> {
+    pub escrow_token_account: &'a AccountInfo,
+    pub poster_token_account: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RefundTokenAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow_token_account, poster_token_account, token_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow_token_account, poster_token_account])?;
+
+        // Verify token program ID
+        if token_program.key() != &TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self { escrow_token_account, poster_token_account, token_program })
+    }
+}
+
+impl<'a> RefundTokenAccounts<'a> {
+    /// Parse the optional trailing token accounts of a refund instruction
+    ///
+    /// Token accounts are only needed (and only parsed) for token escrows.
+    pub fn from_trailing(rest: &'a [AccountInfo]) -> Result<Option<Self>, ProgramError> {
+        if rest.is_empty() {
+            Ok(None)
+        } else {
+            Self::try_from(rest).map(Some)
+        }
+    }
+}
+
+/// Return everything the escrow still holds to the poster
+///
+/// SOL escrows pay out of the escrow's lamports; token escrows transfer from
+/// the escrow token account and leave the escrow's rent lamports in place.
+/// The escrow is settled, so nothing can be refunded from it twice. Token
+/// accounts passed for a SOL escrow are rejected rather than ignored.
+fn refund_escrow_funds(
+    escrow: &mut JobEscrow,
+    escrow_account: &AccountInfo,
+    poster: &AccountInfo,
+    tokens: Option<&RefundTokenAccounts>,
+) -> ProgramResult {
+    let funds = RefundFunds::for_escrow(escrow);
+    escrow.settle_remaining();
+    match funds {
+        RefundFunds::Lamports(amount) => {
+            require!(tokens.is_none(), EscrowError::NotTokenEscrow);
+            safe_pay_from_escrow(escrow_account, poster, amount)
+        }
+        RefundFunds::Tokens(amount) => {
+            let tokens = require_some!(tokens, ProgramError::NotEnoughAccountKeys);
+            refund_tokens(
+                escrow,
+                escrow_account,
+                tokens.escrow_token_account,
+                tokens.poster_token_account,
+                amount,
+            )
+        }
+    }
+}
+
+/// Return `amount` of a token escrow's tokens from its token account to the poster
+///
+/// Checks that `escrow_token_account` is the one stored on the escrow and that
+/// `poster_token_account` holds the escrow's mint and belongs to the poster.
+fn refund_tokens(
+    escrow: &JobEscrow,
+    escrow_account: &AccountInfo,
+    escrow_token_account: &AccountInfo,
+    poster_token_account: &AccountInfo,
+    amount: u64,
+) -> ProgramResult {
+    pay_escrow_tokens(escrow, escrow_account, escrow_token_account, poster_token_account, &escrow.poster, amount)
+}
+
+/// Check that token account `data` can receive a token escrow's payout to `recipient`
+///
+/// It must be an initialized token account for the escrow's mint owned by
+/// `recipient`.
+fn check_token_destination(data: &[u8], escrow: &JobEscrow, recipient: &Pubkey) -> ProgramResult {
+    let info = TokenAccountInfo::unpack(data)?;
+    require!(info.state == TokenAccountInfo::STATE_INITIALIZED, EscrowError::InvalidAccountData);
+    require!(info.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    require!(&info.owner == recipient, EscrowError::TokenAccountMismatch);
+    Ok(())
+}
+
+/// Pay `amount` of a token escrow's tokens from its token account to `recipient`
+///
+/// Checks that `escrow_token_account` is the one stored on the escrow and that
+/// `to` holds the escrow's mint and belongs to `recipient`.
+fn pay_escrow_tokens(
+    escrow: &JobEscrow,
+    escrow_account: &AccountInfo,
+    escrow_token_account: &AccountInfo,
+    to: &AccountInfo,
+    recipient: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    // Verify the escrow token account matches
+    require!(escrow_token_account.key() == &escrow.escrow_token_account, EscrowError::TokenAccountMismatch);
+
+    // Destination must hold the escrow's mint and belong to the recipient
+    if *to.owner() != TOKEN_PROGRAM_ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    check_token_destination(&to.try_borrow_data()?, escrow, recipient)?;
+
+    // Create PDA signer for the token transfer
+    let bump_ref = &[escrow.bump];
+    let signer_seeds = seeds!(b"escrow", &escrow.job_id_hash, &escrow.poster, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    TokenTransfer {
+        from: escrow_token_account,
+        to,
+        authority: escrow_account,
+        amount,
+    }
+    .invoke_signed(&[signer])
+}
+
+// ============== EMERGENCY REFUND ==============
+
+/// Emergency refund accounts (same as refund_to_poster)
+pub type EmergencyRefundAccounts<'a> = RefundToPosterAccounts<'a>;
+
+/// Process emergency_refund instruction
+///
+/// Platform-only refund of a disputed escrow that skips the 24h timelock.
+/// Reserved for clear-cut fraud; every use is logged and emitted as an event.
+pub fn process_emergency_refund(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = EmergencyRefundAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // Skips the timelock, so only the platform authority may call it
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    // Only disputed escrows; the timelock is the only check skipped
+    require!(escrow.status == EscrowStatus::Disputed as u8, EscrowError::RefundNotAllowed);
+    require!(escrow.is_token_escrow == 0, EscrowError::UnsupportedTokenEscrow);
+
+    // Verify poster
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+
+    let dispute_time = require_some!(escrow.get_dispute_initiated_at(), EscrowError::NoDisputeTime);
+
+    sol_log("EMERGENCY REFUND: dispute timelock bypassed by platform authority");
+
+    let amount = escrow.settle_remaining();
+    escrow.status = EscrowStatus::Refunded as u8;
+
+    safe_pay_from_escrow(ctx.escrow, ctx.poster, amount)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.poster)?;
+
+    EmergencyRefundExecuted {
+        escrow: *ctx.escrow.key(),
+        poster: escrow.poster,
+        amount,
+        dispute_initiated_at: dispute_time,
+        executed_at: clock.unix_timestamp,
+    }
+    .emit();
 
     Ok(())
 }
@@ -184,24 +449,32 @@ pub fn process_refund_to_poster(
 // ============== CLAIM EXPIRED ==============
 
 /// Claim expired accounts
+///
+/// Token escrows pass their RefundTokenAccounts after the poster; this is
+/// also the claim_tokens_expired layout.
 pub struct ClaimExpiredAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub poster: &'a AccountInfo,
+    pub tokens: Option<RefundTokenAccounts<'a>>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ClaimExpiredAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, poster, ..] = accounts else {
+        let [escrow, poster, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, poster])?;
+
         if !poster.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, poster })
+        let tokens = RefundTokenAccounts::from_trailing(rest)?;
+
+        Ok(Self { escrow, poster, tokens })
     }
 }
 
@@ -210,8 +483,26 @@ pub fn process_claim_expired(
     accounts: &[AccountInfo],
     _data: &[u8],
     program_id: &Pubkey,
+) -> ProgramResult {
+    claim_expired(&ClaimExpiredAccounts::try_from(accounts)?, program_id)
+}
+
+/// Process claim_tokens_expired instruction
+///
+/// Token escrow entry point for claim_expired: same accounts, but the token
+/// accounts are required.
+pub fn process_claim_tokens_expired(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = ClaimExpiredAccounts::try_from(accounts)?;
+    require!(ctx.tokens.is_some(), ProgramError::NotEnoughAccountKeys);
+    claim_expired(&ctx, program_id)
+}
+
+/// Return an expired, still-active escrow to its poster
+fn claim_expired(ctx: &ClaimExpiredAccounts, program_id: &Pubkey) -> ProgramResult {
     let clock = Clock::get()?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
@@ -234,10 +525,56 @@ pub fn process_claim_expired(
     require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
     require!(clock.unix_timestamp >= escrow.expires_at, EscrowError::NotExpired);
 
-    let amount = escrow.amount;
     escrow.status = EscrowStatus::Expired as u8;
 
-    transfer_lamports(ctx.escrow, ctx.poster, amount)?;
+    refund_escrow_funds(escrow, ctx.escrow, ctx.poster, ctx.tokens.as_ref())?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.poster)?;
+
+    Ok(())
+}
+
+// ============== CLAIM MISSED DEADLINE ==============
+
+/// Process claim_missed_deadline instruction
+///
+/// Same accounts as claim_expired. The poster reclaims an Active escrow whose
+/// worker never submitted work by the delivery deadline.
+pub fn process_claim_missed_deadline(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ClaimExpiredAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+    require!(
+        escrow.missed_delivery(clock.unix_timestamp),
+        EscrowError::DeliveryDeadlineNotMissed
+    );
+
+    escrow.status = EscrowStatus::Expired as u8;
+
+    // A worker who missed the deadline forfeits any posted deposit
+    refund_escrow_funds(escrow, ctx.escrow, ctx.poster, ctx.tokens.as_ref())?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.poster)?;
 
     Ok(())
 }
@@ -245,24 +582,31 @@ pub fn process_claim_expired(
 // ============== CANCEL ESCROW ==============
 
 /// Cancel escrow accounts
+///
+/// Token escrows pass their RefundTokenAccounts after the poster.
 pub struct CancelEscrowAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub poster: &'a AccountInfo,
+    pub tokens: Option<RefundTokenAccounts<'a>>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CancelEscrowAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, poster, ..] = accounts else {
+        let [escrow, poster, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, poster])?;
+
         if !poster.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, poster })
+        let tokens = RefundTokenAccounts::from_trailing(rest)?;
+
+        Ok(Self { escrow, poster, tokens })
     }
 }
 
@@ -273,6 +617,7 @@ pub fn process_cancel_escrow(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = CancelEscrowAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
@@ -293,11 +638,218 @@ pub fn process_cancel_escrow(
     require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
     require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
     require!(!escrow.has_worker(), EscrowError::WorkerAlreadyAssigned);
+    // Bidders get a fair window before the job can be pulled
+    require!(
+        !escrow.in_cancel_grace(clock.unix_timestamp, CANCEL_GRACE_SECONDS),
+        EscrowError::CancelGraceActive
+    );
+
+    escrow.status = EscrowStatus::Cancelled as u8;
+
+    refund_escrow_funds(escrow, ctx.escrow, ctx.poster, ctx.tokens.as_ref())?;
+
+    Ok(())
+}
+
+// ============== MUTUAL CANCEL ==============
+
+/// Both parties must sign a mutual cancellation
+fn require_mutual_consent(poster_signed: bool, worker_signed: bool) -> ProgramResult {
+    if !poster_signed || !worker_signed {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(())
+}
+
+/// Mutual cancel accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer, writable)
+/// 2. worker (signer, writable) - the assigned worker
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee
+pub struct MutualCancelAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MutualCancelAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, poster, worker, platform, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, poster, worker, platform])?;
+        require_mutual_consent(poster.is_signer(), worker.is_signer())?;
+
+        Ok(Self { escrow, poster, worker, platform, platform_config })
+    }
+}
+
+/// Instruction data for MutualCancel
+/// Layout: [worker_bps: u16]
+pub struct MutualCancelData {
+    pub worker_bps: u16,
+}
+
+impl MutualCancelData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let bytes = data.get(..2).ok_or(ProgramError::InvalidInstructionData)?;
+        let worker_bps = u16::from_le_bytes([bytes[0], bytes[1]]);
+        Ok(Self { worker_bps })
+    }
+}
+
+/// Process mutual_cancel instruction
+///
+/// Poster and assigned worker agree to walk away: the worker keeps
+/// `worker_bps` of what remains in the escrow (after the platform fee) for
+/// partial work, the poster gets the rest back, and a posted worker deposit
+/// is returned. SOL escrows only.
+pub fn process_mutual_cancel(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = MutualCancelAccounts::try_from(accounts)?;
+    let args = MutualCancelData::try_from_slice(data)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(escrow.is_token_escrow == 0, EscrowError::UnsupportedTokenEscrow);
+    require!(
+        escrow.is_active() || escrow.is_pending_review(),
+        EscrowError::EscrowNotActive
+    );
+    require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    let amount = escrow.amount;
+    let payout = DisputePayout::for_mutual_cancel(escrow.remaining_amount(), args.worker_bps, fee_bps)?;
+
+    escrow.settle_remaining();
     escrow.status = EscrowStatus::Cancelled as u8;
 
-    transfer_lamports(ctx.escrow, ctx.poster, amount)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, payout.worker)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.poster, payout.poster)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, payout.platform)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
+
+    Ok(())
+}
+
+// ============== CLAIM ABANDONED DISPUTE ==============
+
+/// Claim abandoned dispute accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. worker (signer, writable)
+/// 2. platform (writable) - the platform authority, per the platform config
+/// 3. platform_config (readonly) - PDA ["platform_config"], sets the fee and window
+pub struct ClaimAbandonedDisputeAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAbandonedDisputeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, worker, platform, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, worker, platform])?;
+
+        if !worker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, worker, platform, platform_config })
+    }
+}
+
+/// Process claim_abandoned_dispute instruction
+///
+/// A simple dispute that was never escalated to arbitration nor refunded
+/// within the configured window counts as abandoned by the poster; the
+/// worker is paid as on a normal release.
+pub fn process_claim_abandoned_dispute(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ClaimAbandonedDisputeAccounts::try_from(accounts)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+    let window = abandoned_dispute_seconds(ctx.platform_config, program_id)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(escrow.is_token_escrow == 0, EscrowError::UnsupportedTokenEscrow);
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+    require!(
+        escrow.dispute_abandoned(clock.unix_timestamp, window),
+        EscrowError::DisputeNotAbandoned
+    );
+
+    let amount = escrow.settle_remaining();
+    let (worker_payment, platform_fee) = split_fee(amount, fee_bps)?;
+    verify_payout(amount, &[worker_payment, platform_fee])?;
+
+    escrow.status = EscrowStatus::Released as u8;
+
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
+    }
+    .emit();
+
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, worker_payment)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, platform_fee)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
 
     Ok(())
 }
@@ -306,25 +858,45 @@ pub fn process_cancel_escrow(
 
 /// Claim expired arbitration accounts
 /// SECURITY FIX H-02: Now requires dispute_case account to read voting_deadline
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. dispute_case (readonly)
+/// 2. poster (signer, writable) - refunded the disputed part
+/// 3. worker (writable) - paid the undisputed rest
+/// 4. escrow_token_account (token escrows only, writable)
+/// 5. poster_token_account (token escrows only, writable)
+/// 6. token_program (token escrows only)
+/// 7. worker_token_account (token escrows only, writable)
 pub struct ClaimExpiredArbitrationAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub dispute_case: &'a AccountInfo,
     pub poster: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub tokens: Option<RefundTokenAccounts<'a>>,
+    pub worker_token_account: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ClaimExpiredArbitrationAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, dispute_case, poster, ..] = accounts else {
+        let [escrow, dispute_case, poster, worker, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, poster, worker])?;
+
         if !poster.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        Ok(Self { escrow, dispute_case, poster })
+        let tokens = RefundTokenAccounts::from_trailing(rest)?;
+        let worker_token_account = rest.get(3);
+        if let Some(worker_token_account) = worker_token_account {
+            require_writable(&[worker_token_account])?;
+        }
+
+        Ok(Self { escrow, dispute_case, poster, worker, tokens, worker_token_account })
     }
 }
 
@@ -373,20 +945,123 @@ pub fn process_claim_expired_arbitration(
     let dispute_data = ctx.dispute_case.try_borrow_data()?;
     let dispute = DisputeCase::load(&dispute_data)?;
 
-    // Must be past voting_deadline + grace period
-    let emergency_deadline = dispute.voting_deadline.saturating_add(ARBITRATION_GRACE_PERIOD);
+    // Must be past voting_deadline + grace period, or the escrow's dispute cap
+    let emergency_deadline =
+        dispute.emergency_deadline(ARBITRATION_GRACE_PERIOD, escrow.max_dispute_duration);
     require!(
         clock.unix_timestamp >= emergency_deadline,
         EscrowError::ArbitrationGracePeriodNotPassed
     );
 
+    let disputed_amount = dispute.disputed_amount;
     drop(dispute_data);
 
-    let amount = escrow.amount;
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+
     escrow.status = EscrowStatus::Refunded as u8;
 
-    // No fee for emergency release
-    transfer_lamports(ctx.escrow, ctx.poster, amount)?;
+    // No fee for emergency release; only the disputed part goes back
+    let payout = DisputePayout::for_expired_arbitration(escrow.settle_remaining(), disputed_amount)?;
+    if escrow.is_token_escrow() {
+        let tokens = require_some!(ctx.tokens.as_ref(), ProgramError::NotEnoughAccountKeys);
+        let worker_token_account = require_some!(ctx.worker_token_account, ProgramError::NotEnoughAccountKeys);
+        pay_escrow_tokens(
+            escrow,
+            ctx.escrow,
+            tokens.escrow_token_account,
+            worker_token_account,
+            &escrow.worker,
+            payout.worker,
+        )?;
+        refund_tokens(escrow, ctx.escrow, tokens.escrow_token_account, tokens.poster_token_account, payout.poster)?;
+    } else {
+        safe_pay_from_escrow(ctx.escrow, ctx.worker, payout.worker)?;
+        safe_pay_from_escrow(ctx.escrow, ctx.poster, payout.poster)?;
+    }
+    settle_worker_deposit(escrow, ctx.escrow, ctx.poster)?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::helpers::{token_account_bytes, transfer_token_bytes};
+
+    #[test]
+    fn test_mutual_cancel_needs_both_signatures() {
+        assert!(require_mutual_consent(true, true).is_ok());
+        assert_eq!(require_mutual_consent(true, false), Err(ProgramError::MissingRequiredSignature));
+        assert_eq!(require_mutual_consent(false, true), Err(ProgramError::MissingRequiredSignature));
+        assert_eq!(require_mutual_consent(false, false), Err(ProgramError::MissingRequiredSignature));
+    }
+
+    #[test]
+    fn test_token_refund_checks_poster_token_account() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.poster = [1u8; 32];
+        escrow.token_mint = [2u8; 32];
+        escrow.is_token_escrow = 1;
+        escrow.amount = 1_000_000;
+
+        let poster_ata = token_account_bytes([2u8; 32], [1u8; 32], 0);
+        assert!(check_token_destination(&poster_ata, escrow, &escrow.poster).is_ok());
+        // Someone else's account, another mint, or an uninitialized account
+        assert_eq!(
+            check_token_destination(&token_account_bytes([2u8; 32], [3u8; 32], 0), escrow, &escrow.poster),
+            Err(ProgramError::from(EscrowError::TokenAccountMismatch))
+        );
+        assert_eq!(
+            check_token_destination(&token_account_bytes([4u8; 32], [1u8; 32], 0), escrow, &escrow.poster),
+            Err(ProgramError::from(EscrowError::TokenAccountMismatch))
+        );
+        let mut uninitialized = poster_ata;
+        uninitialized[108] = 0;
+        assert_eq!(
+            check_token_destination(&uninitialized, escrow, &escrow.poster),
+            Err(ProgramError::from(EscrowError::InvalidAccountData))
+        );
+        assert!(check_token_destination(&poster_ata[..100], escrow, &escrow.poster).is_err());
+
+        // Refunds move tokens, and milestones already paid are not refunded again
+        assert_eq!(RefundFunds::for_escrow(escrow), RefundFunds::Tokens(1_000_000));
+        escrow.set_milestones(&[400_000, 600_000]).unwrap();
+        escrow.release_milestone(0).unwrap();
+        assert_eq!(RefundFunds::for_escrow(escrow), RefundFunds::Tokens(600_000));
+    }
+
+    #[test]
+    fn test_token_refund_drains_escrow_token_account() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.poster = [1u8; 32];
+        escrow.token_mint = [2u8; 32];
+        escrow.is_token_escrow = 1;
+        escrow.amount = 1_000_000;
+        escrow.set_milestones(&[400_000, 600_000]).unwrap();
+
+        let escrow_pda = [9u8; 32];
+        let mut escrow_ata = token_account_bytes(escrow.token_mint, escrow_pda, 1_000_000);
+        let mut worker_ata = token_account_bytes(escrow.token_mint, [3u8; 32], 0);
+        let mut poster_ata = token_account_bytes(escrow.token_mint, escrow.poster, 25_000);
+
+        // First milestone goes to the worker before the refund
+        let milestone = escrow.release_milestone(0).unwrap();
+        transfer_token_bytes(&mut escrow_ata, &mut worker_ata, milestone);
+
+        // The refund moves exactly what the escrow token account still holds
+        let RefundFunds::Tokens(amount) = RefundFunds::for_escrow(escrow) else {
+            panic!("token escrows refund tokens");
+        };
+        assert_eq!(escrow.settle_remaining(), amount);
+        transfer_token_bytes(&mut escrow_ata, &mut poster_ata, amount);
+
+        assert_eq!(TokenAccountInfo::unpack(&poster_ata).unwrap().amount, 625_000);
+        assert_eq!(TokenAccountInfo::unpack(&escrow_ata).unwrap().amount, 0);
+        assert_eq!(TokenAccountInfo::unpack(&worker_ata).unwrap().amount, 400_000);
+
+        // Settled: a second refund would move nothing
+        assert_eq!(RefundFunds::for_escrow(escrow), RefundFunds::Tokens(0));
+    }
+}
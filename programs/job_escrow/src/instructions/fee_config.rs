@@ -0,0 +1,825 @@
+//! Fee and platform configuration instructions
+//!
+//! The platform can override the token release fee for individual mints via
+//! a MintFeeConfig PDA (seeds ["mint_fee", mint]), and tune platform-wide
+//! parameters in the PlatformConfig PDA (seeds ["platform_config"]).
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+use pinocchio_system::instructions::CreateAccount;
+
+use crate::{
+    errors::EscrowError,
+    state::{
+        MintFeeConfig, PlatformConfig, DEFAULT_ABANDONED_DISPUTE_SECONDS, MAX_PANEL_SIZE,
+        DEFAULT_PLATFORM_FEE_BPS, MAX_CRANK_BOUNTY_BPS, MAX_PLATFORM_FEE_BPS,
+    },
+    require,
+    ID,
+    PLATFORM_WALLET,
+};
+
+use super::helpers::{close_account, require_writable, TOKEN_PROGRAM_ID};
+
+/// Seed prefix for MintFeeConfig PDAs
+pub const MINT_FEE_SEED: &[u8] = b"mint_fee";
+
+/// Seed for the PlatformConfig PDA
+pub const PLATFORM_CONFIG_SEED: &[u8] = b"platform_config";
+
+/// Derive the PlatformConfig PDA
+#[inline(always)]
+pub fn find_platform_config(program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[PLATFORM_CONFIG_SEED], program_id)
+}
+
+/// Verify `account` is the PlatformConfig PDA owned by this program
+pub fn check_platform_config(account: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    if *account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(account.key() == &expected_pda, EscrowError::InvalidPda);
+    Ok(())
+}
+
+/// Fail with ProgramPaused while the platform config is paused
+///
+/// The account must be the config PDA; until the platform creates it,
+/// nothing is paused. Only the flag byte is read.
+pub fn require_not_paused(platform_config: &AccountInfo, program_id: &Pubkey) -> ProgramResult {
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+    if *platform_config.owner() != ID {
+        return check_not_paused(None);
+    }
+    let config_data = platform_config.try_borrow_data()?;
+    check_not_paused(Some(&config_data))
+}
+
+/// Pause check shared by escrow creation and every release, on the platform
+/// config's data (None = the config does not exist yet)
+#[inline(always)]
+pub fn check_not_paused(config_data: Option<&[u8]>) -> ProgramResult {
+    match config_data {
+        Some(data) => PlatformConfig::load(data)?.require_not_paused(),
+        None => Ok(()),
+    }
+}
+
+/// Platform authority, read from the platform config
+///
+/// The account must be the config PDA; until the platform creates it, the
+/// authority is PLATFORM_WALLET.
+pub fn platform_authority(platform_config: &AccountInfo, program_id: &Pubkey) -> Result<Pubkey, ProgramError> {
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+    if *platform_config.owner() != ID {
+        return Ok(PLATFORM_WALLET);
+    }
+    let config_data = platform_config.try_borrow_data()?;
+    Ok(PlatformConfig::load(&config_data)?.authority())
+}
+
+/// Fail unless `signer` signed and is the platform authority
+pub fn require_platform_authority(
+    signer: &AccountInfo,
+    platform_config: &AccountInfo,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    if !signer.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    check_platform_authority(signer.key(), &platform_authority(platform_config, program_id)?)
+}
+
+/// Fail unless `platform` is the platform authority, which receives platform
+/// fees, failed appeal bonds and slashed stake
+pub fn require_platform_recipient(
+    platform: &AccountInfo,
+    platform_config: &AccountInfo,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    check_platform_authority(platform.key(), &platform_authority(platform_config, program_id)?)
+}
+
+/// Fail with NotPlatformAuthority unless `signer` is `authority`
+#[inline(always)]
+pub fn check_platform_authority(signer: &Pubkey, authority: &Pubkey) -> ProgramResult {
+    require!(signer == authority, EscrowError::NotPlatformAuthority);
+    Ok(())
+}
+
+/// Platform fee for SOL releases, read from the platform config
+///
+/// The account must be the config PDA; until the platform creates it, the
+/// default fee applies.
+pub fn platform_fee_bps(platform_config: &AccountInfo, program_id: &Pubkey) -> Result<u16, ProgramError> {
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+    if *platform_config.owner() != ID {
+        return Ok(DEFAULT_PLATFORM_FEE_BPS);
+    }
+    let config_data = platform_config.try_borrow_data()?;
+    Ok(PlatformConfig::load(&config_data)?.fee_bps)
+}
+
+/// Abandoned-dispute window, read from the platform config
+///
+/// Like platform_fee_bps, the default applies until the config exists.
+pub fn abandoned_dispute_seconds(platform_config: &AccountInfo, program_id: &Pubkey) -> Result<i64, ProgramError> {
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+    if *platform_config.owner() != ID {
+        return Ok(DEFAULT_ABANDONED_DISPUTE_SECONDS);
+    }
+    let config_data = platform_config.try_borrow_data()?;
+    Ok(PlatformConfig::load(&config_data)?.abandoned_dispute_window())
+}
+
+/// Referrers' share of the platform fee, read from the platform config
+///
+/// No referral is paid until the config exists.
+pub fn referral_bps(platform_config: &AccountInfo, program_id: &Pubkey) -> Result<u16, ProgramError> {
+    let (expected_pda, _) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+    if *platform_config.owner() != ID {
+        return Ok(0);
+    }
+    let config_data = platform_config.try_borrow_data()?;
+    Ok(PlatformConfig::load(&config_data)?.referral_bps)
+}
+
+/// Derive the MintFeeConfig PDA for a mint
+#[inline(always)]
+pub fn find_mint_fee_config(mint: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[MINT_FEE_SEED, mint], program_id)
+}
+
+/// Platform fee for `mint` as (fee_bps, min_fee), read from an optional
+/// MintFeeConfig account
+///
+/// Falls back to the default fee, with no floor, when no config account is passed.
+pub fn mint_fee(
+    config: Option<&AccountInfo>,
+    mint: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<(u16, u64), ProgramError> {
+    let Some(config) = config else {
+        return Ok((MintFeeConfig::resolve_fee_bps(None), MintFeeConfig::resolve_min_fee(None)));
+    };
+    if *config.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let (expected_pda, _) = find_mint_fee_config(mint, program_id);
+    require!(config.key() == &expected_pda, EscrowError::InvalidPda);
+
+    let data = config.try_borrow_data()?;
+    let fee_config = MintFeeConfig::load(&data)?;
+    Ok((
+        MintFeeConfig::resolve_fee_bps(Some(fee_config)),
+        MintFeeConfig::resolve_min_fee(Some(fee_config)),
+    ))
+}
+
+// ============== SET MINT FEE ==============
+
+/// Set mint fee accounts
+/// Accounts:
+/// 0. mint_fee_config (PDA, writable) - created on first use
+/// 1. mint (readonly)
+/// 2. platform_authority (signer, writable) - the platform authority, pays rent
+/// 3. system_program
+/// 4. platform_config (readonly) - PDA ["platform_config"], names the authority
+pub struct SetMintFeeAccounts<'a> {
+    pub mint_fee_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetMintFeeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [mint_fee_config, mint, platform_authority, system_program, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        // Mint must be an SPL token mint
+        if *mint.owner() != TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self { mint_fee_config, mint, platform_authority, system_program, platform_config })
+    }
+}
+
+/// Instruction data for SetMintFee
+/// Layout: [fee_bps: u16, min_fee: u64 (optional, absent = unchanged)]
+pub struct SetMintFeeData {
+    pub fee_bps: u16,
+    pub min_fee: Option<u64>,
+}
+
+impl SetMintFeeData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 2 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let fee_bps = u16::from_le_bytes(data[0..2].try_into().unwrap());
+        require!(fee_bps <= MAX_PLATFORM_FEE_BPS, EscrowError::InvalidFeeBps);
+        let min_fee = data
+            .get(2..10)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()));
+        Ok(Self { fee_bps, min_fee })
+    }
+}
+
+/// Process set_mint_fee instruction
+///
+/// Creates the mint's fee config on first use, otherwise updates it.
+pub fn process_set_mint_fee(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetMintFeeAccounts::try_from(accounts)?;
+    let args = SetMintFeeData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    let (expected_pda, bump) = find_mint_fee_config(ctx.mint.key(), program_id);
+    require!(ctx.mint_fee_config.key() == &expected_pda, EscrowError::InvalidPda);
+
+    if *ctx.mint_fee_config.owner() != ID {
+        let rent = Rent::get()?;
+        let rent_lamports = rent.minimum_balance(MintFeeConfig::SPACE);
+
+        let bump_ref = &[bump];
+        let signer_seeds = seeds!(MINT_FEE_SEED, ctx.mint.key(), bump_ref);
+        let signer = Signer::from(&signer_seeds);
+
+        CreateAccount {
+            from: ctx.platform_authority,
+            to: ctx.mint_fee_config,
+            lamports: rent_lamports,
+            space: MintFeeConfig::SPACE as u64,
+            owner: program_id,
+        }
+        .invoke_signed(&[signer])?;
+
+        let config_data = &mut ctx.mint_fee_config.try_borrow_mut_data()?;
+        let config = MintFeeConfig::init(config_data)?;
+        config.mint = *ctx.mint.key();
+        config.bump = bump;
+    }
+
+    let config_data = &mut ctx.mint_fee_config.try_borrow_mut_data()?;
+    let config = MintFeeConfig::load_mut(config_data)?;
+    config.fee_bps = args.fee_bps;
+    if let Some(min_fee) = args.min_fee {
+        config.min_fee = min_fee;
+    }
+    config.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+// ============== INIT PLATFORM CONFIG ==============
+
+/// Create the PlatformConfig PDA with the default fee, paid for by `payer`
+fn create_platform_config(
+    platform_config: &AccountInfo,
+    payer: &AccountInfo,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let (expected_pda, bump) = find_platform_config(program_id);
+    require!(platform_config.key() == &expected_pda, EscrowError::InvalidPda);
+
+    let rent = Rent::get()?;
+    let rent_lamports = rent.minimum_balance(PlatformConfig::SPACE);
+
+    let bump_ref = &[bump];
+    let signer_seeds = seeds!(PLATFORM_CONFIG_SEED, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    CreateAccount {
+        from: payer,
+        to: platform_config,
+        lamports: rent_lamports,
+        space: PlatformConfig::SPACE as u64,
+        owner: program_id,
+    }
+    .invoke_signed(&[signer])?;
+
+    let config_data = &mut platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::init(config_data)?;
+    config.bump = bump;
+    config.fee_bps = DEFAULT_PLATFORM_FEE_BPS;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+/// Process init_platform_config instruction
+///
+/// Creates the platform config; fails if it already exists. Only
+/// PLATFORM_WALLET can call it, since no other authority exists yet.
+/// Accounts: as set_platform_config
+/// Data: [fee_bps: u16 (optional, absent = DEFAULT_PLATFORM_FEE_BPS)]
+pub fn process_init_platform_config(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetPlatformConfigAccounts::try_from(accounts)?;
+    let fee_bps = data
+        .get(0..2)
+        .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
+
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+    require!(*ctx.platform_config.owner() != ID, EscrowError::AccountAlreadyInitialized);
+
+    create_platform_config(ctx.platform_config, ctx.platform_authority, program_id)?;
+
+    if let Some(fee_bps) = fee_bps {
+        let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+        PlatformConfig::load_mut(config_data)?.set_fee_bps(fee_bps)?;
+    }
+
+    Ok(())
+}
+
+// ============== SET PLATFORM CONFIG ==============
+
+/// Set platform config accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable) - created on first use
+/// 1. platform_authority (signer, writable) - the platform authority (PLATFORM_WALLET
+///    until the config exists), pays rent
+/// 2. system_program
+pub struct SetPlatformConfigAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetPlatformConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, platform_authority, system_program, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, platform_authority, system_program })
+    }
+}
+
+/// Instruction data for SetPlatformConfig
+/// Layout: [crank_bounty_bps: u16 (optional, absent or u16::MAX = unchanged),
+///          retiring: u8 (optional, absent = unchanged),
+///          min_early_votes: u8 (optional, absent = unchanged),
+///          reputation_tie_break: u8 (optional, absent = unchanged),
+///          fee_bps: u16 (optional, absent = unchanged),
+///          abandoned_dispute_seconds: i64 (optional, absent = unchanged, 0 = default),
+///          referral_bps: u16 (optional, absent = unchanged),
+///          no_quorum_resolution: u8 (optional, absent = unchanged; PosterWins or Split)]
+pub struct SetPlatformConfigData {
+    pub crank_bounty_bps: Option<u16>,
+    pub retiring: Option<bool>,
+    pub min_early_votes: Option<u8>,
+    pub reputation_tie_break: Option<bool>,
+    pub fee_bps: Option<u16>,
+    pub abandoned_dispute_seconds: Option<i64>,
+    pub referral_bps: Option<u16>,
+    pub no_quorum_resolution: Option<u8>,
+}
+
+impl SetPlatformConfigData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let crank_bounty_bps = data
+            .get(0..2)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()))
+            .filter(|&bps| bps != u16::MAX);
+        if let Some(bps) = crank_bounty_bps {
+            require!(bps <= MAX_CRANK_BOUNTY_BPS, EscrowError::InvalidFeeBps);
+        }
+        let retiring = data.get(2).map(|&flag| flag != 0);
+        let min_early_votes = data.get(3).copied();
+        if let Some(votes) = min_early_votes {
+            require!(votes as usize <= MAX_PANEL_SIZE, ProgramError::InvalidInstructionData);
+        }
+        let reputation_tie_break = data.get(4).map(|&flag| flag != 0);
+        let fee_bps = data
+            .get(5..7)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
+        let abandoned_dispute_seconds = data
+            .get(7..15)
+            .map(|bytes| i64::from_le_bytes(bytes.try_into().unwrap()));
+        let referral_bps = data
+            .get(15..17)
+            .map(|bytes| u16::from_le_bytes(bytes.try_into().unwrap()));
+        let no_quorum_resolution = data.get(17).copied();
+        Ok(Self {
+            crank_bounty_bps,
+            retiring,
+            min_early_votes,
+            reputation_tie_break,
+            fee_bps,
+            abandoned_dispute_seconds,
+            referral_bps,
+            no_quorum_resolution,
+        })
+    }
+}
+
+/// Process set_platform_config instruction
+///
+/// Creates the platform config on first use, otherwise updates it.
+pub fn process_set_platform_config(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetPlatformConfigAccounts::try_from(accounts)?;
+    let args = SetPlatformConfigData::try_from_slice(data)?;
+    let clock = Clock::get()?;
+
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    if *ctx.platform_config.owner() != ID {
+        create_platform_config(ctx.platform_config, ctx.platform_authority, program_id)?;
+    }
+
+    let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::load_mut(config_data)?;
+    if let Some(crank_bounty_bps) = args.crank_bounty_bps {
+        config.crank_bounty_bps = crank_bounty_bps;
+    }
+    if let Some(retiring) = args.retiring {
+        config.retiring = retiring as u8;
+    }
+    if let Some(min_early_votes) = args.min_early_votes {
+        config.min_early_votes = min_early_votes;
+    }
+    if let Some(reputation_tie_break) = args.reputation_tie_break {
+        config.reputation_tie_break = reputation_tie_break as u8;
+    }
+    if let Some(fee_bps) = args.fee_bps {
+        config.set_fee_bps(fee_bps)?;
+    }
+    if let Some(seconds) = args.abandoned_dispute_seconds {
+        config.set_abandoned_dispute_seconds(seconds)?;
+    }
+    if let Some(referral_bps) = args.referral_bps {
+        config.set_referral_bps(referral_bps)?;
+    }
+    if let Some(resolution) = args.no_quorum_resolution {
+        config.set_no_quorum_resolution(resolution)?;
+    }
+    config.updated_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+// ============== SET FEE BPS ==============
+
+/// Set fee bps accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable) - must already exist
+/// 1. platform_authority (signer) - the platform authority
+pub struct SetFeeBpsAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetFeeBpsAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, platform_authority, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[platform_config])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, platform_authority })
+    }
+}
+
+/// Process set_fee_bps instruction
+///
+/// Sets the platform fee on SOL releases, at most MAX_PLATFORM_FEE_BPS.
+/// Data: [fee_bps: u16]
+pub fn process_set_fee_bps(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetFeeBpsAccounts::try_from(accounts)?;
+    let fee_bps = u16::from_le_bytes(
+        data.get(0..2)
+            .ok_or(ProgramError::InvalidInstructionData)?
+            .try_into()
+            .unwrap(),
+    );
+
+    check_platform_config(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::load_mut(config_data)?;
+    config.set_fee_bps(fee_bps)?;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+// ============== SET PAUSED ==============
+
+/// Set paused accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable) - must already exist
+/// 1. platform_authority (signer) - the platform authority
+pub struct SetPausedAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetPausedAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, platform_authority, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[platform_config])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, platform_authority })
+    }
+}
+
+/// Process set_paused instruction
+///
+/// Emergency switch: while paused, escrows cannot be created or released.
+/// Refunds, disputes and arbitration are unaffected.
+/// Data: [paused: u8 (0 = resume)]
+pub fn process_set_paused(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetPausedAccounts::try_from(accounts)?;
+    let paused = *data.first().ok_or(ProgramError::InvalidInstructionData)? != 0;
+
+    check_platform_config(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::load_mut(config_data)?;
+    config.paused = paused as u8;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+// ============== CLOSE PLATFORM CONFIG ==============
+
+/// Close platform config accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable)
+/// 1. platform_authority (signer, writable) - the platform authority, receives the rent
+pub struct ClosePlatformConfigAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClosePlatformConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, platform_authority, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[platform_config, platform_authority])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, platform_authority })
+    }
+}
+
+/// Process close_platform_config instruction
+///
+/// Returns the config's rent to the platform when the program is retired.
+/// The config must have been marked retiring through set_platform_config
+/// first. It cannot be closed while paused, since a missing config pauses
+/// nothing, nor once the authority has been rotated away from
+/// PLATFORM_WALLET, since closing it would hand control back to that wallet.
+pub fn process_close_platform_config(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ClosePlatformConfigAccounts::try_from(accounts)?;
+
+    check_platform_config(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    {
+        let config_data = ctx.platform_config.try_borrow_data()?;
+        PlatformConfig::load(&config_data)?.require_closable()?;
+    }
+
+    close_account(ctx.platform_config, ctx.platform_authority)
+}
+
+// ============== SET AUTHORITY ==============
+
+/// Set authority accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable) - must already exist
+/// 1. platform_authority (signer) - the current platform authority
+pub struct SetAuthorityAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, platform_authority, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[platform_config])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, platform_authority })
+    }
+}
+
+/// Process set_authority instruction
+///
+/// First step of rotating the platform authority: records the successor,
+/// which only takes over once it signs accept_authority, so a mistyped key
+/// cannot lock the platform out. The zero key withdraws the proposal.
+/// Data: [new_authority: Pubkey]
+pub fn process_set_authority(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetAuthorityAccounts::try_from(accounts)?;
+    let new_authority: Pubkey = data
+        .get(0..32)
+        .ok_or(ProgramError::InvalidInstructionData)?
+        .try_into()
+        .unwrap();
+
+    check_platform_config(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::load_mut(config_data)?;
+    config.propose_authority(&new_authority);
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+// ============== ACCEPT AUTHORITY ==============
+
+/// Accept authority accounts
+/// Accounts:
+/// 0. platform_config (PDA, writable)
+/// 1. new_authority (signer) - the authority proposed by set_authority
+pub struct AcceptAuthorityAccounts<'a> {
+    pub platform_config: &'a AccountInfo,
+    pub new_authority: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AcceptAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_config, new_authority, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[platform_config])?;
+
+        if !new_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { platform_config, new_authority })
+    }
+}
+
+/// Process accept_authority instruction
+///
+/// Second step of rotating the platform authority: the proposed key takes
+/// over every platform-gated instruction.
+pub fn process_accept_authority(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = AcceptAuthorityAccounts::try_from(accounts)?;
+
+    check_platform_config(ctx.platform_config, program_id)?;
+
+    let config_data = &mut ctx.platform_config.try_borrow_mut_data()?;
+    let config = PlatformConfig::load_mut(config_data)?;
+    config.accept_authority(ctx.new_authority.key())?;
+    config.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PLATFORM_WALLET;
+
+    #[test]
+    fn test_platform_config_update_keeps_unsent_crank_bounty() {
+        let args = SetPlatformConfigData::try_from_slice(&[]).unwrap();
+        assert_eq!(args.crank_bounty_bps, None);
+        assert_eq!(args.fee_bps, None);
+
+        // u16::MAX leaves the bounty alone while later fields are set
+        let mut data = [0u8; 7];
+        data[0..2].copy_from_slice(&u16::MAX.to_le_bytes());
+        data[5..7].copy_from_slice(&250u16.to_le_bytes());
+        let args = SetPlatformConfigData::try_from_slice(&data).unwrap();
+        assert_eq!(args.crank_bounty_bps, None);
+        assert_eq!(args.fee_bps, Some(250));
+
+        data[0..2].copy_from_slice(&MAX_CRANK_BOUNTY_BPS.to_le_bytes());
+        let args = SetPlatformConfigData::try_from_slice(&data).unwrap();
+        assert_eq!(args.crank_bounty_bps, Some(MAX_CRANK_BOUNTY_BPS));
+
+        data[0..2].copy_from_slice(&(MAX_CRANK_BOUNTY_BPS + 1).to_le_bytes());
+        assert!(SetPlatformConfigData::try_from_slice(&data).is_err());
+    }
+
+    #[test]
+    fn test_platform_authority_check_follows_rotation() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        let outsider = [7u8; 32];
+
+        assert!(check_platform_authority(&PLATFORM_WALLET, &config.authority()).is_ok());
+        assert_eq!(
+            check_platform_authority(&outsider, &config.authority()),
+            Err(ProgramError::from(EscrowError::NotPlatformAuthority))
+        );
+
+        // After a rotation the old wallet is an outsider too
+        let new_authority = [8u8; 32];
+        config.propose_authority(&new_authority);
+        config.accept_authority(&new_authority).unwrap();
+        assert!(check_platform_authority(&new_authority, &config.authority()).is_ok());
+        assert_eq!(
+            check_platform_authority(&PLATFORM_WALLET, &config.authority()),
+            Err(ProgramError::from(EscrowError::NotPlatformAuthority))
+        );
+    }
+}
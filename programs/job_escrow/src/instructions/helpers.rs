@@ -0,0 +1,667 @@
+//! Shared helpers for instruction handlers
+//!
+//! Token program constants, SPL token account parsing, the platform fee vault,
+//! account closing, rent-safe escrow payouts, worker deposit settlement, fee
+//! splits, referral payouts, payout invariants, and job_id hashing.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    errors::EscrowError,
+    state::{mark_closed, AgentReputation, DisputeResolution, JobEscrow, BPS_DENOMINATOR, MAX_PLATFORM_FEE_BPS},
+    require, require_some,
+    ID,
+};
+
+/// SPL Token program ID: TokenkegQEcLiukSpvdP3kMR6CYjQLTdM9TBgmYABBmL
+pub const TOKEN_PROGRAM_ID: Pubkey = [
+    0x06, 0xdd, 0xf6, 0xe1, 0xd7, 0x65, 0xa1, 0x93,
+    0xd9, 0xcb, 0xe1, 0x46, 0xce, 0xeb, 0x79, 0xac,
+    0x1c, 0xb4, 0x85, 0xed, 0x5f, 0x5b, 0x37, 0x91,
+    0x3a, 0x8c, 0xf5, 0x85, 0x7e, 0xff, 0x00, 0xa9,
+];
+
+/// Associated Token Account program ID: ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL
+pub const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey = [
+    0x8c, 0x97, 0x25, 0x8f, 0x4e, 0x24, 0x89, 0xf1,
+    0xbb, 0x3d, 0x10, 0x29, 0x14, 0x8e, 0x0d, 0x83,
+    0x0b, 0x5a, 0x13, 0x99, 0xda, 0xff, 0x10, 0x84,
+    0x04, 0x8e, 0x7b, 0xd8, 0xdb, 0xe9, 0xf8, 0x59,
+];
+
+/// Seed for the platform vault PDA, which owns program-controlled fee token accounts
+pub const PLATFORM_VAULT_SEED: &[u8] = b"platform_vault";
+
+/// Derive the platform vault PDA
+#[inline(always)]
+pub fn find_platform_vault(program_id: &Pubkey) -> (Pubkey, u8) {
+    find_program_address(&[PLATFORM_VAULT_SEED], program_id)
+}
+
+/// The fields of an SPL token account this program cares about
+///
+/// Layout (165 bytes): mint [0..32], owner [32..64], amount [64..72], ..., state [108]
+pub struct TokenAccountInfo {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub state: u8,
+}
+
+impl TokenAccountInfo {
+    /// Size of an SPL token account
+    pub const LEN: usize = 165;
+
+    /// Account state: initialized
+    pub const STATE_INITIALIZED: u8 = 1;
+
+    /// Parse token account data
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        Ok(Self {
+            mint: data[0..32].try_into().unwrap(),
+            owner: data[32..64].try_into().unwrap(),
+            amount: u64::from_le_bytes(data[64..72].try_into().unwrap()),
+            state: data[108],
+        })
+    }
+
+    /// Load from an account, verifying it is an initialized token program account
+    pub fn from_account(account: &AccountInfo) -> Result<Self, ProgramError> {
+        if *account.owner() != TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        let data = account.try_borrow_data()?;
+        let info = Self::unpack(&data)?;
+        if info.state != Self::STATE_INITIALIZED {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        Ok(info)
+    }
+}
+
+/// Initialized token account data holding `amount` of `mint` for `owner`
+#[cfg(test)]
+pub(crate) fn token_account_bytes(mint: Pubkey, owner: Pubkey, amount: u64) -> [u8; TokenAccountInfo::LEN] {
+    let mut data = [0u8; TokenAccountInfo::LEN];
+    data[0..32].copy_from_slice(&mint);
+    data[32..64].copy_from_slice(&owner);
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    data[108] = TokenAccountInfo::STATE_INITIALIZED;
+    data
+}
+
+/// Apply an SPL token transfer of `amount` between two token account buffers
+///
+/// Stands in for the token program CPI, which cannot run on the host.
+#[cfg(test)]
+pub(crate) fn transfer_token_bytes(from: &mut [u8], to: &mut [u8], amount: u64) {
+    let from_amount = TokenAccountInfo::unpack(from).unwrap().amount;
+    let to_amount = TokenAccountInfo::unpack(to).unwrap().amount;
+    from[64..72].copy_from_slice(&(from_amount - amount).to_le_bytes());
+    to[64..72].copy_from_slice(&(to_amount + amount).to_le_bytes());
+}
+
+/// Fail with AccountNotWritable unless every account is writable
+///
+/// Called from `TryFrom` for accounts whose lamports or data the handler
+/// changes, so a client mistake surfaces as a clear error instead of a
+/// runtime failure on the first mutation.
+#[inline(always)]
+pub fn require_writable(accounts: &[&AccountInfo]) -> ProgramResult {
+    for account in accounts {
+        require!(account.is_writable(), EscrowError::AccountNotWritable);
+    }
+    Ok(())
+}
+
+/// Check that a token account may receive platform fees
+///
+/// Fees go either to an account owned by the platform authority (e.g. its
+/// ATA) or to one owned by the platform vault PDA for later batched withdrawal.
+#[inline(always)]
+pub fn is_platform_fee_owner(owner: &Pubkey, authority: &Pubkey, vault: &Pubkey) -> bool {
+    owner == authority || owner == vault
+}
+
+/// Transfer all lamports and close account
+#[inline(always)]
+pub fn close_account(account: &AccountInfo, recipient: &AccountInfo) -> ProgramResult {
+    let lamports = *account.try_borrow_lamports()?;
+    *account.try_borrow_mut_lamports()? = 0;
+    *recipient.try_borrow_mut_lamports()? += lamports;
+    
+    // Zero out data and write the closed sentinel
+    let mut data = account.try_borrow_mut_data()?;
+    mark_closed(&mut data)
+}
+
+/// Whether paying `amount` out of `balance` leaves at least `rent_minimum`
+#[inline(always)]
+pub fn payout_keeps_rent_exempt(balance: u64, amount: u64, rent_minimum: u64) -> bool {
+    balance.checked_sub(amount).is_some_and(|left| left >= rent_minimum)
+}
+
+/// Pay `amount` lamports from an escrow account to `recipient`
+///
+/// Fails with InsufficientFunds rather than dip into the escrow's
+/// rent-exempt reserve, so a miscomputed payout cannot get the account
+/// purged with funds still in flight. Only close_account takes the reserve.
+pub fn safe_pay_from_escrow(escrow: &AccountInfo, recipient: &AccountInfo, amount: u64) -> ProgramResult {
+    if amount == 0 {
+        return Ok(());
+    }
+    let rent_minimum = Rent::get()?.minimum_balance(escrow.data_len());
+    require!(
+        payout_keeps_rent_exempt(escrow.lamports(), amount, rent_minimum),
+        EscrowError::InsufficientFunds
+    );
+    *escrow.try_borrow_mut_lamports()? -= amount;
+    *recipient.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Pay out a posted worker deposit and the assignment bond (if any) from the
+/// escrow to `recipient`
+///
+/// The worker gets them back when they are paid; they go to the poster when
+/// the poster is refunded. Clearing the flags makes a second settlement a no-op.
+pub fn settle_worker_deposit(
+    escrow: &mut JobEscrow,
+    escrow_account: &AccountInfo,
+    recipient: &AccountInfo,
+) -> ProgramResult {
+    let deposit = escrow
+        .take_worker_deposit()
+        .checked_add(escrow.take_worker_bond())
+        .ok_or(EscrowError::ArithmeticOverflow)?;
+    safe_pay_from_escrow(escrow_account, recipient, deposit)
+}
+
+/// Split `amount` into (recipient share, platform fee) at `fee_bps`
+///
+/// The fee rounds down, so any remainder goes to the recipient.
+pub fn split_fee(amount: u64, fee_bps: u16) -> Result<(u64, u64), ProgramError> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(EscrowError::ArithmeticOverflow)?
+        / BPS_DENOMINATOR as u128;
+    let fee = u64::try_from(fee).map_err(|_| EscrowError::ArithmeticOverflow)?;
+    let remainder = amount.checked_sub(fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    Ok((remainder, fee))
+}
+
+/// Split a token release like split_fee, raising the fee to at least `min_fee`
+///
+/// The floor keeps small releases of high-value tokens from paying no fee,
+/// but never takes more than MAX_PLATFORM_FEE_BPS of the amount.
+pub fn split_token_fee(amount: u64, fee_bps: u16, min_fee: u64) -> Result<(u64, u64), ProgramError> {
+    let (remainder, fee) = split_fee(amount, fee_bps)?;
+    if fee >= min_fee {
+        return Ok((remainder, fee));
+    }
+    let (_, cap) = split_fee(amount, MAX_PLATFORM_FEE_BPS)?;
+    let fee = min_fee.min(cap).max(fee);
+    let remainder = amount.checked_sub(fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    Ok((remainder, fee))
+}
+
+/// Referrer accounts passed as optional trailing accounts to SOL releases
+/// Accounts:
+/// 0. referrer (writable) - must match the escrow's referrer
+/// 1. referrer_reputation (optional, writable) - PDA ["reputation", referrer];
+///    credited with the referral if the referrer has one
+pub struct ReferralAccounts<'a> {
+    pub referrer: &'a AccountInfo,
+    pub referrer_reputation: Option<&'a AccountInfo>,
+}
+
+impl<'a> ReferralAccounts<'a> {
+    /// Parse from the accounts following an instruction's fixed accounts
+    pub fn from_rest(rest: &'a [AccountInfo]) -> Result<Option<Self>, ProgramError> {
+        let Some((referrer, rest)) = rest.split_first() else {
+            return Ok(None);
+        };
+        require_writable(&[referrer])?;
+        let referrer_reputation = rest.first();
+        if let Some(reputation) = referrer_reputation {
+            require_writable(&[reputation])?;
+        }
+        Ok(Some(Self { referrer, referrer_reputation }))
+    }
+}
+
+/// Referrer's share of a release's `platform_fee` at `referral_bps`
+///
+/// Nothing is owed without a referrer or a configured share. Otherwise the
+/// referrer account must be passed, so a release cannot skip the payout.
+pub fn referral_share(
+    escrow: &JobEscrow,
+    referral: Option<&ReferralAccounts>,
+    platform_fee: u64,
+    referral_bps: u16,
+) -> Result<u64, ProgramError> {
+    if !escrow.has_referrer() || referral_bps == 0 {
+        return Ok(0);
+    }
+    let referral = require_some!(referral, EscrowError::InvalidReferrer);
+    require!(referral.referrer.key() == &escrow.referrer, EscrowError::InvalidReferrer);
+    let (_, share) = split_fee(platform_fee, referral_bps)?;
+    Ok(share)
+}
+
+/// Pay a referral share from the escrow and record it on the referrer's
+/// reputation account, if passed
+pub fn pay_referral(
+    escrow_account: &AccountInfo,
+    referral: Option<&ReferralAccounts>,
+    amount: u64,
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let Some(referral) = referral else {
+        return Ok(());
+    };
+    if amount == 0 {
+        return Ok(());
+    }
+    safe_pay_from_escrow(escrow_account, referral.referrer, amount)?;
+
+    if let Some(reputation) = referral.referrer_reputation {
+        // SECURITY FIX C-01: Verify reputation account is owned by this program
+        if *reputation.owner() != ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        // SECURITY FIX C-03: Verify referrer reputation PDA derivation
+        let (expected_rep, _) = find_program_address(&[b"reputation", referral.referrer.key()], program_id);
+        require!(reputation.key() == &expected_rep, EscrowError::InvalidPda);
+
+        let rep_data = &mut reputation.try_borrow_mut_data()?;
+        let rep = AgentReputation::load_mut(rep_data)?;
+        // SECURITY FIX H-05: Use checked arithmetic
+        rep.referral_earned = rep.referral_earned.saturating_add(amount);
+    }
+    Ok(())
+}
+
+/// SHA-256 of `data` (syscall on-chain, sha2 crate off-chain)
+pub fn sha256(data: &[u8]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = [0u8; 32];
+        let vals: &[&[u8]] = &[data];
+        unsafe {
+            pinocchio::syscalls::sol_sha256(
+                vals.as_ptr() as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr(),
+            );
+        }
+        hash
+    }
+    #[cfg(not(target_os = "solana"))]
+    {
+        use sha2::{Digest, Sha256};
+        Sha256::digest(data).into()
+    }
+}
+
+/// Check that `job_id` is the preimage of an escrow's job_id_hash
+pub fn verify_job_id(job_id: &[u8], job_id_hash: &[u8; 32]) -> ProgramResult {
+    require!(!job_id.is_empty(), EscrowError::JobIdMismatch);
+    require!(&sha256(job_id) == job_id_hash, EscrowError::JobIdMismatch);
+    Ok(())
+}
+
+/// How an escrowed amount is divided once a dispute is resolved
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisputePayout {
+    pub worker: u64,
+    pub poster: u64,
+    pub platform: u64,
+}
+
+impl DisputePayout {
+    /// Payout for a resolution: WorkerWins pays the worker less the 1% fee,
+    /// PosterWins refunds the poster in full (no fee), and Split takes the 1%
+    /// fee and halves the rest, giving any odd unit to the poster.
+    pub fn for_resolution(resolution: DisputeResolution, amount: u64) -> Result<Self, ProgramError> {
+        let payout = match resolution {
+            DisputeResolution::WorkerWins => {
+                // SECURITY FIX H-05: Use checked arithmetic
+                let platform = amount.checked_div(100).unwrap_or(0);
+                let worker = amount.checked_sub(platform).ok_or(EscrowError::ArithmeticOverflow)?;
+                Self { worker, poster: 0, platform }
+            }
+            DisputeResolution::PosterWins => Self { worker: 0, poster: amount, platform: 0 },
+            DisputeResolution::Split => {
+                // SECURITY FIX H-05: Use checked arithmetic
+                let platform = amount.checked_div(100).unwrap_or(0);
+                let remaining = amount.checked_sub(platform).ok_or(EscrowError::ArithmeticOverflow)?;
+                let worker = remaining.checked_div(2).unwrap_or(0);
+                let poster = remaining.checked_sub(worker).ok_or(EscrowError::ArithmeticOverflow)?;
+                Self { worker, poster, platform }
+            }
+            DisputeResolution::Pending => return Err(EscrowError::DisputeNotResolved.into()),
+        };
+        verify_payout(amount, &[payout.worker, payout.poster, payout.platform])?;
+        Ok(payout)
+    }
+
+    /// Payout when only `disputed` of the escrow's `amount` was contested
+    ///
+    /// The undisputed rest is released to the worker as if the worker had won
+    /// it, and the resolution applies to the disputed part alone.
+    pub fn for_partial_dispute(
+        resolution: DisputeResolution,
+        amount: u64,
+        disputed: u64,
+    ) -> Result<Self, ProgramError> {
+        let disputed = disputed.min(amount);
+        let undisputed = Self::for_resolution(DisputeResolution::WorkerWins, amount - disputed)?;
+        let arbitrated = Self::for_resolution(resolution, disputed)?;
+        let payout = Self {
+            worker: undisputed.worker + arbitrated.worker,
+            poster: undisputed.poster + arbitrated.poster,
+            platform: undisputed.platform + arbitrated.platform,
+        };
+        verify_payout(amount, &[payout.worker, payout.poster, payout.platform])?;
+        Ok(payout)
+    }
+
+    /// Payout when a stuck arbitration expires before any resolution
+    ///
+    /// The undisputed rest goes to the worker as in for_partial_dispute and
+    /// only the disputed part is refunded to the poster. No platform fee is
+    /// taken on an emergency release.
+    pub fn for_expired_arbitration(amount: u64, disputed: u64) -> Result<Self, ProgramError> {
+        let poster = disputed.min(amount);
+        let payout = Self { worker: amount - poster, poster, platform: 0 };
+        verify_payout(amount, &[payout.worker, payout.poster, payout.platform])?;
+        Ok(payout)
+    }
+
+    /// Payout when both parties agree to cancel with the worker keeping
+    /// `worker_bps` of the escrow for partial work
+    ///
+    /// Like a Split resolution, the platform fee comes off the top and the
+    /// rest is divided, with any rounding remainder going to the poster.
+    pub fn for_mutual_cancel(amount: u64, worker_bps: u16, fee_bps: u16) -> Result<Self, ProgramError> {
+        require!(worker_bps as u64 <= BPS_DENOMINATOR, EscrowError::InvalidWorkerShare);
+        let (remaining, platform) = split_fee(amount, fee_bps)?;
+        let worker = (remaining as u128 * worker_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+        let poster = remaining.checked_sub(worker).ok_or(EscrowError::ArithmeticOverflow)?;
+        let payout = Self { worker, poster, platform };
+        verify_payout(amount, &[payout.worker, payout.poster, payout.platform])?;
+        Ok(payout)
+    }
+}
+
+/// Funds a refund returns to the poster
+///
+/// SOL escrows hold the amount in the escrow account's own lamports; token
+/// escrows hold it in the escrow token account, and their lamports are only
+/// rent, which stays with the escrow until it is closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefundFunds {
+    Lamports(u64),
+    Tokens(u64),
+}
+
+impl RefundFunds {
+    /// Refund of everything the escrow still holds
+    pub fn for_escrow(escrow: &JobEscrow) -> Self {
+        let amount = escrow.remaining_amount();
+        if escrow.is_token_escrow() {
+            Self::Tokens(amount)
+        } else {
+            Self::Lamports(amount)
+        }
+    }
+}
+
+/// Post-condition for payouts: the parts must sum exactly to the total
+///
+/// Every release/refund/dispute branch calls this with the amounts it is
+/// about to transfer, so a rounding or refactoring mistake can never pay out
+/// more (or silently strand less) than the escrowed amount.
+pub fn verify_payout(total: u64, parts: &[u64]) -> ProgramResult {
+    let mut sum: u64 = 0;
+    for part in parts {
+        sum = sum.checked_add(*part).ok_or(EscrowError::ArithmeticOverflow)?;
+    }
+    require!(sum == total, EscrowError::PayoutMismatch);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PLATFORM_WALLET;
+
+    #[test]
+    fn test_payout_cannot_drain_rent_reserve() {
+        let rent_minimum = 2_000_000;
+        let balance = rent_minimum + 50_000_000;
+        assert!(payout_keeps_rent_exempt(balance, 50_000_000, rent_minimum));
+        assert!(payout_keeps_rent_exempt(balance, 0, rent_minimum));
+        // One lamport too many dips into the reserve
+        assert!(!payout_keeps_rent_exempt(balance, 50_000_001, rent_minimum));
+        // More than the whole balance
+        assert!(!payout_keeps_rent_exempt(balance, u64::MAX, rent_minimum));
+    }
+
+    #[test]
+    fn test_unpack_token_account() {
+        let data = token_account_bytes([1u8; 32], [2u8; 32], 42);
+        let info = TokenAccountInfo::unpack(&data).unwrap();
+        assert_eq!(info.mint, [1u8; 32]);
+        assert_eq!(info.owner, [2u8; 32]);
+        assert_eq!(info.amount, 42);
+        assert_eq!(info.state, TokenAccountInfo::STATE_INITIALIZED);
+        assert!(TokenAccountInfo::unpack(&data[..100]).is_err());
+    }
+
+    #[test]
+    fn test_referral_share_requires_referrer_account() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+
+        // No referrer, or no share configured: nothing owed, no account needed
+        assert_eq!(referral_share(escrow, None, 1_000, 2_000), Ok(0));
+        escrow.referrer = [7u8; 32];
+        assert_eq!(referral_share(escrow, None, 1_000, 0), Ok(0));
+
+        // A referral is owed, so the release must pass the referrer
+        assert_eq!(
+            referral_share(escrow, None, 1_000, 2_000),
+            Err(ProgramError::from(EscrowError::InvalidReferrer))
+        );
+    }
+
+    #[test]
+    fn test_platform_fee_owner() {
+        let vault = [9u8; 32];
+        assert!(is_platform_fee_owner(&PLATFORM_WALLET, &PLATFORM_WALLET, &vault));
+        assert!(is_platform_fee_owner(&vault, &PLATFORM_WALLET, &vault));
+        assert!(!is_platform_fee_owner(&[3u8; 32], &PLATFORM_WALLET, &vault));
+
+        // After a rotation the old wallet no longer receives fees
+        let authority = [4u8; 32];
+        assert!(is_platform_fee_owner(&authority, &authority, &vault));
+        assert!(!is_platform_fee_owner(&PLATFORM_WALLET, &authority, &vault));
+    }
+
+    #[test]
+    fn test_split_fee() {
+        use crate::state::{MintFeeConfig, DEFAULT_PLATFORM_FEE_BPS};
+
+        // Default 1% matches the legacy amount / 100
+        assert_eq!(split_fee(1_000_000, DEFAULT_PLATFORM_FEE_BPS).unwrap(), (990_000, 10_000));
+        assert_eq!(split_fee(199, DEFAULT_PLATFORM_FEE_BPS).unwrap(), (198, 1));
+        assert_eq!(split_fee(u64::MAX, 10_000).unwrap(), (0, u64::MAX));
+
+        // A per-mint override replaces the default on release
+        let mut data = std::vec![0u8; MintFeeConfig::SPACE];
+        let config = MintFeeConfig::init(&mut data).unwrap();
+        config.fee_bps = 250;
+        let bps = MintFeeConfig::resolve_fee_bps(Some(config));
+        let (worker, fee) = split_fee(1_000_000, bps).unwrap();
+        assert_eq!((worker, fee), (975_000, 25_000));
+        assert!(verify_payout(1_000_000, &[worker, fee]).is_ok());
+
+        assert_eq!(MintFeeConfig::resolve_fee_bps(None), DEFAULT_PLATFORM_FEE_BPS);
+
+        // Crank bounty carved out of the platform fee
+        let (platform_share, bounty) = split_fee(fee, 2_000).unwrap();
+        assert_eq!((platform_share, bounty), (20_000, 5_000));
+        assert!(verify_payout(1_000_000, &[worker, platform_share, bounty]).is_ok());
+    }
+
+    #[test]
+    fn test_split_fee_at_configured_rates() {
+        use crate::state::MAX_PLATFORM_FEE_BPS;
+
+        assert_eq!(split_fee(1_000_000, 0).unwrap(), (1_000_000, 0));
+        assert_eq!(split_fee(1_000_000, 100).unwrap(), (990_000, 10_000));
+        assert_eq!(split_fee(1_000_000, MAX_PLATFORM_FEE_BPS).unwrap(), (900_000, 100_000));
+        for bps in [0, 100, MAX_PLATFORM_FEE_BPS] {
+            let (worker, fee) = split_fee(123_456_789, bps).unwrap();
+            assert!(verify_payout(123_456_789, &[worker, fee]).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_split_token_fee_floor() {
+        // Above the floor the percentage fee applies unchanged
+        assert_eq!(split_token_fee(1_000_000, 100, 500).unwrap(), (990_000, 10_000));
+
+        // Small release: 1% of 5_000 is 50, raised to the 200 floor
+        assert_eq!(split_token_fee(5_000, 100, 200).unwrap(), (4_800, 200));
+
+        // The floor never takes more than 10% of the amount
+        assert_eq!(split_token_fee(1_000, 100, 500).unwrap(), (900, 100));
+        assert_eq!(split_token_fee(5, 100, 500).unwrap(), (5, 0));
+
+        // No floor configured
+        assert_eq!(split_token_fee(50, 100, 0).unwrap(), (50, 0));
+        let (worker, fee) = split_token_fee(7_777, 100, 300).unwrap();
+        assert!(verify_payout(7_777, &[worker, fee]).is_ok());
+    }
+
+    #[test]
+    fn test_verify_job_id() {
+        // sha256("abc")
+        let abc: [u8; 32] = [
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22, 0x23,
+            0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00, 0x15, 0xad,
+        ];
+        assert_eq!(sha256(b"abc"), abc);
+
+        assert!(verify_job_id(b"abc", &abc).is_ok());
+        assert_eq!(
+            verify_job_id(b"abd", &abc),
+            Err(ProgramError::from(EscrowError::JobIdMismatch))
+        );
+        assert_eq!(
+            verify_job_id(b"", &abc),
+            Err(ProgramError::from(EscrowError::JobIdMismatch))
+        );
+    }
+
+    #[test]
+    fn test_dispute_payouts() {
+        assert_eq!(
+            DisputePayout::for_resolution(DisputeResolution::WorkerWins, 1_000_000).unwrap(),
+            DisputePayout { worker: 990_000, poster: 0, platform: 10_000 }
+        );
+        assert_eq!(
+            DisputePayout::for_resolution(DisputeResolution::PosterWins, 1_000_000).unwrap(),
+            DisputePayout { worker: 0, poster: 1_000_000, platform: 0 }
+        );
+        // 1% fee, then the odd unit of the remainder goes to the poster
+        assert_eq!(
+            DisputePayout::for_resolution(DisputeResolution::Split, 1_001).unwrap(),
+            DisputePayout { worker: 495, poster: 496, platform: 10 }
+        );
+        assert_eq!(
+            DisputePayout::for_resolution(DisputeResolution::Pending, 1_000),
+            Err(ProgramError::from(EscrowError::DisputeNotResolved))
+        );
+    }
+
+    #[test]
+    fn test_mutual_cancel_split() {
+        // 30/70 split after a 1% fee
+        let payout = DisputePayout::for_mutual_cancel(1_000_000, 3_000, 100).unwrap();
+        assert_eq!(payout, DisputePayout { worker: 297_000, poster: 693_000, platform: 10_000 });
+
+        // Either party may walk away with everything but the fee
+        let payout = DisputePayout::for_mutual_cancel(1_000_000, 0, 100).unwrap();
+        assert_eq!((payout.worker, payout.poster), (0, 990_000));
+        let payout = DisputePayout::for_mutual_cancel(1_000_000, 10_000, 100).unwrap();
+        assert_eq!((payout.worker, payout.poster), (990_000, 0));
+
+        assert_eq!(
+            DisputePayout::for_mutual_cancel(1_000_000, 10_001, 100),
+            Err(ProgramError::from(EscrowError::InvalidWorkerShare))
+        );
+    }
+
+    #[test]
+    fn test_partial_dispute_releases_undisputed_rest_to_worker() {
+        // Half of 2 SOL disputed, poster wins that half
+        assert_eq!(
+            DisputePayout::for_partial_dispute(DisputeResolution::PosterWins, 2_000_000, 1_000_000).unwrap(),
+            DisputePayout { worker: 990_000, poster: 1_000_000, platform: 10_000 }
+        );
+        // Disputing everything matches a full dispute
+        assert_eq!(
+            DisputePayout::for_partial_dispute(DisputeResolution::Split, 1_001, 1_001).unwrap(),
+            DisputePayout::for_resolution(DisputeResolution::Split, 1_001).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_expired_arbitration_refunds_only_disputed_part() {
+        assert_eq!(
+            DisputePayout::for_expired_arbitration(2_000_000, 500_000).unwrap(),
+            DisputePayout { worker: 1_500_000, poster: 500_000, platform: 0 }
+        );
+        // A dispute over everything is a full refund
+        assert_eq!(
+            DisputePayout::for_expired_arbitration(2_000_000, 2_000_000).unwrap(),
+            DisputePayout { worker: 0, poster: 2_000_000, platform: 0 }
+        );
+        // Capped by what is left after earlier releases
+        assert_eq!(
+            DisputePayout::for_expired_arbitration(1_000_000, 2_000_000).unwrap(),
+            DisputePayout { worker: 0, poster: 1_000_000, platform: 0 }
+        );
+    }
+
+    #[test]
+    fn test_verify_payout() {
+        assert!(verify_payout(1_000, &[990, 10]).is_ok());
+        assert!(verify_payout(1_001, &[496, 495, 10]).is_ok());
+        assert_eq!(
+            verify_payout(1_000, &[990, 11]),
+            Err(ProgramError::from(EscrowError::PayoutMismatch))
+        );
+        assert_eq!(
+            verify_payout(1_000, &[990]),
+            Err(ProgramError::from(EscrowError::PayoutMismatch))
+        );
+        assert_eq!(
+            verify_payout(u64::MAX, &[u64::MAX, 1]),
+            Err(ProgramError::from(EscrowError::ArithmeticOverflow))
+        );
+    }
+}
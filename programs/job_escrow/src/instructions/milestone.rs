@@ -0,0 +1,401 @@
+//! Milestone instructions
+//!
+//! A token escrow can be split into up to MAX_MILESTONES milestones whose
+//! amounts sum to the escrow amount. Each milestone is released on its own;
+//! the escrow is only marked `Released` once every milestone has been paid.
+//! - set_milestones (poster, before a worker is assigned)
+//! - release_token_milestone (platform only, SPL tokens)
+//!
+//! SOL escrows without a schedule can instead be paid out in arbitrary
+//! portions with release_milestone (platform only).
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    ProgramResult,
+};
+use pinocchio_token::instructions::Transfer as TokenTransfer;
+
+use crate::{
+    errors::EscrowError,
+    events::FundsReleased,
+    state::{JobEscrow, EscrowStatus, MAX_MILESTONES},
+    require,
+    ID,
+};
+
+use super::helpers::{
+    find_platform_vault, is_platform_fee_owner, require_writable, safe_pay_from_escrow, settle_worker_deposit,
+    split_fee, split_token_fee, verify_payout, TokenAccountInfo, TOKEN_PROGRAM_ID,
+};
+use super::fee_config::{
+    mint_fee, platform_authority, platform_fee_bps, require_not_paused, require_platform_authority,
+    require_platform_recipient,
+};
+
+// ============== SET MILESTONES (Poster) ==============
+
+/// Set milestones accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer)
+pub struct SetMilestonesAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetMilestonesAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, poster, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow])?;
+
+        if !poster.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, poster })
+    }
+}
+
+/// Instruction data for SetMilestones
+/// Layout: [count: u8, amounts: [u64; count]]
+pub struct SetMilestonesData {
+    pub amounts: [u64; MAX_MILESTONES],
+    pub count: usize,
+}
+
+impl SetMilestonesData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let (&count, rest) = data.split_first().ok_or(ProgramError::InvalidInstructionData)?;
+        let count = count as usize;
+        require!(count > 0 && count <= MAX_MILESTONES, EscrowError::InvalidMilestone);
+        if rest.len() < count * 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let mut amounts = [0u64; MAX_MILESTONES];
+        for (amount, bytes) in amounts.iter_mut().zip(rest.chunks_exact(8)).take(count) {
+            *amount = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Ok(Self { amounts, count })
+    }
+}
+
+/// Process set_milestones instruction
+///
+/// The schedule can be changed until a worker is assigned.
+pub fn process_set_milestones(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    _program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SetMilestonesAccounts::try_from(accounts)?;
+    let args = SetMilestonesData::try_from_slice(data)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+    require!(escrow.is_token_escrow(), EscrowError::NotTokenEscrow);
+    require!(escrow.is_active(), EscrowError::EscrowNotActive);
+    require!(!escrow.has_worker(), EscrowError::WorkerAlreadyAssigned);
+
+    escrow.set_milestones(&args.amounts[..args.count])
+}
+
+// ============== RELEASE TOKEN MILESTONE (Platform Only, SPL Tokens) ==============
+
+/// Release token milestone accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. platform_authority (signer) - the platform authority, per the platform config
+/// 2. worker (writable) - gets the worker bond back on the final milestone
+/// 3. escrow_token_account (writable) - escrow's token account
+/// 4. worker_token_account (writable) - worker's ATA
+/// 5. platform_token_account (writable) - platform's ATA, or a platform vault token account
+/// 6. token_program
+/// 7. platform_config (readonly) - PDA ["platform_config"]; release fails while paused
+/// 8. mint_fee_config (optional, readonly) - PDA ["mint_fee", mint]; overrides the default fee
+pub struct ReleaseTokenMilestoneAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub escrow_token_account: &'a AccountInfo,
+    pub worker_token_account: &'a AccountInfo,
+    pub platform_token_account: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub mint_fee_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseTokenMilestoneAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, platform_authority, worker, escrow_token_account, worker_token_account, platform_token_account, token_program, platform_config, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, worker, escrow_token_account, worker_token_account, platform_token_account])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if token_program.key() != &TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self {
+            escrow,
+            platform_authority,
+            worker,
+            escrow_token_account,
+            worker_token_account,
+            platform_token_account,
+            token_program,
+            platform_config,
+            mint_fee_config: rest.first(),
+        })
+    }
+}
+
+/// Instruction data for ReleaseTokenMilestone
+/// Layout: [milestone_index: u8]
+pub struct ReleaseTokenMilestoneData {
+    pub milestone_index: u8,
+}
+
+impl ReleaseTokenMilestoneData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let milestone_index = *data.first().ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(Self { milestone_index })
+    }
+}
+
+/// Process release_token_milestone instruction
+///
+/// Pays one milestone, net of the platform fee, from the escrow token account
+/// to the worker. The final milestone also returns the worker bond.
+pub fn process_release_token_milestone(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ReleaseTokenMilestoneAccounts::try_from(accounts)?;
+    let args = ReleaseTokenMilestoneData::try_from_slice(data)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(escrow.is_token_escrow(), EscrowError::NotTokenEscrow);
+    require!(escrow.has_milestones(), EscrowError::InvalidMilestone);
+    require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+    require!(ctx.escrow_token_account.key() == &escrow.escrow_token_account, EscrowError::TokenAccountMismatch);
+
+    // Worker destination must hold the escrow's mint and belong to the worker
+    let worker_token = TokenAccountInfo::from_account(ctx.worker_token_account)?;
+    require!(worker_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    require!(worker_token.owner == escrow.worker, EscrowError::TokenAccountMismatch);
+
+    // Fee destination must hold the escrow's mint and belong to the platform
+    // authority or the platform vault PDA
+    let platform_token = TokenAccountInfo::from_account(ctx.platform_token_account)?;
+    require!(platform_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    let authority = platform_authority(ctx.platform_config, program_id)?;
+    let (platform_vault, _) = find_platform_vault(program_id);
+    require!(
+        is_platform_fee_owner(&platform_token.owner, &authority, &platform_vault),
+        EscrowError::InvalidFeeRecipient
+    );
+
+    let amount = escrow.release_milestone(args.milestone_index)?;
+    let (fee_bps, min_fee) = mint_fee(ctx.mint_fee_config, &escrow.token_mint, program_id)?;
+    let (worker_payment, platform_fee) = split_token_fee(amount, fee_bps, min_fee)?;
+    verify_payout(amount, &[worker_payment, platform_fee])?;
+
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: escrow.status == EscrowStatus::Released as u8,
+    }
+    .emit();
+
+    let bump_ref = &[escrow.bump];
+    let signer_seeds = seeds!(b"escrow", &escrow.job_id_hash, &escrow.poster, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    TokenTransfer {
+        from: ctx.escrow_token_account,
+        to: ctx.worker_token_account,
+        authority: ctx.escrow,
+        amount: worker_payment,
+    }
+    .invoke_signed(core::slice::from_ref(&signer))?;
+
+    if platform_fee > 0 {
+        TokenTransfer {
+            from: ctx.escrow_token_account,
+            to: ctx.platform_token_account,
+            authority: ctx.escrow,
+            amount: platform_fee,
+        }
+        .invoke_signed(&[signer])?;
+    }
+
+    if escrow.status == EscrowStatus::Released as u8 {
+        settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
+    }
+
+    Ok(())
+}
+
+// ============== RELEASE MILESTONE (Platform Only, SOL) ==============
+
+/// Release milestone accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. platform_authority (signer) - the platform authority, per the platform config
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee
+pub struct ReleaseMilestoneAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub platform_authority: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseMilestoneAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, platform_authority, worker, platform, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, worker, platform])?;
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self {
+            escrow,
+            platform_authority,
+            worker,
+            platform,
+            platform_config,
+        })
+    }
+}
+
+/// Instruction data for ReleaseMilestone
+/// Layout: [release_amount: u64]
+pub struct ReleaseMilestoneData {
+    pub release_amount: u64,
+}
+
+impl ReleaseMilestoneData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        let bytes = data.get(..8).ok_or(ProgramError::InvalidInstructionData)?;
+        let release_amount = u64::from_le_bytes(bytes.try_into().unwrap());
+        Ok(Self { release_amount })
+    }
+}
+
+/// Process release_milestone instruction
+///
+/// Pays `release_amount` lamports, net of the platform fee, to the worker.
+/// The escrow stays `Active` until the whole amount has been released; the
+/// worker's deposit is returned with the final release.
+pub fn process_release_milestone(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = ReleaseMilestoneAccounts::try_from(accounts)?;
+    let args = ReleaseMilestoneData::try_from_slice(data)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(!escrow.is_token_escrow(), EscrowError::UnsupportedTokenEscrow);
+    require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
+    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+
+    escrow.release_partial(args.release_amount)?;
+    let (worker_payment, platform_fee) = split_fee(args.release_amount, fee_bps)?;
+    verify_payout(args.release_amount, &[worker_payment, platform_fee])?;
+
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount: args.release_amount,
+        worker_payment,
+        platform_fee,
+        fully_released: escrow.status == EscrowStatus::Released as u8,
+    }
+    .emit();
+
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, worker_payment)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, platform_fee)?;
+
+    if escrow.status == EscrowStatus::Released as u8 {
+        settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
+    }
+
+    Ok(())
+}
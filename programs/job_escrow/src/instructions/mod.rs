@@ -12,6 +12,11 @@ mod dispute;
 mod arbitrator;
 mod reputation;
 mod close;
+mod vault;
+mod view;
+mod fee_config;
+mod milestone;
+mod helpers;
 
 pub use create_escrow::*;
 pub use create_token_escrow::*;
@@ -22,6 +27,10 @@ pub use dispute::*;
 pub use arbitrator::*;
 pub use reputation::*;
 pub use close::*;
+pub use vault::*;
+pub use view::*;
+pub use fee_config::*;
+pub use milestone::*;
 
 /// Instruction discriminators (single byte for efficiency)
 #[repr(u8)]
@@ -80,4 +89,118 @@ pub enum Instruction {
     CreateTokenEscrow = 25,
     /// Release tokens to worker
     ReleaseTokensToWorker = 26,
+    /// Update the escrow categories an arbitrator will judge
+    SetArbitratorCategories = 27,
+    /// Withdraw accrued token fees from the platform vault
+    WithdrawPlatformVault = 28,
+    /// Assigned worker gives up the job before submitting work
+    AbandonAssignment = 29,
+    /// Move an arbitrator's stake and history to a new wallet
+    MigrateArbitrator = 30,
+    /// Platform-funded reputation account creation
+    SponsorReputation = 31,
+    /// Platform refund of a disputed escrow without the timelock
+    EmergencyRefund = 32,
+    /// One-time, platform-approved extension of a dispute's voting deadline
+    ExtendVoting = 33,
+    /// Selected arbitrator delegates their vote on a case
+    DelegateVote = 34,
+    /// Read-only arbitrator pool metrics
+    GetPoolHealth = 35,
+    /// Create an escrow where the worker must also lock a bond
+    CreateBilateralEscrow = 36,
+    /// Worker posts the bond for a bilateral escrow
+    PostWorkerDeposit = 37,
+    /// Read-only check of whether an arbitrator can vote on a case
+    EmitEligibility = 38,
+    /// Set the platform fee override for a token mint
+    SetMintFee = 39,
+    /// Create or update the platform config
+    SetPlatformConfig = 40,
+    /// Auto-release an expired review and pay the cranker a bounty
+    PokeReview = 41,
+    /// Refund a token escrow's tokens to the poster (platform only)
+    RefundTokensToPoster = 42,
+    /// Execute a dispute resolution for a token escrow
+    ExecuteTokenDisputeResolution = 43,
+    /// Verify the plaintext job_id and record it on the dispute case
+    RecordJobId = 44,
+    /// Poster reclaims the tokens of an expired token escrow
+    ClaimTokensExpired = 45,
+    /// Poster splits a token escrow into milestones
+    SetMilestones = 46,
+    /// Release a single token milestone to the worker (platform only)
+    ReleaseTokenMilestone = 47,
+    /// Bid on an open job, starting the poster's cancel grace
+    PlaceBid = 48,
+    /// Voting arbitrator claims their share of the vote fee pot
+    ClaimVoteFee = 49,
+    /// Read-only preview of the platform fee for an amount
+    QuoteFee = 50,
+    /// Read-only count of arbitrators that dispute selection could pick
+    EmitSelectableCount = 51,
+    /// Close the retiring platform config and reclaim its rent
+    ClosePlatformConfig = 52,
+    /// Record that a selected arbitrator never voted on a resolved case
+    PenalizeAbsentArbitrator = 53,
+    /// Assigned worker declines the job before starting it
+    DeclineAssignment = 54,
+    /// Poster sends submitted work back for revision
+    RequestRevision = 55,
+    /// Draw a raised dispute's arbitrators from the committed slot hash
+    RevealDisputeArbitrators = 56,
+    /// Read-only timeline of an escrow and its dispute
+    EmitAudit = 57,
+    /// Release part of a SOL escrow to the worker (platform only)
+    ReleaseMilestone = 58,
+    /// Poster or platform unassigns a worker who has not submitted work
+    UnassignWorker = 59,
+    /// Read-only check of whether a dispute case can be finalized now
+    CanFinalize = 60,
+    /// Poster adds funds to an active escrow
+    TopUpEscrow = 61,
+    /// Poster pushes back an escrow's expiry
+    ExtendExpiry = 62,
+    /// Arbitrator withdraws stake above the locked minimum, staying active
+    WithdrawExcessStake = 63,
+    /// Poster and worker agree to cancel, splitting the escrow
+    MutualCancel = 64,
+    /// Poster reclaims an escrow whose worker missed the delivery deadline
+    ClaimMissedDeadline = 65,
+    /// Worker claims payment from a simple dispute nobody pursued
+    ClaimAbandonedDispute = 66,
+    /// Anyone folds an idle agent's reputation decay into the stored score
+    DecayReputation = 67,
+    /// Rent payer closes an accuracy claim once its dispute case is closed
+    CloseAccuracyClaim = 68,
+    /// Anyone removes an arbitrator whose accuracy fell below the minimum
+    DeactivateLowAccuracy = 69,
+    /// Selected arbitrator commits a hidden vote on a commit-reveal case
+    CommitVote = 70,
+    /// Selected arbitrator reveals their committed vote
+    RevealVote = 71,
+    /// Losing party appeals a finalized dispute to a larger panel
+    AppealDispute = 72,
+    /// Anyone pays out an appeal bond once the appealed case is final
+    SettleAppealBond = 73,
+    /// Selected arbitrator with a conflict of interest steps down before voting
+    RecuseArbitrator = 74,
+    /// Read-only: log whether the signer is the escrow's poster, worker or neither
+    GetMyRole = 75,
+    /// Create the arbitrator pool's overflow segment
+    InitArbitratorPoolExtension = 76,
+    /// Read-only: return a versioned snapshot of an escrow's state
+    GetEscrow = 77,
+    /// Read-only: check a preimage against the escrow's proof_hash
+    VerifyProof = 78,
+    /// Platform pauses or resumes escrow creation and releases
+    SetPaused = 79,
+    /// Platform authority proposes its successor
+    SetAuthority = 80,
+    /// Proposed platform authority accepts the role
+    AcceptAuthority = 81,
+    /// Platform creates the platform config
+    InitPlatformConfig = 82,
+    /// Platform sets the fee on SOL releases
+    SetFeeBps = 83,
 }
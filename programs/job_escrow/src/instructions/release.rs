@@ -5,7 +5,13 @@
 //! - release_tokens_to_worker (platform only, SPL tokens)
 //! - approve_work (poster approves)
 //! - auto_release (review window expired)
+//! - poke_review (review window expired, cranker earns a bounty)
 //! - release_with_reputation (with reputation updates)
+//!
+//! SOL releases take the escrow's referrer (and optionally their reputation
+//! account) as trailing accounts; the referrer gets the configured share of
+//! the platform fee. approve_work, auto_release and poke_review can also take
+//! the parties' reputation accounts, ahead of the referrer, to credit the job.
 
 use pinocchio::{
     account_info::AccountInfo,
@@ -20,57 +26,162 @@ use pinocchio_token::instructions::Transfer as TokenTransfer;
 
 use crate::{
     errors::EscrowError,
-    state::{JobEscrow, EscrowStatus, AgentReputation},
+    events::FundsReleased,
+    state::{JobEscrow, EscrowStatus, AgentReputation, PlatformConfig},
     require, require_some,
-    PLATFORM_WALLET,
     ID,
 };
 
+use super::helpers::{
+    find_platform_vault, is_platform_fee_owner, pay_referral, referral_share, require_writable,
+    safe_pay_from_escrow, settle_worker_deposit, split_fee, split_token_fee, verify_payout,
+    ReferralAccounts, TokenAccountInfo, TOKEN_PROGRAM_ID,
+};
+use super::fee_config::{
+    check_platform_config, mint_fee, platform_authority, platform_fee_bps, referral_bps, require_not_paused,
+    require_platform_authority, require_platform_recipient,
+};
 use super::submit_work::REVIEW_WINDOW_SECONDS;
 
-/// Transfer lamports between accounts
-#[inline(always)]
-fn transfer_lamports(
-    from: &AccountInfo,
-    to: &AccountInfo,
+/// Optional trailing accounts of approve_work, auto_release and poke_review
+/// Accounts:
+/// 0. worker_reputation (optional, writable) - PDA ["reputation", worker]
+/// 1. poster_reputation (optional, writable) - PDA ["reputation", poster]
+/// 2. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 3. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
+///
+/// The reputation accounts come as a pair, ahead of a referrer. Either group
+/// may be left out; a leading account keyed as the escrow's referrer starts
+/// the referral accounts.
+pub struct SettlementAccounts<'a> {
+    pub reputations: Option<(&'a AccountInfo, &'a AccountInfo)>,
+    pub referral: Option<ReferralAccounts<'a>>,
+}
+
+impl<'a> SettlementAccounts<'a> {
+    /// Parse from the accounts following an instruction's fixed accounts
+    pub fn from_rest(rest: &'a [AccountInfo], escrow: &JobEscrow) -> Result<Self, ProgramError> {
+        let Some(first) = rest.first() else {
+            return Ok(Self { reputations: None, referral: None });
+        };
+        if leads_with_referrer(escrow, first.key()) {
+            return Ok(Self { reputations: None, referral: ReferralAccounts::from_rest(rest)? });
+        }
+        let [worker_reputation, poster_reputation, referral @ ..] = rest else {
+            return Ok(Self { reputations: None, referral: None });
+        };
+        require_writable(&[worker_reputation, poster_reputation])?;
+        Ok(Self {
+            reputations: Some((worker_reputation, poster_reputation)),
+            referral: ReferralAccounts::from_rest(referral)?,
+        })
+    }
+}
+
+/// Whether the first trailing settlement account is the escrow's referrer
+/// rather than the worker's reputation account
+fn leads_with_referrer(escrow: &JobEscrow, first: &Pubkey) -> bool {
+    escrow.has_referrer() && first == &escrow.referrer
+}
+
+/// Credit a completed job to the worker's and poster's reputation accounts
+fn record_completed_job(
+    worker_reputation: &AccountInfo,
+    poster_reputation: &AccountInfo,
+    escrow: &JobEscrow,
+    worker_payment: u64,
     amount: u64,
+    now: i64,
+    program_id: &Pubkey,
 ) -> ProgramResult {
-    *from.try_borrow_mut_lamports()? -= amount;
-    *to.try_borrow_mut_lamports()? += amount;
+    // SECURITY FIX C-01: Verify reputation accounts are owned by this program
+    if *worker_reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *poster_reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // SECURITY FIX C-03: Verify worker reputation PDA derivation
+    let (expected_worker_rep, _) = find_program_address(
+        &[b"reputation", &escrow.worker],
+        program_id,
+    );
+    require!(worker_reputation.key() == &expected_worker_rep, EscrowError::InvalidPda);
+
+    // SECURITY FIX C-03: Verify poster reputation PDA derivation
+    let (expected_poster_rep, _) = find_program_address(
+        &[b"reputation", &escrow.poster],
+        program_id,
+    );
+    require!(poster_reputation.key() == &expected_poster_rep, EscrowError::InvalidPda);
+
+    // Update worker reputation
+    {
+        let worker_rep_data = &mut worker_reputation.try_borrow_mut_data()?;
+        let worker_rep = AgentReputation::load_mut(worker_rep_data)?;
+        // SECURITY FIX H-05: Use checked arithmetic
+        worker_rep.jobs_completed = worker_rep.jobs_completed.saturating_add(1);
+        worker_rep.total_earned = worker_rep.total_earned.saturating_add(worker_payment);
+        worker_rep.last_active_at = now;
+        worker_rep.update_score();
+    }
+
+    // Update poster reputation
+    {
+        let poster_rep_data = &mut poster_reputation.try_borrow_mut_data()?;
+        let poster_rep = AgentReputation::load_mut(poster_rep_data)?;
+        // SECURITY FIX H-05: Use checked arithmetic
+        poster_rep.jobs_posted = poster_rep.jobs_posted.saturating_add(1);
+        poster_rep.total_spent = poster_rep.total_spent.saturating_add(amount);
+        poster_rep.last_active_at = now;
+        poster_rep.update_score();
+    }
+
     Ok(())
 }
 
 // ============== RELEASE TO WORKER (Platform Only) ==============
 
 /// Release to worker accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. platform_authority (signer) - the platform authority, per the platform config
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee
+/// 5. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 6. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
 pub struct ReleaseToWorkerAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub platform_authority: &'a AccountInfo,
     pub worker: &'a AccountInfo,
     pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub referral: Option<ReferralAccounts<'a>>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseToWorkerAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, platform_authority, worker, platform, ..] = accounts else {
+        let [escrow, platform_authority, worker, platform, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker, platform])?;
+
         if !platform_authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Platform authority must be PLATFORM_WALLET
-        require!(platform_authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-        require!(platform.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
         Ok(Self {
             escrow,
             platform_authority,
             worker,
             platform,
+            platform_config,
+            referral: ReferralAccounts::from_rest(rest)?,
         })
     }
 }
@@ -82,6 +193,11 @@ pub fn process_release_to_worker(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = ReleaseToWorkerAccounts::try_from(accounts)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+    let referral_bps = referral_bps(ctx.platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
@@ -103,16 +219,29 @@ pub fn process_release_to_worker(
     require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
     require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    let amount = escrow.amount;
-    // SECURITY FIX H-05: Use checked arithmetic
-    let platform_fee = amount.checked_div(100).unwrap_or(0);
-    let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    let amount = escrow.settle_remaining();
+    let (worker_payment, platform_fee) = split_fee(amount, fee_bps)?;
+    let referral = referral_share(escrow, ctx.referral.as_ref(), platform_fee, referral_bps)?;
+    let platform_share = platform_fee - referral;
+    verify_payout(amount, &[worker_payment, platform_share, referral])?;
 
     escrow.status = EscrowStatus::Released as u8;
 
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
+    }
+    .emit();
+
     // Transfer funds
-    transfer_lamports(ctx.escrow, ctx.worker, worker_payment)?;
-    transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, worker_payment)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, platform_share)?;
+    pay_referral(ctx.escrow, ctx.referral.as_ref(), referral, program_id)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
 
     Ok(())
 }
@@ -120,28 +249,47 @@ pub fn process_release_to_worker(
 // ============== APPROVE WORK (Poster) ==============
 
 /// Approve work accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. poster (signer)
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee
+/// 5. worker_reputation (optional, writable) - PDA ["reputation", worker]
+/// 6. poster_reputation (optional, writable) - PDA ["reputation", poster]
+/// 7. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 8. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
 pub struct ApproveWorkAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub poster: &'a AccountInfo,
     pub worker: &'a AccountInfo,
     pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub settlement: &'a [AccountInfo],
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ApproveWorkAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, poster, worker, platform, ..] = accounts else {
+        let [escrow, poster, worker, platform, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker, platform])?;
+
         if !poster.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(platform.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
-        Ok(Self { escrow, poster, worker, platform })
+        Ok(Self {
+            escrow,
+            poster,
+            worker,
+            platform,
+            platform_config,
+            settlement: rest,
+        })
     }
 }
 
@@ -152,6 +300,11 @@ pub fn process_approve_work(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = ApproveWorkAccounts::try_from(accounts)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+    let referral_bps = referral_bps(ctx.platform_config, program_id)?;
+    let clock = Clock::get()?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
@@ -173,15 +326,42 @@ pub fn process_approve_work(
     require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
     require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    let amount = escrow.amount;
-    // SECURITY FIX H-05: Use checked arithmetic
-    let platform_fee = amount.checked_div(100).unwrap_or(0);
-    let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    let amount = escrow.settle_remaining();
+    let (worker_payment, platform_fee) = split_fee(amount, fee_bps)?;
+    let settlement = SettlementAccounts::from_rest(ctx.settlement, escrow)?;
+    let referral = settlement.referral.as_ref();
+    let referral_amount = referral_share(escrow, referral, platform_fee, referral_bps)?;
+    let platform_share = platform_fee - referral_amount;
+    verify_payout(amount, &[worker_payment, platform_share, referral_amount])?;
 
     escrow.status = EscrowStatus::Released as u8;
 
-    transfer_lamports(ctx.escrow, ctx.worker, worker_payment)?;
-    transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
+    }
+    .emit();
+
+    if let Some((worker_reputation, poster_reputation)) = settlement.reputations {
+        record_completed_job(
+            worker_reputation,
+            poster_reputation,
+            escrow,
+            worker_payment,
+            amount,
+            clock.unix_timestamp,
+            program_id,
+        )?;
+    }
+
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, worker_payment)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, platform_share)?;
+    pay_referral(ctx.escrow, referral, referral_amount, program_id)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
 
     Ok(())
 }
@@ -189,28 +369,47 @@ pub fn process_approve_work(
 // ============== AUTO RELEASE (Anyone after deadline) ==============
 
 /// Auto release accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. cranker (signer)
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee
+/// 5. worker_reputation (optional, writable) - PDA ["reputation", worker]
+/// 6. poster_reputation (optional, writable) - PDA ["reputation", poster]
+/// 7. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 8. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
 pub struct AutoReleaseAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub cranker: &'a AccountInfo,
     pub worker: &'a AccountInfo,
     pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub settlement: &'a [AccountInfo],
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for AutoReleaseAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, cranker, worker, platform, ..] = accounts else {
+        let [escrow, cranker, worker, platform, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker, platform])?;
+
         if !cranker.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(platform.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
-        Ok(Self { escrow, cranker, worker, platform })
+        Ok(Self {
+            escrow,
+            cranker,
+            worker,
+            platform,
+            platform_config,
+            settlement: rest,
+        })
     }
 }
 
@@ -221,14 +420,44 @@ pub fn process_auto_release(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = AutoReleaseAccounts::try_from(accounts)?;
+    release_after_review(
+        ctx.escrow,
+        ctx.worker,
+        ctx.platform,
+        ctx.platform_config,
+        None,
+        ctx.settlement,
+        program_id,
+    )
+}
+
+/// Release a PendingReview SOL escrow whose review window has expired
+///
+/// The platform takes the configured fee of the amount. With `bounty` set,
+/// that share (in basis points) of the platform fee goes to the given account
+/// instead of the platform, as does the referrer's share. Reputation accounts
+/// among the `settlement` accounts are credited with the job.
+fn release_after_review(
+    escrow_account: &AccountInfo,
+    worker: &AccountInfo,
+    platform: &AccountInfo,
+    platform_config: &AccountInfo,
+    bounty: Option<(&AccountInfo, u16)>,
+    settlement: &[AccountInfo],
+    program_id: &Pubkey,
+) -> ProgramResult {
     let clock = Clock::get()?;
+    require_not_paused(platform_config, program_id)?;
+    require_platform_recipient(platform, platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(platform_config, program_id)?;
+    let referral_bps = referral_bps(platform_config, program_id)?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
-    if *ctx.escrow.owner() != ID {
+    if *escrow_account.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow_data = &mut escrow_account.try_borrow_mut_data()?;
     let escrow = JobEscrow::load_mut(escrow_data)?;
 
     // SECURITY FIX C-02: Verify escrow PDA derivation
@@ -236,11 +465,13 @@ pub fn process_auto_release(
         &[b"escrow", &escrow.job_id_hash, &escrow.poster],
         program_id,
     );
-    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow_account.key() == &expected_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
 
     require!(escrow.status == EscrowStatus::PendingReview as u8, EscrowError::NotPendingReview);
-    require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+    require!(worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
+    // The worker asked for a neutral decision; the review escalates instead
+    require!(escrow.auto_arbitrate == 0, EscrowError::AutoArbitrationEnabled);
 
     // Check review window expired
     let submitted_at = require_some!(escrow.get_submitted_at(), EscrowError::NoSubmissionTime);
@@ -249,22 +480,140 @@ pub fn process_auto_release(
         EscrowError::ReviewWindowNotExpired
     );
 
-    let amount = escrow.amount;
-    // SECURITY FIX H-05: Use checked arithmetic
-    let platform_fee = amount.checked_div(100).unwrap_or(0);
-    let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    let amount = escrow.settle_remaining();
+    let (worker_payment, platform_fee) = split_fee(amount, fee_bps)?;
+    let bounty_bps = bounty.map_or(0, |(_, bps)| bps);
+    let (_, bounty_amount) = split_fee(platform_fee, bounty_bps)?;
+    let settlement = SettlementAccounts::from_rest(settlement, escrow)?;
+    let referral = settlement.referral.as_ref();
+    let referral_amount = referral_share(escrow, referral, platform_fee, referral_bps)?;
+    // Bounty and referral are each capped at half the fee, so this cannot underflow
+    let platform_share = platform_fee - bounty_amount - referral_amount;
+    verify_payout(amount, &[worker_payment, platform_share, bounty_amount, referral_amount])?;
 
     escrow.status = EscrowStatus::Released as u8;
 
-    transfer_lamports(ctx.escrow, ctx.worker, worker_payment)?;
-    transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
+    FundsReleased {
+        escrow: *escrow_account.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
+    }
+    .emit();
+
+    if let Some((worker_reputation, poster_reputation)) = settlement.reputations {
+        record_completed_job(
+            worker_reputation,
+            poster_reputation,
+            escrow,
+            worker_payment,
+            amount,
+            clock.unix_timestamp,
+            program_id,
+        )?;
+    }
+
+    safe_pay_from_escrow(escrow_account, worker, worker_payment)?;
+    safe_pay_from_escrow(escrow_account, platform, platform_share)?;
+    if let Some((recipient, _)) = bounty {
+        safe_pay_from_escrow(escrow_account, recipient, bounty_amount)?;
+    }
+    pay_referral(escrow_account, referral, referral_amount, program_id)?;
+    settle_worker_deposit(escrow, escrow_account, worker)?;
 
     Ok(())
 }
 
+// ============== POKE REVIEW (Anyone after deadline, with bounty) ==============
+
+/// Poke review accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. cranker (signer, writable) - receives the bounty
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. platform_config (readonly) - PDA ["platform_config"], sets the fee and bounty
+/// 5. worker_reputation (optional, writable) - PDA ["reputation", worker]
+/// 6. poster_reputation (optional, writable) - PDA ["reputation", poster]
+/// 7. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 8. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
+pub struct PokeReviewAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub cranker: &'a AccountInfo,
+    pub worker: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub settlement: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PokeReviewAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, cranker, worker, platform, platform_config, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow, cranker, worker, platform])?;
+
+        if !cranker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self {
+            escrow,
+            cranker,
+            worker,
+            platform,
+            platform_config,
+            settlement: rest,
+        })
+    }
+}
+
+/// Process poke_review instruction
+///
+/// Same as auto_release, but pays the cranker the configured share of the
+/// platform fee so keepers have a reason to clear stuck reviews.
+pub fn process_poke_review(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = PokeReviewAccounts::try_from(accounts)?;
+
+    check_platform_config(ctx.platform_config, program_id)?;
+    let bounty_bps = {
+        let config_data = ctx.platform_config.try_borrow_data()?;
+        PlatformConfig::load(&config_data)?.crank_bounty_bps
+    };
+
+    release_after_review(
+        ctx.escrow,
+        ctx.worker,
+        ctx.platform,
+        ctx.platform_config,
+        Some((ctx.cranker, bounty_bps)),
+        ctx.settlement,
+        program_id,
+    )
+}
+
 // ============== RELEASE WITH REPUTATION ==============
 
 /// Release with reputation accounts
+/// Accounts:
+/// 0. escrow (PDA, writable)
+/// 1. platform_authority (signer) - the platform authority, per the platform config
+/// 2. worker (writable)
+/// 3. platform (writable) - the platform authority, per the platform config
+/// 4. worker_reputation (PDA, writable)
+/// 5. poster_reputation (PDA, writable)
+/// 6. platform_config (readonly) - PDA ["platform_config"], sets the fee
+/// 7. referrer (optional, writable) - the escrow's referrer, if it has one
+/// 8. referrer_reputation (optional, writable) - PDA ["reputation", referrer]
 pub struct ReleaseWithReputationAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub platform_authority: &'a AccountInfo,
@@ -272,23 +621,24 @@ pub struct ReleaseWithReputationAccounts<'a> {
     pub platform: &'a AccountInfo,
     pub worker_reputation: &'a AccountInfo,
     pub poster_reputation: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub referral: Option<ReferralAccounts<'a>>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseWithReputationAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, platform_authority, worker, platform, worker_reputation, poster_reputation, ..] = accounts else {
+        let [escrow, platform_authority, worker, platform, worker_reputation, poster_reputation, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker, platform, worker_reputation, poster_reputation])?;
+
         if !platform_authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        require!(platform_authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-        require!(platform.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
         Ok(Self {
             escrow,
             platform_authority,
@@ -296,6 +646,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseWithReputationAccounts<'a> {
             platform,
             worker_reputation,
             poster_reputation,
+            platform_config,
+            referral: ReferralAccounts::from_rest(rest)?,
         })
     }
 }
@@ -307,20 +659,18 @@ pub fn process_release_with_reputation(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = ReleaseWithReputationAccounts::try_from(accounts)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+    require_platform_recipient(ctx.platform, ctx.platform_config, program_id)?;
+    let fee_bps = platform_fee_bps(ctx.platform_config, program_id)?;
+    let referral_bps = referral_bps(ctx.platform_config, program_id)?;
+    let clock = Clock::get()?;
 
     // SECURITY FIX C-01: Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // SECURITY FIX C-01: Verify reputation accounts are owned by this program
-    if *ctx.worker_reputation.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
-    if *ctx.poster_reputation.owner() != ID {
-        return Err(ProgramError::IncorrectProgramId);
-    }
-
     // Load escrow
     let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
     let escrow = JobEscrow::load_mut(escrow_data)?;
@@ -340,50 +690,39 @@ pub fn process_release_with_reputation(
     require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
     require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    // SECURITY FIX C-03: Verify worker reputation PDA derivation
-    let (expected_worker_rep, _) = find_program_address(
-        &[b"reputation", &escrow.worker],
-        program_id,
-    );
-    require!(ctx.worker_reputation.key() == &expected_worker_rep, EscrowError::InvalidPda);
-
-    // SECURITY FIX C-03: Verify poster reputation PDA derivation
-    let (expected_poster_rep, _) = find_program_address(
-        &[b"reputation", &escrow.poster],
-        program_id,
-    );
-    require!(ctx.poster_reputation.key() == &expected_poster_rep, EscrowError::InvalidPda);
-
-    let amount = escrow.amount;
-    // SECURITY FIX H-05: Use checked arithmetic
-    let platform_fee = amount.checked_div(100).unwrap_or(0);
-    let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    let amount = escrow.settle_remaining();
+    let (worker_payment, platform_fee) = split_fee(amount, fee_bps)?;
+    let referral = referral_share(escrow, ctx.referral.as_ref(), platform_fee, referral_bps)?;
+    let platform_share = platform_fee - referral;
+    verify_payout(amount, &[worker_payment, platform_share, referral])?;
 
     escrow.status = EscrowStatus::Released as u8;
 
-    // Update worker reputation
-    {
-        let worker_rep_data = &mut ctx.worker_reputation.try_borrow_mut_data()?;
-        let worker_rep = AgentReputation::load_mut(worker_rep_data)?;
-        // SECURITY FIX H-05: Use checked arithmetic
-        worker_rep.jobs_completed = worker_rep.jobs_completed.saturating_add(1);
-        worker_rep.total_earned = worker_rep.total_earned.saturating_add(worker_payment);
-        worker_rep.update_score();
-    }
-
-    // Update poster reputation
-    {
-        let poster_rep_data = &mut ctx.poster_reputation.try_borrow_mut_data()?;
-        let poster_rep = AgentReputation::load_mut(poster_rep_data)?;
-        // SECURITY FIX H-05: Use checked arithmetic
-        poster_rep.jobs_posted = poster_rep.jobs_posted.saturating_add(1);
-        poster_rep.total_spent = poster_rep.total_spent.saturating_add(amount);
-        poster_rep.update_score();
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
     }
+    .emit();
+
+    record_completed_job(
+        ctx.worker_reputation,
+        ctx.poster_reputation,
+        escrow,
+        worker_payment,
+        amount,
+        clock.unix_timestamp,
+        program_id,
+    )?;
 
     // Transfer funds
-    transfer_lamports(ctx.escrow, ctx.worker, worker_payment)?;
-    transfer_lamports(ctx.escrow, ctx.platform, platform_fee)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.worker, worker_payment)?;
+    safe_pay_from_escrow(ctx.escrow, ctx.platform, platform_share)?;
+    pay_referral(ctx.escrow, ctx.referral.as_ref(), referral, program_id)?;
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
 
     Ok(())
 }
@@ -393,12 +732,14 @@ pub fn process_release_with_reputation(
 /// Release tokens to worker accounts
 /// Accounts:
 /// 0. escrow (PDA, writable)
-/// 1. platform_authority (signer) - must be PLATFORM_WALLET
-/// 2. worker (readonly)
+/// 1. platform_authority (signer) - the platform authority, per the platform config
+/// 2. worker (writable) - gets the worker bond back
 /// 3. escrow_token_account (writable) - escrow's token account
 /// 4. worker_token_account (writable) - worker's ATA
-/// 5. platform_token_account (writable) - platform's ATA for fee
+/// 5. platform_token_account (writable) - platform's ATA, or a platform vault token account
 /// 6. token_program
+/// 7. platform_config (readonly) - PDA ["platform_config"]; release fails while paused
+/// 8. mint_fee_config (optional, readonly) - PDA ["mint_fee", mint]; overrides the default fee
 pub struct ReleaseTokensToWorkerAccounts<'a> {
     pub escrow: &'a AccountInfo,
     pub platform_authority: &'a AccountInfo,
@@ -407,31 +748,26 @@ pub struct ReleaseTokensToWorkerAccounts<'a> {
     pub worker_token_account: &'a AccountInfo,
     pub platform_token_account: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+    pub mint_fee_config: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseTokensToWorkerAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [escrow, platform_authority, worker, escrow_token_account, worker_token_account, platform_token_account, token_program, ..] = accounts else {
+        let [escrow, platform_authority, worker, escrow_token_account, worker_token_account, platform_token_account, token_program, platform_config, rest @ ..] = accounts else {
             return Err(ProgramError::NotEnoughAccountKeys);
         };
 
+        require_writable(&[escrow, worker])?;
+
         if !platform_authority.is_signer() {
             return Err(ProgramError::MissingRequiredSignature);
         }
 
-        // Platform authority must be PLATFORM_WALLET
-        require!(platform_authority.key() == &PLATFORM_WALLET, EscrowError::NotPlatformAuthority);
-
         // Verify token program ID
-        let expected_token_program: Pubkey = [
-            0x06, 0xdd, 0xf6, 0xe1, 0xd7, 0x65, 0xa1, 0x93,
-            0xd9, 0xcb, 0xe1, 0x46, 0xce, 0xeb, 0x79, 0xac,
-            0x1c, 0xb4, 0x85, 0xed, 0x5f, 0x5b, 0x37, 0x91,
-            0x3a, 0x8c, 0xf5, 0x85, 0x7e, 0xff, 0x00, 0xa9,
-        ]; // TokenkegQEcLiukSpvdP3kMR6CYjQLTdM9TBgmYABBmL
-        if token_program.key() != &expected_token_program {
+        if token_program.key() != &TOKEN_PROGRAM_ID {
             return Err(ProgramError::IncorrectProgramId);
         }
 
@@ -443,6 +779,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ReleaseTokensToWorkerAccounts<'a> {
             worker_token_account,
             platform_token_account,
             token_program,
+            platform_config,
+            mint_fee_config: rest.first(),
         })
     }
 }
@@ -454,6 +792,8 @@ pub fn process_release_tokens_to_worker(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = ReleaseTokensToWorkerAccounts::try_from(accounts)?;
+    require_not_paused(ctx.platform_config, program_id)?;
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
 
     // Verify escrow account is owned by this program
     if *ctx.escrow.owner() != ID {
@@ -481,20 +821,43 @@ pub fn process_release_tokens_to_worker(
     require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
     require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    let amount = escrow.amount;
-    // Calculate platform fee (1%)
-    let platform_fee = amount.checked_div(100).unwrap_or(0);
-    let worker_payment = amount.checked_sub(platform_fee).ok_or(EscrowError::ArithmeticOverflow)?;
+    // Fee destination must hold the escrow's mint and belong to the platform
+    // authority or the platform vault PDA
+    let platform_token = TokenAccountInfo::from_account(ctx.platform_token_account)?;
+    require!(platform_token.mint == escrow.token_mint, EscrowError::TokenAccountMismatch);
+    let authority = platform_authority(ctx.platform_config, program_id)?;
+    let (platform_vault, _) = find_platform_vault(program_id);
+    require!(
+        is_platform_fee_owner(&platform_token.owner, &authority, &platform_vault),
+        EscrowError::InvalidFeeRecipient
+    );
+
+    // Milestones already paid out are no longer held by the escrow
+    let amount = escrow.remaining_amount();
+    // Platform fee: the mint's override if configured, otherwise the default 1%
+    let (fee_bps, min_fee) = mint_fee(ctx.mint_fee_config, &escrow.token_mint, program_id)?;
+    let (worker_payment, platform_fee) = split_token_fee(amount, fee_bps, min_fee)?;
+    verify_payout(amount, &[worker_payment, platform_fee])?;
 
     // Update status
     escrow.status = EscrowStatus::Released as u8;
 
+    FundsReleased {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        amount,
+        worker_payment,
+        platform_fee,
+        fully_released: true,
+    }
+    .emit();
+
     // Create PDA signer for token transfers
     let bump_ref = &[escrow.bump];
     let signer_seeds = seeds!(b"escrow", &escrow.job_id_hash, &escrow.poster, bump_ref);
     let signer = Signer::from(&signer_seeds);
 
-    // Transfer tokens to worker (99%)
+    // Transfer tokens to worker
     TokenTransfer {
         from: ctx.escrow_token_account,
         to: ctx.worker_token_account,
@@ -503,7 +866,7 @@ pub fn process_release_tokens_to_worker(
     }
     .invoke_signed(&[signer.clone()])?;
 
-    // Transfer platform fee (1%)
+    // Transfer platform fee
     if platform_fee > 0 {
         TokenTransfer {
             from: ctx.escrow_token_account,
@@ -514,5 +877,59 @@ pub fn process_release_tokens_to_worker(
         .invoke_signed(&[signer])?;
     }
 
+    settle_worker_deposit(escrow, ctx.escrow, ctx.worker)?;
+
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions::fee_config::check_not_paused;
+
+    #[test]
+    fn test_release_fails_while_paused_and_succeeds_after_unpause() {
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut escrow_data).unwrap();
+        escrow.amount = 1_000_000_000;
+        escrow.status = EscrowStatus::PendingReview as u8;
+        let mut config_data = std::vec![0u8; PlatformConfig::SPACE];
+        PlatformConfig::init(&mut config_data).unwrap().paused = 1;
+
+        // The settlement steps of approve_work, against the config's data
+        let release = |config_data: &[u8], escrow: &mut JobEscrow| -> Result<u64, ProgramError> {
+            check_not_paused(Some(config_data))?;
+            let fee_bps = PlatformConfig::load(config_data)?.fee_bps;
+            let (worker_payment, _) = split_fee(escrow.settle_remaining(), fee_bps)?;
+            escrow.status = EscrowStatus::Released as u8;
+            Ok(worker_payment)
+        };
+
+        assert_eq!(
+            release(&config_data, escrow),
+            Err(ProgramError::from(EscrowError::ProgramPaused))
+        );
+        assert_eq!(escrow.status, EscrowStatus::PendingReview as u8);
+        assert_eq!(escrow.remaining_amount(), 1_000_000_000);
+
+        PlatformConfig::load_mut(&mut config_data).unwrap().paused = 0;
+        assert_eq!(release(&config_data, escrow).unwrap(), 1_000_000_000);
+        assert_eq!(escrow.status, EscrowStatus::Released as u8);
+        assert_eq!(escrow.remaining_amount(), 0);
+    }
+
+    #[test]
+    fn test_referred_release_without_reputations_reads_referrer() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        let referrer = [5u8; 32];
+        let worker_reputation = [6u8; 32];
+
+        // Without a referrer the leading accounts are the reputation pair
+        assert!(!leads_with_referrer(escrow, &referrer));
+
+        escrow.referrer = referrer;
+        assert!(leads_with_referrer(escrow, &referrer));
+        assert!(!leads_with_referrer(escrow, &worker_reputation));
+    }
+}
@@ -1,6 +1,7 @@
 //! Reputation instructions
 //!
-//! Handles reputation account initialization.
+//! Handles reputation account initialization, either self-funded or
+//! sponsored by the platform, and the permissionless inactivity decay crank.
 
 use pinocchio::{
     account_info::AccountInfo,
@@ -17,8 +18,12 @@ use crate::{
     errors::EscrowError,
     state::AgentReputation,
     require,
+    ID,
 };
 
+use super::fee_config::require_platform_authority;
+use super::helpers::require_writable;
+
 // ============== INIT REPUTATION ==============
 
 pub struct InitReputationAccounts<'a> {
@@ -51,26 +56,142 @@ pub fn process_init_reputation(
     program_id: &Pubkey,
 ) -> ProgramResult {
     let ctx = InitReputationAccounts::try_from(accounts)?;
+    create_reputation_account(ctx.reputation, ctx.agent, ctx.payer, program_id, false)
+}
+
+// ============== SPONSOR REPUTATION ==============
+
+/// Sponsor reputation accounts
+/// Accounts:
+/// 0. reputation (writable) - PDA ["reputation", agent]
+/// 1. agent - the agent being onboarded (need not sign)
+/// 2. platform (signer, writable) - the platform authority, pays rent
+/// 3. system_program
+/// 4. platform_config (readonly) - PDA ["platform_config"], names the authority
+pub struct SponsorReputationAccounts<'a> {
+    pub reputation: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+    pub platform: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SponsorReputationAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [reputation, agent, platform, system_program, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !platform.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { reputation, agent, platform, system_program, platform_config })
+    }
+}
+
+/// Process sponsor_reputation instruction
+///
+/// Creates an agent's reputation account with rent paid by the platform.
+pub fn process_sponsor_reputation(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = SponsorReputationAccounts::try_from(accounts)?;
+    require_platform_authority(ctx.platform, ctx.platform_config, program_id)?;
+    create_reputation_account(ctx.reputation, ctx.agent, ctx.platform, program_id, true)
+}
+
+// ============== DECAY REPUTATION ==============
+
+/// Decay reputation accounts
+/// Accounts:
+/// 0. reputation (writable) - PDA ["reputation", agent]
+/// 1. cranker (signer)
+pub struct DecayReputationAccounts<'a> {
+    pub reputation: &'a AccountInfo,
+    pub cranker: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DecayReputationAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [reputation, cranker, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[reputation])?;
+
+        if !cranker.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { reputation, cranker })
+    }
+}
+
+/// Process decay_reputation instruction
+///
+/// Anyone can fold an idle agent's inactivity decay into the stored
+/// reputation_score, so readers that use the raw field see it too.
+pub fn process_decay_reputation(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = DecayReputationAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    // SECURITY FIX C-01: Verify reputation account is owned by this program
+    if *ctx.reputation.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let rep_data = &mut ctx.reputation.try_borrow_mut_data()?;
+    let rep = AgentReputation::load_mut(rep_data)?;
+
+    // SECURITY FIX C-03: Verify reputation PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(&[b"reputation", &rep.agent], program_id);
+    require!(ctx.reputation.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(rep.bump == expected_bump, EscrowError::InvalidPda);
+
+    rep.apply_decay(clock.unix_timestamp);
+
+    Ok(())
+}
+
+/// Create and initialize the reputation PDA for `agent`, with rent from `payer`
+pub(crate) fn create_reputation_account(
+    reputation: &AccountInfo,
+    agent: &AccountInfo,
+    payer: &AccountInfo,
+    program_id: &Pubkey,
+    sponsored: bool,
+) -> ProgramResult {
     let clock = Clock::get()?;
 
     // Verify PDA
     let (expected_pda, bump) = find_program_address(
-        &[b"reputation", ctx.agent.key()],
+        &[b"reputation", agent.key()],
         program_id,
     );
-    require!(ctx.reputation.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(reputation.key() == &expected_pda, EscrowError::InvalidPda);
 
     // Create account
     let rent = Rent::get()?;
     let rent_lamports = rent.minimum_balance(AgentReputation::SPACE);
 
     let bump_ref = &[bump];
-    let signer_seeds = seeds!(b"reputation", ctx.agent.key(), bump_ref);
+    let signer_seeds = seeds!(b"reputation", agent.key(), bump_ref);
     let signer = Signer::from(&signer_seeds);
 
     CreateAccount {
-        from: ctx.payer,
-        to: ctx.reputation,
+        from: payer,
+        to: reputation,
         lamports: rent_lamports,
         space: AgentReputation::SPACE as u64,
         owner: program_id,
@@ -78,19 +199,59 @@ pub fn process_init_reputation(
     .invoke_signed(&[signer])?;
 
     // Initialize
-    let rep_data = &mut ctx.reputation.try_borrow_mut_data()?;
-    let rep = AgentReputation::init(rep_data)?;
+    let rep_data = &mut reputation.try_borrow_mut_data()?;
+    initialize_reputation(rep_data, agent.key(), bump, clock.unix_timestamp, sponsored)
+}
+
+/// Write a fresh reputation record into newly created account data
+pub(crate) fn initialize_reputation(
+    data: &mut [u8],
+    agent: &Pubkey,
+    bump: u8,
+    now: i64,
+    sponsored: bool,
+) -> ProgramResult {
+    let rep = AgentReputation::init(data)?;
 
-    rep.agent = *ctx.agent.key();
+    rep.agent = *agent;
     rep.jobs_completed = 0;
     rep.jobs_posted = 0;
     rep.total_earned = 0;
     rep.total_spent = 0;
     rep.disputes_won = 0;
     rep.disputes_lost = 0;
+    rep.jobs_assigned = 0;
+    rep.jobs_abandoned = 0;
     rep.reputation_score = 0;
-    rep.created_at = clock.unix_timestamp;
+    rep.created_at = now;
+    rep.last_active_at = now;
     rep.bump = bump;
+    rep.is_sponsored = sponsored as u8;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sponsored_reputation_is_initialized_for_agent() {
+        let agent = [7u8; 32];
+        let mut data = std::vec![0u8; AgentReputation::SPACE];
+        initialize_reputation(&mut data, &agent, 254, 1_700_000_000, true).unwrap();
+
+        let rep = AgentReputation::load(&data).unwrap();
+        assert_eq!(rep.agent, agent);
+        assert_eq!(rep.bump, 254);
+        assert_eq!(rep.created_at, 1_700_000_000);
+        assert_eq!(rep.reputation_score, 0);
+        assert!(rep.is_sponsored());
+
+        // A sponsored account cannot be re-initialized over an existing one
+        assert_eq!(
+            initialize_reputation(&mut data, &agent, 254, 1_700_000_001, false),
+            Err(ProgramError::from(EscrowError::AccountAlreadyInitialized))
+        );
+    }
+}
@@ -1,6 +1,15 @@
 //! SubmitWork instruction
 //!
-//! Worker submits completed work, starting the review window.
+//! Worker submits completed work, starting the review window. A worker may
+//! resubmit while the work is pending review, but only during the first half
+//! of the window, so the window cannot be pushed back indefinitely.
+//!
+//! The worker can also opt the escrow into auto-arbitration: if the poster
+//! lets the review window lapse, the escrow escalates to arbitration instead
+//! of auto-releasing.
+//!
+//! During review the poster may instead send the work back for revision, up
+//! to MAX_REVISIONS times.
 
 use pinocchio::{
     account_info::AccountInfo,
@@ -12,14 +21,27 @@ use pinocchio::{
 
 use crate::{
     errors::EscrowError,
+    events::WorkSubmitted,
     state::{JobEscrow, EscrowStatus},
-    require,
+    require, require_some,
     ID,
 };
 
+use super::helpers::require_writable;
+
 /// Review window after worker submits: 24 hours
 pub const REVIEW_WINDOW_SECONDS: i64 = 24 * 60 * 60;
 
+/// Resubmission is allowed only in the first half of the review window
+pub const RESUBMISSION_WINDOW_SECONDS: i64 = REVIEW_WINDOW_SECONDS / 2;
+
+/// Check that a resubmission at `now` still falls within the resubmission window
+fn check_resubmission_window(submitted_at: i64, now: i64) -> ProgramResult {
+    let closes_at = submitted_at.saturating_add(RESUBMISSION_WINDOW_SECONDS);
+    require!(now < closes_at, EscrowError::ResubmissionWindowClosed);
+    Ok(())
+}
+
 /// Submit work instruction accounts
 pub struct SubmitWorkAccounts<'a> {
     pub escrow: &'a AccountInfo,
@@ -43,20 +65,22 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SubmitWorkAccounts<'a> {
 }
 
 /// Instruction data for SubmitWork
-/// Layout: [has_proof: u8, proof_hash: [u8; 32] (optional)]
+/// Layout: [has_proof: u8, proof_hash: [u8; 32] (only if has_proof), auto_arbitrate: u8 (optional)]
 pub struct SubmitWorkData {
     pub proof_hash: Option<[u8; 32]>,
+    pub auto_arbitrate: bool,
 }
 
 impl SubmitWorkData {
     pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
         if data.is_empty() {
-            return Ok(Self { proof_hash: None });
+            return Ok(Self { proof_hash: None, auto_arbitrate: false });
         }
         
         let has_proof = data[0];
         if has_proof == 0 {
-            return Ok(Self { proof_hash: None });
+            let auto_arbitrate = data.get(1).is_some_and(|&flag| flag != 0);
+            return Ok(Self { proof_hash: None, auto_arbitrate });
         }
         
         if data.len() < 33 {
@@ -64,7 +88,8 @@ impl SubmitWorkData {
         }
         
         let proof_hash: [u8; 32] = data[1..33].try_into().unwrap();
-        Ok(Self { proof_hash: Some(proof_hash) })
+        let auto_arbitrate = data.get(33).is_some_and(|&flag| flag != 0);
+        Ok(Self { proof_hash: Some(proof_hash), auto_arbitrate })
     }
 }
 
@@ -96,8 +121,13 @@ pub fn process_submit_work(
     require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
     require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
 
-    // Must be active
-    require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    // Must be active, or pending review for a resubmission
+    if escrow.is_pending_review() {
+        let submitted_at = require_some!(escrow.get_submitted_at(), EscrowError::NoSubmissionTime);
+        check_resubmission_window(submitted_at, clock.unix_timestamp)?;
+    } else {
+        require!(escrow.status == EscrowStatus::Active as u8, EscrowError::EscrowNotActive);
+    }
 
     // Must have worker assigned
     require!(escrow.has_worker(), EscrowError::NoWorkerAssigned);
@@ -105,9 +135,11 @@ pub fn process_submit_work(
     // Worker must match
     require!(ctx.worker.key() == &escrow.worker, EscrowError::WorkerMismatch);
 
-    // Update status and timestamps
-    escrow.status = EscrowStatus::PendingReview as u8;
-    escrow.submitted_at = clock.unix_timestamp;
+    // Bilateral escrows need the worker's bond before work is submitted
+    require!(!escrow.awaiting_worker_deposit(), EscrowError::WorkerDepositNotPosted);
+
+    // Update status, timestamps, and review cycle count
+    escrow.mark_submitted(clock.unix_timestamp);
 
     // Set proof hash if provided
     if let Some(hash) = args.proof_hash {
@@ -115,5 +147,112 @@ pub fn process_submit_work(
         escrow.has_proof_hash = 1;
     }
 
+    if args.auto_arbitrate {
+        escrow.auto_arbitrate = 1;
+    }
+
+    WorkSubmitted {
+        escrow: *ctx.escrow.key(),
+        worker: escrow.worker,
+        submitted_at: clock.unix_timestamp,
+        review_cycles: escrow.review_cycles,
+    }
+    .emit();
+
     Ok(())
 }
+
+// ============== REQUEST REVISION ==============
+
+/// Request revision accounts
+/// Accounts:
+/// 0. escrow (writable)
+/// 1. poster (signer)
+pub struct RequestRevisionAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub poster: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RequestRevisionAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, poster, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        require_writable(&[escrow])?;
+
+        if !poster.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, poster })
+    }
+}
+
+/// Process request_revision instruction
+///
+/// The poster sends submitted work back; the escrow returns to Active and
+/// the worker submits again.
+pub fn process_request_revision(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = RequestRevisionAccounts::try_from(accounts)?;
+
+    // SECURITY FIX C-01: Verify escrow account is owned by this program
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = &mut ctx.escrow.try_borrow_mut_data()?;
+    let escrow = JobEscrow::load_mut(escrow_data)?;
+
+    // SECURITY FIX C-02: Verify escrow PDA derivation
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    require!(ctx.poster.key() == &escrow.poster, EscrowError::PosterMismatch);
+
+    escrow.request_revision()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resubmission_only_in_first_half_of_review_window() {
+        let submitted_at = 1_000;
+        assert!(check_resubmission_window(submitted_at, submitted_at + 60).is_ok());
+        assert!(check_resubmission_window(submitted_at, submitted_at + RESUBMISSION_WINDOW_SECONDS - 1).is_ok());
+        assert_eq!(
+            check_resubmission_window(submitted_at, submitted_at + RESUBMISSION_WINDOW_SECONDS),
+            Err(ProgramError::from(EscrowError::ResubmissionWindowClosed))
+        );
+        assert_eq!(
+            check_resubmission_window(submitted_at, submitted_at + REVIEW_WINDOW_SECONDS - 1),
+            Err(ProgramError::from(EscrowError::ResubmissionWindowClosed))
+        );
+    }
+
+    #[test]
+    fn test_auto_arbitrate_flag_follows_optional_proof() {
+        assert!(!SubmitWorkData::try_from_slice(&[]).unwrap().auto_arbitrate);
+        assert!(SubmitWorkData::try_from_slice(&[0, 1]).unwrap().auto_arbitrate);
+
+        let mut data = [7u8; 34];
+        data[0] = 1;
+        data[33] = 1;
+        let args = SubmitWorkData::try_from_slice(&data).unwrap();
+        assert_eq!(args.proof_hash, Some([7u8; 32]));
+        assert!(args.auto_arbitrate);
+        assert!(!SubmitWorkData::try_from_slice(&data[..33]).unwrap().auto_arbitrate);
+    }
+}
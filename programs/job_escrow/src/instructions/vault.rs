@@ -0,0 +1,124 @@
+//! Platform vault instructions
+//!
+//! Token fees can accrue in token accounts owned by the platform vault PDA
+//! (seeds ["platform_vault"]) and are withdrawn in batches by the platform.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    seeds,
+    ProgramResult,
+};
+use pinocchio_token::instructions::Transfer as TokenTransfer;
+
+use crate::{
+    errors::EscrowError,
+    require,
+};
+
+use super::fee_config::require_platform_authority;
+use super::helpers::{find_platform_vault, TokenAccountInfo, PLATFORM_VAULT_SEED, TOKEN_PROGRAM_ID};
+
+// ============== WITHDRAW PLATFORM VAULT ==============
+
+/// Withdraw platform vault accounts
+/// Accounts:
+/// 0. platform_authority (signer) - the platform authority, per the platform config
+/// 1. vault (PDA, readonly) - token authority of the vault token account
+/// 2. vault_token_account (writable)
+/// 3. destination_token_account (writable)
+/// 4. token_program
+/// 5. platform_config (readonly) - PDA ["platform_config"]
+pub struct WithdrawPlatformVaultAccounts<'a> {
+    pub platform_authority: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub vault_token_account: &'a AccountInfo,
+    pub destination_token_account: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub platform_config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for WithdrawPlatformVaultAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [platform_authority, vault, vault_token_account, destination_token_account, token_program, platform_config, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !platform_authority.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        if token_program.key() != &TOKEN_PROGRAM_ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+
+        Ok(Self {
+            platform_authority,
+            vault,
+            vault_token_account,
+            destination_token_account,
+            token_program,
+            platform_config,
+        })
+    }
+}
+
+/// Instruction data for WithdrawPlatformVault
+/// Layout: [amount: u64 (0 = full balance)]
+pub struct WithdrawPlatformVaultData {
+    pub amount: u64,
+}
+
+impl WithdrawPlatformVaultData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        Ok(Self { amount })
+    }
+}
+
+/// Process withdraw_platform_vault instruction
+pub fn process_withdraw_platform_vault(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = WithdrawPlatformVaultAccounts::try_from(accounts)?;
+    let args = WithdrawPlatformVaultData::try_from_slice(data)?;
+
+    require_platform_authority(ctx.platform_authority, ctx.platform_config, program_id)?;
+
+    // Verify vault PDA derivation
+    let (expected_vault, vault_bump) = find_platform_vault(program_id);
+    require!(ctx.vault.key() == &expected_vault, EscrowError::InvalidPda);
+
+    // Vault token account must be controlled by the vault PDA
+    let vault_token = TokenAccountInfo::from_account(ctx.vault_token_account)?;
+    require!(vault_token.owner == expected_vault, EscrowError::TokenAccountMismatch);
+
+    let amount = if args.amount == 0 { vault_token.amount } else { args.amount };
+    require!(amount <= vault_token.amount, EscrowError::InsufficientFunds);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let bump_ref = &[vault_bump];
+    let signer_seeds = seeds!(PLATFORM_VAULT_SEED, bump_ref);
+    let signer = Signer::from(&signer_seeds);
+
+    TokenTransfer {
+        from: ctx.vault_token_account,
+        to: ctx.destination_token_account,
+        authority: ctx.vault,
+        amount,
+    }
+    .invoke_signed(&[signer])?;
+
+    Ok(())
+}
@@ -0,0 +1,748 @@
+//! View instructions
+//!
+//! Read-only instructions for monitoring and client decisions. They modify no accounts; results are
+//! logged as events and also set as return data for simulated transactions.
+
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    program_error::ProgramError,
+    pubkey::{find_program_address, Pubkey},
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    errors::EscrowError,
+    events::{
+        AgentRole, ArbitratorEligibility, EscrowAudit, EscrowSnapshot, FeeQuote, PoolHealth, ProofVerified,
+        SelectableCount,
+    },
+    state::{
+        ArbitratorEntry, ArbitratorPool, DisputeCase, JobEscrow, Vote, ARBITRATORS_PER_DISPUTE,
+        DEFAULT_PLATFORM_FEE_BPS,
+    },
+    require,
+    ID,
+};
+
+use super::arbitrator::check_pool_extension;
+use super::fee_config::mint_fee;
+use super::helpers::{sha256, split_token_fee};
+
+// ============== GET POOL HEALTH ==============
+
+/// Get pool health accounts
+/// Accounts:
+/// 0. pool - the arbitrator pool PDA
+/// 1. pool_extension (optional) - adds the overflow segment to the totals
+pub struct GetPoolHealthAccounts<'a> {
+    pub pool: &'a AccountInfo,
+    pub pool_extension: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetPoolHealthAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { pool, pool_extension: rest.first() })
+    }
+}
+
+/// Process get_pool_health instruction
+///
+/// The pool only holds active arbitrators (unregistering removes them), so
+/// `arbitrator_count` is also the number available for selection.
+pub fn process_get_pool_health(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = GetPoolHealthAccounts::try_from(accounts)?;
+
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let pool_data = ctx.pool.try_borrow_data()?;
+    let pool = ArbitratorPool::load(&pool_data)?;
+
+    let mut arbitrator_count = pool.arbitrator_count;
+    let mut remaining_capacity = pool.remaining_capacity();
+    if let Some(extension) = ctx.pool_extension.filter(|_| pool.is_extended()) {
+        check_pool_extension(extension, program_id)?;
+        let extension_data = extension.try_borrow_data()?;
+        let extension_pool = ArbitratorPool::load(&extension_data)?;
+        arbitrator_count += extension_pool.arbitrator_count;
+        remaining_capacity += extension_pool.remaining_capacity();
+    }
+
+    let health = PoolHealth {
+        arbitrator_count,
+        remaining_capacity,
+        min_stake: pool.min_stake,
+        meets_dispute_minimum: arbitrator_count as usize >= ARBITRATORS_PER_DISPUTE,
+    };
+    health.emit();
+    set_return_data(&health.to_bytes());
+
+    Ok(())
+}
+
+// ============== EMIT ELIGIBILITY ==============
+
+/// Emit eligibility accounts
+/// Accounts:
+/// 0. dispute_case
+/// 1. arbitrator_account - the arbitrator's ArbitratorEntry
+pub struct EmitEligibilityAccounts<'a> {
+    pub dispute_case: &'a AccountInfo,
+    pub arbitrator_account: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EmitEligibilityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [dispute_case, arbitrator_account, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { dispute_case, arbitrator_account })
+    }
+}
+
+/// Work out whether `arb` can vote on `dispute` at `now`
+fn arbitrator_eligibility(
+    dispute_case: &Pubkey,
+    dispute: &DisputeCase,
+    arb: &ArbitratorEntry,
+    now: i64,
+) -> ArbitratorEligibility {
+    let position = dispute.find_arbitrator_position(&arb.agent);
+    ArbitratorEligibility {
+        dispute_case: *dispute_case,
+        arbitrator: arb.agent,
+        selected: position.is_some(),
+        active: arb.is_active(),
+        available: !dispute.is_resolved() && dispute.is_revealed() && dispute.is_voting_open(now),
+        has_voted: position.is_some_and(|i| dispute.votes[i] != Vote::None as u8),
+    }
+}
+
+/// Process emit_eligibility instruction
+///
+/// Lets an arbitrator client check in one call whether it should vote.
+pub fn process_emit_eligibility(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = EmitEligibilityAccounts::try_from(accounts)?;
+    let clock = Clock::get()?;
+
+    if *ctx.dispute_case.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    if *ctx.arbitrator_account.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let dispute_data = ctx.dispute_case.try_borrow_data()?;
+    let dispute = DisputeCase::load(&dispute_data)?;
+
+    let (expected_dispute_pda, expected_dispute_bump) = find_program_address(
+        &[b"dispute", &dispute.escrow],
+        program_id,
+    );
+    require!(ctx.dispute_case.key() == &expected_dispute_pda, EscrowError::InvalidPda);
+    require!(dispute.bump == expected_dispute_bump, EscrowError::InvalidPda);
+
+    let arb_data = ctx.arbitrator_account.try_borrow_data()?;
+    let arb = ArbitratorEntry::load(&arb_data)?;
+
+    let (expected_arb_pda, expected_arb_bump) = find_program_address(
+        &[b"arbitrator", &arb.agent],
+        program_id,
+    );
+    require!(ctx.arbitrator_account.key() == &expected_arb_pda, EscrowError::InvalidPda);
+    require!(arb.bump == expected_arb_bump, EscrowError::InvalidPda);
+
+    let eligibility = arbitrator_eligibility(ctx.dispute_case.key(), dispute, arb, clock.unix_timestamp);
+    eligibility.emit();
+    set_return_data(&eligibility.to_bytes());
+
+    Ok(())
+}
+
+// ============== EMIT SELECTABLE COUNT ==============
+
+/// Emit selectable count accounts
+/// Accounts:
+/// 0. pool - the arbitrator pool PDA
+/// 1. escrow (optional) - counts for this escrow's category, excluding its poster and worker
+pub struct EmitSelectableCountAccounts<'a> {
+    pub pool: &'a AccountInfo,
+    pub escrow: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EmitSelectableCountAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [pool, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { pool, escrow: rest.first() })
+    }
+}
+
+/// Process emit_selectable_count instruction
+///
+/// Unlike `arbitrator_count`, applies the same filters as dispute selection.
+/// Data: [category: u8] (optional, defaults to 0; ignored when an escrow is passed)
+pub fn process_emit_selectable_count(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = EmitSelectableCountAccounts::try_from(accounts)?;
+
+    if *ctx.pool.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let (expected_pool_pda, _) = find_program_address(&[b"arbitrator_pool_v2"], program_id);
+    require!(ctx.pool.key() == &expected_pool_pda, EscrowError::InvalidPda);
+
+    let pool_data = ctx.pool.try_borrow_data()?;
+    let pool = ArbitratorPool::load(&pool_data)?;
+
+    let (category, selectable) = match ctx.escrow {
+        Some(escrow_account) => {
+            if *escrow_account.owner() != ID {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            let escrow_data = escrow_account.try_borrow_data()?;
+            let escrow = JobEscrow::load(&escrow_data)?;
+            let (expected_escrow_pda, _) = find_program_address(
+                &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+                program_id,
+            );
+            require!(escrow_account.key() == &expected_escrow_pda, EscrowError::InvalidPda);
+            let excluded = [escrow.poster, escrow.worker];
+            (escrow.category, pool.selectable_count(escrow.category, &excluded))
+        }
+        None => {
+            let category = data.first().copied().unwrap_or(0);
+            (category, pool.selectable_count(category, &[]))
+        }
+    };
+
+    let count = SelectableCount {
+        category,
+        selectable,
+        can_raise_dispute: selectable as usize >= ARBITRATORS_PER_DISPUTE,
+    };
+    count.emit();
+    set_return_data(&count.to_bytes());
+
+    Ok(())
+}
+
+// ============== EMIT AUDIT ==============
+
+/// Emit audit accounts
+/// Accounts:
+/// 0. escrow
+/// 1. dispute_case (optional) - the escrow's dispute case, adds its timeline
+pub struct EmitAuditAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub dispute_case: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EmitAuditAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, rest @ ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { escrow, dispute_case: rest.first() })
+    }
+}
+
+/// Collect the timeline of `escrow`, including its dispute case if given
+fn escrow_audit(escrow_key: &Pubkey, escrow: &JobEscrow, dispute: Option<&DisputeCase>) -> EscrowAudit {
+    EscrowAudit {
+        escrow: *escrow_key,
+        status: escrow.status,
+        created_at: escrow.created_at,
+        expires_at: escrow.expires_at,
+        first_bid_at: escrow.first_bid_at,
+        worker: escrow.worker,
+        submitted_at: escrow.submitted_at,
+        review_cycles: escrow.review_cycles,
+        revision_count: escrow.revision_count,
+        dispute_initiated_at: escrow.dispute_initiated_at,
+        dispute_case: escrow.dispute_case,
+        dispute_raised_at: dispute.map_or(0, |d| d.created_at),
+        voting_deadline: dispute.map_or(0, |d| d.voting_deadline),
+        resolution: dispute.map_or(0, |d| d.resolution),
+    }
+}
+
+/// Process emit_audit instruction
+///
+/// Logs an escrow's whole timeline in one payload so support can
+/// reconstruct what happened from a single simulation.
+pub fn process_emit_audit(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = EmitAuditAccounts::try_from(accounts)?;
+
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    let audit = match ctx.dispute_case {
+        Some(dispute_case) => {
+            if *dispute_case.owner() != ID {
+                return Err(ProgramError::IncorrectProgramId);
+            }
+            require!(escrow.has_dispute_case != 0, EscrowError::InvalidPda);
+            require!(dispute_case.key() == &escrow.dispute_case, EscrowError::InvalidPda);
+            let dispute_data = dispute_case.try_borrow_data()?;
+            let dispute = DisputeCase::load(&dispute_data)?;
+            escrow_audit(ctx.escrow.key(), escrow, Some(dispute))
+        }
+        None => escrow_audit(ctx.escrow.key(), escrow, None),
+    };
+    audit.emit();
+    set_return_data(&audit.to_bytes());
+
+    Ok(())
+}
+
+// ============== QUOTE FEE ==============
+
+/// Quote fee accounts
+/// Accounts:
+/// 0. mint_fee_config (optional) - PDA ["mint_fee", mint]; only read when a mint is given
+pub struct QuoteFeeAccounts<'a> {
+    pub mint_fee_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for QuoteFeeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self { mint_fee_config: accounts.first() })
+    }
+}
+
+/// Instruction data for QuoteFee
+/// Layout: [amount: u64][mint: Pubkey (optional, for token escrows)]
+pub struct QuoteFeeData {
+    pub amount: u64,
+    pub mint: Option<Pubkey>,
+}
+
+impl QuoteFeeData {
+    pub fn try_from_slice(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < 8 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        let amount = u64::from_le_bytes(data[0..8].try_into().unwrap());
+        let mint = data.get(8..40).map(|bytes| bytes.try_into().unwrap());
+        Ok(Self { amount, mint })
+    }
+}
+
+/// Fee and net worker payment for releasing `amount` at `fee_bps`, with the
+/// fee raised to `min_fee` where it falls short
+///
+/// Uses the same split as the release instructions.
+fn fee_quote(amount: u64, fee_bps: u16, min_fee: u64) -> Result<FeeQuote, ProgramError> {
+    let (worker_payment, platform_fee) = split_token_fee(amount, fee_bps, min_fee)?;
+    Ok(FeeQuote { amount, fee_bps, platform_fee, worker_payment })
+}
+
+/// Process quote_fee instruction
+///
+/// Previews the platform fee a release would charge: the default 1%, or the
+/// mint's override when a mint (and its fee config, if any) is passed.
+pub fn process_quote_fee(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = QuoteFeeAccounts::try_from(accounts)?;
+    let args = QuoteFeeData::try_from_slice(data)?;
+
+    let (fee_bps, min_fee) = match args.mint {
+        Some(mint) => mint_fee(ctx.mint_fee_config, &mint, program_id)?,
+        None => (DEFAULT_PLATFORM_FEE_BPS, 0),
+    };
+
+    let quote = fee_quote(args.amount, fee_bps, min_fee)?;
+    quote.emit();
+    set_return_data(&quote.to_bytes());
+
+    Ok(())
+}
+
+// ============== GET MY ROLE ==============
+
+/// Get my role accounts
+/// Accounts:
+/// 0. escrow
+/// 1. agent (signer)
+pub struct GetMyRoleAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+    pub agent: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetMyRoleAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, agent, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        if !agent.is_signer() {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+
+        Ok(Self { escrow, agent })
+    }
+}
+
+/// Process get_my_role instruction
+///
+/// Logs whether the signer is the escrow's poster, worker or neither, so
+/// clients can gate actions without decoding the escrow account.
+pub fn process_get_my_role(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = GetMyRoleAccounts::try_from(accounts)?;
+
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    let role = AgentRole {
+        escrow: *ctx.escrow.key(),
+        agent: *ctx.agent.key(),
+        role: escrow.role_of(ctx.agent.key()) as u8,
+    };
+    role.emit();
+    set_return_data(&role.to_bytes());
+
+    Ok(())
+}
+
+// ============== GET ESCROW ==============
+
+/// Get escrow accounts
+/// Accounts:
+/// 0. escrow
+pub struct GetEscrowAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for GetEscrowAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { escrow })
+    }
+}
+
+/// Pack the versioned subset of `escrow` returned by get_escrow
+fn escrow_snapshot(escrow: &JobEscrow) -> EscrowSnapshot {
+    EscrowSnapshot {
+        status: escrow.status,
+        is_token: escrow.is_token_escrow != 0,
+        amount: escrow.amount,
+        poster: escrow.poster,
+        worker: escrow.worker,
+        created_at: escrow.created_at,
+        expires_at: escrow.expires_at,
+        submitted_at: escrow.submitted_at,
+    }
+}
+
+/// Process get_escrow instruction
+///
+/// Returns an EscrowSnapshot (layout documented there) so integrators can
+/// read an escrow from a simulation without decoding the raw account.
+pub fn process_get_escrow(
+    accounts: &[AccountInfo],
+    _data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = GetEscrowAccounts::try_from(accounts)?;
+
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    let snapshot = escrow_snapshot(escrow);
+    snapshot.emit();
+    set_return_data(&snapshot.to_bytes());
+
+    Ok(())
+}
+
+// ============== VERIFY PROOF ==============
+
+/// Verify proof accounts
+/// Accounts:
+/// 0. escrow
+pub struct VerifyProofAccounts<'a> {
+    pub escrow: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for VerifyProofAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [escrow, ..] = accounts else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        Ok(Self { escrow })
+    }
+}
+
+/// Check `preimage` against the proof hash the worker committed to `escrow`
+fn proof_verification(escrow_key: &Pubkey, escrow: &JobEscrow, preimage: &[u8]) -> ProofVerified {
+    let has_proof_hash = escrow.has_proof_hash != 0;
+    ProofVerified {
+        escrow: *escrow_key,
+        has_proof_hash,
+        matches: has_proof_hash && sha256(preimage) == escrow.proof_hash,
+    }
+}
+
+/// Process verify_proof instruction
+///
+/// Lets the poster confirm during review that the worker's off-chain
+/// deliverable matches the committed proof_hash before approving.
+/// Data: the preimage bytes (the whole instruction data)
+pub fn process_verify_proof(
+    accounts: &[AccountInfo],
+    data: &[u8],
+    program_id: &Pubkey,
+) -> ProgramResult {
+    let ctx = VerifyProofAccounts::try_from(accounts)?;
+
+    if *ctx.escrow.owner() != ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let escrow_data = ctx.escrow.try_borrow_data()?;
+    let escrow = JobEscrow::load(&escrow_data)?;
+
+    let (expected_pda, expected_bump) = find_program_address(
+        &[b"escrow", &escrow.job_id_hash, &escrow.poster],
+        program_id,
+    );
+    require!(ctx.escrow.key() == &expected_pda, EscrowError::InvalidPda);
+    require!(escrow.bump == expected_bump, EscrowError::InvalidPda);
+
+    let verified = proof_verification(ctx.escrow.key(), escrow, data);
+    verified.emit();
+    set_return_data(&verified.to_bytes());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MAX_PANEL_SIZE;
+
+    #[test]
+    fn test_fee_quote_matches_release_split() {
+        let quote = fee_quote(1_000_050, DEFAULT_PLATFORM_FEE_BPS, 0).unwrap();
+        assert_eq!(quote.platform_fee, 10_000);
+        assert_eq!(quote.worker_payment, 990_050);
+
+        // A 2.5% mint override
+        let quote = fee_quote(1_000_000, 250, 0).unwrap();
+        assert_eq!(quote.platform_fee, 25_000);
+        assert_eq!(quote.worker_payment, 975_000);
+        assert_eq!(&quote.to_bytes()[..8], b"FeeQuote");
+
+        // A mint's fee floor lifts the fee on small releases
+        let quote = fee_quote(5_000, DEFAULT_PLATFORM_FEE_BPS, 200).unwrap();
+        assert_eq!((quote.platform_fee, quote.worker_payment), (200, 4_800));
+
+        let args = QuoteFeeData::try_from_slice(&1_000u64.to_le_bytes()).unwrap();
+        assert_eq!(args.amount, 1_000);
+        assert!(args.mint.is_none());
+    }
+
+    #[test]
+    fn test_selected_arbitrator_that_already_voted() {
+        let mut dispute_data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut dispute_data).unwrap();
+        let mut arbitrators = [[0u8; 32]; MAX_PANEL_SIZE];
+        arbitrators[2] = [5u8; 32];
+        dispute.reveal_arbitrators(arbitrators, 1_000).unwrap();
+        dispute.set_vote(2, Vote::ForPoster);
+
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        arb.agent = [5u8; 32];
+        arb.is_active = 1;
+
+        let eligibility = arbitrator_eligibility(&[9u8; 32], dispute, arb, 500);
+        assert!(eligibility.selected);
+        assert!(eligibility.active);
+        assert!(eligibility.available);
+        assert!(eligibility.has_voted);
+        assert!(!eligibility.can_vote());
+        assert_eq!(eligibility.to_bytes()[72..77], [1, 1, 1, 1, 0]);
+    }
+
+    #[test]
+    fn test_audit_covers_submission_and_dispute() {
+        use crate::state::EscrowStatus;
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut escrow_data).unwrap();
+        escrow.created_at = 100;
+        escrow.expires_at = 10_000;
+        escrow.first_bid_at = 150;
+        escrow.worker = [2u8; 32];
+        escrow.submitted_at = 400;
+        escrow.review_cycles = 2;
+        escrow.revision_count = 1;
+        escrow.status = EscrowStatus::InArbitration as u8;
+        escrow.dispute_case = [3u8; 32];
+        escrow.has_dispute_case = 1;
+
+        let mut dispute_data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut dispute_data).unwrap();
+        dispute.created_at = 500;
+        dispute.voting_deadline = 500 + 48 * 60 * 60;
+
+        let audit = escrow_audit(&[1u8; 32], escrow, Some(dispute));
+        assert_eq!(audit.status, EscrowStatus::InArbitration as u8);
+        assert_eq!((audit.created_at, audit.expires_at, audit.first_bid_at), (100, 10_000, 150));
+        assert_eq!(audit.worker, [2u8; 32]);
+        assert_eq!((audit.submitted_at, audit.review_cycles, audit.revision_count), (400, 2, 1));
+        assert_eq!(audit.dispute_case, [3u8; 32]);
+        assert_eq!((audit.dispute_raised_at, audit.voting_deadline), (500, 500 + 48 * 60 * 60));
+
+        let bytes = audit.to_bytes();
+        assert_eq!(&bytes[..8], b"EscAudit");
+        assert_eq!(bytes[40], EscrowStatus::InArbitration as u8);
+        assert_eq!(bytes[41..49], 100i64.to_le_bytes());
+        assert_eq!(bytes[65..97], [2u8; 32]);
+        assert_eq!(bytes[97..105], 400i64.to_le_bytes());
+        assert_eq!(bytes[147..155], 500i64.to_le_bytes());
+
+        // Without the case, its fields stay zeroed
+        let audit = escrow_audit(&[1u8; 32], escrow, None);
+        assert_eq!((audit.dispute_raised_at, audit.voting_deadline), (0, 0));
+    }
+
+    #[test]
+    fn test_escrow_snapshot_decodes() {
+        use crate::state::EscrowStatus;
+
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut escrow_data).unwrap();
+        escrow.status = EscrowStatus::PendingReview as u8;
+        escrow.is_token_escrow = 1;
+        escrow.amount = 25_000_000;
+        escrow.poster = [1u8; 32];
+        escrow.worker = [2u8; 32];
+        escrow.created_at = 100;
+        escrow.expires_at = 10_000;
+        escrow.submitted_at = 400;
+
+        let bytes = escrow_snapshot(escrow).to_bytes();
+        assert_eq!(bytes.len(), EscrowSnapshot::LEN);
+        assert_eq!(&bytes[..8], b"EscState");
+        assert_eq!(bytes[8], EscrowSnapshot::VERSION);
+        assert_eq!(bytes[9], EscrowStatus::PendingReview as u8);
+        assert_eq!(bytes[10], 1);
+        assert_eq!(u64::from_le_bytes(bytes[11..19].try_into().unwrap()), 25_000_000);
+        assert_eq!(bytes[19..51], [1u8; 32]);
+        assert_eq!(bytes[51..83], [2u8; 32]);
+        assert_eq!(i64::from_le_bytes(bytes[83..91].try_into().unwrap()), 100);
+        assert_eq!(i64::from_le_bytes(bytes[91..99].try_into().unwrap()), 10_000);
+        assert_eq!(i64::from_le_bytes(bytes[99..107].try_into().unwrap()), 400);
+    }
+
+    #[test]
+    fn test_verify_proof_against_committed_hash() {
+        let mut escrow_data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut escrow_data).unwrap();
+
+        // Nothing committed: never a match, even for an empty preimage
+        let verified = proof_verification(&[1u8; 32], escrow, b"");
+        assert!(!verified.has_proof_hash && !verified.matches);
+
+        escrow.proof_hash = sha256(b"deliverable-v1");
+        escrow.has_proof_hash = 1;
+        assert!(proof_verification(&[1u8; 32], escrow, b"deliverable-v1").matches);
+        let verified = proof_verification(&[1u8; 32], escrow, b"deliverable-v2");
+        assert!(verified.has_proof_hash && !verified.matches);
+        assert_eq!(&verified.to_bytes()[..8], b"PrfVerif");
+        assert_eq!(verified.to_bytes()[40..], [1, 0]);
+    }
+}
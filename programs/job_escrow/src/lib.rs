@@ -20,6 +20,7 @@ use pinocchio::{
 };
 
 pub mod errors;
+pub mod events;
 pub mod state;
 pub mod instructions;
 
@@ -104,6 +105,103 @@ pub fn process_instruction(
         25 => process_create_token_escrow(accounts, data, program_id),
         26 => process_release_tokens_to_worker(accounts, data, program_id),
         
+        // Arbitrator category operations
+        27 => process_set_arbitrator_categories(accounts, data, program_id),
+        
+        // Platform vault operations
+        28 => process_withdraw_platform_vault(accounts, data, program_id),
+        
+        // Worker abandonment
+        29 => process_abandon_assignment(accounts, data, program_id),
+        
+        // Arbitrator wallet migration
+        30 => process_migrate_arbitrator(accounts, data, program_id),
+        
+        // Platform-sponsored reputation
+        31 => process_sponsor_reputation(accounts, data, program_id),
+        
+        // Emergency operations
+        32 => process_emergency_refund(accounts, data, program_id),
+        
+        // Dispute voting extension
+        33 => process_extend_voting(accounts, data, program_id),
+        
+        // Vote delegation
+        34 => process_delegate_vote(accounts, data, program_id),
+        
+        // Read-only views
+        35 => process_get_pool_health(accounts, data, program_id),
+        38 => process_emit_eligibility(accounts, data, program_id),
+        
+        // Bilateral (worker-bonded) escrows
+        36 => process_create_bilateral_escrow(accounts, data, program_id),
+        37 => process_post_worker_deposit(accounts, data, program_id),
+        
+        // Per-mint fee configuration
+        39 => process_set_mint_fee(accounts, data, program_id),
+        
+        // Platform configuration
+        40 => process_set_platform_config(accounts, data, program_id),
+        
+        // Keeper crank for expired reviews
+        41 => process_poke_review(accounts, data, program_id),
+        
+        // SPL Token refunds (refund_to_poster layout variant) and dispute payouts
+        42 => process_refund_tokens_to_poster(accounts, data, program_id),
+        43 => process_execute_token_dispute_resolution(accounts, data, program_id),
+        
+        // Dispute job identity
+        44 => process_record_job_id(accounts, data, program_id),
+        
+        // SPL Token expiry (claim_expired with token accounts required)
+        45 => process_claim_tokens_expired(accounts, data, program_id),
+        
+        // SPL Token milestones
+        46 => process_set_milestones(accounts, data, program_id),
+        47 => process_release_token_milestone(accounts, data, program_id),
+        
+        // Bidding
+        48 => process_place_bid(accounts, data, program_id),
+        
+        // Arbitrator vote fees
+        49 => process_claim_vote_fee(accounts, data, program_id),
+        
+        // Fee and pool previews
+        50 => process_quote_fee(accounts, data, program_id),
+        51 => process_emit_selectable_count(accounts, data, program_id),
+        52 => process_close_platform_config(accounts, data, program_id),
+        53 => process_penalize_absent_arbitrator(accounts, data, program_id),
+        54 => process_decline_assignment(accounts, data, program_id),
+        55 => process_request_revision(accounts, data, program_id),
+        56 => process_reveal_dispute_arbitrators(accounts, data, program_id),
+        57 => process_emit_audit(accounts, data, program_id),
+        58 => process_release_milestone(accounts, data, program_id),
+        59 => process_unassign_worker(accounts, data, program_id),
+        60 => process_can_finalize(accounts, data, program_id),
+        61 => process_top_up_escrow(accounts, data, program_id),
+        62 => process_extend_expiry(accounts, data, program_id),
+        63 => process_withdraw_excess_stake(accounts, data, program_id),
+        64 => process_mutual_cancel(accounts, data, program_id),
+        65 => process_claim_missed_deadline(accounts, data, program_id),
+        66 => process_claim_abandoned_dispute(accounts, data, program_id),
+        67 => process_decay_reputation(accounts, data, program_id),
+        68 => process_close_accuracy_claim(accounts, data, program_id),
+        69 => process_deactivate_low_accuracy(accounts, data, program_id),
+        70 => process_commit_vote(accounts, data, program_id),
+        71 => process_reveal_vote(accounts, data, program_id),
+        72 => process_appeal_dispute(accounts, data, program_id),
+        73 => process_settle_appeal_bond(accounts, data, program_id),
+        74 => process_recuse_arbitrator(accounts, data, program_id),
+        75 => process_get_my_role(accounts, data, program_id),
+        76 => process_init_arbitrator_pool_extension(accounts, data, program_id),
+        77 => process_get_escrow(accounts, data, program_id),
+        78 => process_verify_proof(accounts, data, program_id),
+        79 => process_set_paused(accounts, data, program_id),
+        80 => process_set_authority(accounts, data, program_id),
+        81 => process_accept_authority(accounts, data, program_id),
+        82 => process_init_platform_config(accounts, data, program_id),
+        83 => process_set_fee_bps(accounts, data, program_id),
+        
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -121,10 +219,10 @@ mod tests {
     #[test]
     fn test_account_sizes() {
         // Verify account sizes are reasonable
-        assert!(JobEscrow::SPACE < 500);
+        assert!(JobEscrow::SPACE < 600);
         assert!(AgentReputation::SPACE < 200);
         assert!(ArbitratorEntry::SPACE < 100);
-        assert!(DisputeCase::SPACE < 1000);
+        assert!(DisputeCase::SPACE < 2000);
         // ArbitratorPool is large due to fixed array
         assert!(ArbitratorPool::SPACE > 3000);
     }
@@ -1,23 +1,75 @@
 //! Arbitrator-related account states
 //!
-//! Includes ArbitratorPool, ArbitratorEntry, and AccuracyClaim.
+//! Includes ArbitratorPool, ArbitratorEntry, AccuracyClaim, VoteFeeClaim,
+//! and AbsenceRecord.
 
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 use core::mem::size_of;
-use crate::errors::EscrowError;
+use crate::{errors::EscrowError, require_some};
+use super::CLOSED_ACCOUNT_DISCRIMINATOR;
+use super::dispute::{ARBITRATORS_PER_DISPUTE, MAX_PANEL_SIZE};
 
-/// Maximum number of arbitrators in the pool
+/// Maximum number of arbitrators in one pool segment
 pub const MAX_ARBITRATORS: usize = 100;
 
+/// Pool segments: the main pool plus its overflow extension
+pub const MAX_POOL_SEGMENTS: usize = 2;
+
 /// Minimum stake required to become an arbitrator (0.1 SOL)
 pub const MIN_ARBITRATOR_STAKE: u64 = 100_000_000;
 
-/// Fee per vote for arbitrators (0.001 SOL)  
+/// Minimum fee per vote for arbitrators (0.001 SOL)
 pub const ARBITRATOR_VOTE_FEE: u64 = 1_000_000;
 
+/// Largest fee per vote, however much is disputed (0.01 SOL)
+pub const MAX_ARBITRATOR_VOTE_FEE: u64 = 10_000_000;
+
+/// Fee per vote as a share of the disputed amount (0.1%), within the bounds above
+pub const ARBITRATOR_VOTE_FEE_BPS: u64 = 10;
+
+/// Stake slashed for a wrong vote, as a share of the disputed amount (10%)
+pub const ARBITRATOR_SLASH_BPS: u64 = 1_000;
+
+/// Stake kept locked for each voted case not yet judged (0.05 SOL)
+///
+/// Covers the slash a wrong vote may still incur, so withdrawing excess stake
+/// cannot leave a pending slash unfunded.
+pub const STAKE_RESERVE_PER_CASE: u64 = 50_000_000;
+
+/// Judged votes an arbitrator needs before low accuracy can remove them
+pub const MIN_CASES_FOR_EVAL: u64 = 10;
+
+/// Accuracy, in percent, an evaluated arbitrator must keep to stay in the pool
+pub const MIN_ACCURACY_PCT: u64 = 40;
+
+/// Category mask accepting every escrow category (default on registration)
+pub const ALL_CATEGORIES: u32 = u32::MAX;
+
+/// Accuracy assumed for arbitrators with no recorded votes (50%)
+pub const NEUTRAL_ACCURACY_BPS: u16 = 5_000;
+
+/// Selection weight every arbitrator gets regardless of accuracy
+///
+/// An arbitrator's weight is `SELECTION_BASE_WEIGHT + accuracy_bps`, so a
+/// perfect record (11_000) is 11x as likely to be picked first as one that
+/// was always wrong (1_000), and nobody drops out of selection entirely.
+pub const SELECTION_BASE_WEIGHT: u64 = 1_000;
+
+/// Highest vote fee reward multiplier, in basis points (1.5x)
+pub const MAX_REWARD_MULTIPLIER_BPS: u16 = 15_000;
+
+/// Bit for a single escrow category in an arbitrator's category mask
+#[inline(always)]
+pub fn category_bit(category: u8) -> u32 {
+    1u32 << (category as u32 % 32)
+}
+
 /// Global arbitrator pool
 ///
 /// Seeds: ["arbitrator_pool_v2"]
+///
+/// Once the main pool is full, new arbitrators go to an extension segment
+/// with the same layout. Seeds: ["arbitrator_pool_ext"]
 #[repr(C)]
 pub struct ArbitratorPool {
     /// Platform authority who can manage the pool
@@ -28,10 +80,17 @@ pub struct ArbitratorPool {
     pub arbitrator_count: u32,
     /// PDA bump seed
     pub bump: u8,
+    /// Whether the extension segment exists (main pool only); selection
+    /// must then draw from both segments
+    pub has_extension: u8,
     /// Padding for alignment
-    pub _padding: [u8; 3],
+    pub _padding: [u8; 2],
     /// Array of arbitrator pubkeys (fixed size)
     pub arbitrators: [Pubkey; MAX_ARBITRATORS],
+    /// Category masks, indexed in parallel with `arbitrators`
+    pub categories: [u32; MAX_ARBITRATORS],
+    /// Cached voting accuracy in basis points, indexed in parallel with `arbitrators`
+    pub accuracy_bps: [u16; MAX_ARBITRATORS],
 }
 
 impl ArbitratorPool {
@@ -39,7 +98,7 @@ impl ArbitratorPool {
     pub const DISCRIMINATOR: [u8; 8] = [0x41, 0x72, 0x62, 0x50, 0x6f, 0x6f, 0x6c, 0x5f]; // "ArbPool_"
     
     /// Size of the account data (without discriminator)
-    pub const LEN: usize = 32 + 8 + 4 + 1 + 3 + (32 * MAX_ARBITRATORS);
+    pub const LEN: usize = 32 + 8 + 4 + 1 + 3 + (32 * MAX_ARBITRATORS) + (4 * MAX_ARBITRATORS) + (2 * MAX_ARBITRATORS);
     
     /// Total size including 8-byte discriminator
     pub const SPACE: usize = 8 + Self::LEN;
@@ -52,6 +111,9 @@ impl ArbitratorPool {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -63,6 +125,9 @@ impl ArbitratorPool {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -74,6 +139,9 @@ impl ArbitratorPool {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
         }
@@ -104,8 +172,20 @@ impl ArbitratorPool {
         None
     }
 
+    /// Check if the pool has enough arbitrators to open a new dispute
+    #[inline(always)]
+    pub fn meets_dispute_minimum(&self) -> bool {
+        self.arbitrator_count as usize >= ARBITRATORS_PER_DISPUTE
+    }
+
+    /// Number of free arbitrator slots
+    #[inline(always)]
+    pub fn remaining_capacity(&self) -> u32 {
+        (MAX_ARBITRATORS as u32).saturating_sub(self.arbitrator_count)
+    }
+
     /// Add an arbitrator to the pool
-    pub fn add(&mut self, pubkey: Pubkey) -> Result<(), ProgramError> {
+    pub fn add(&mut self, pubkey: Pubkey, categories: u32) -> Result<(), ProgramError> {
         if self.arbitrator_count as usize >= MAX_ARBITRATORS {
             return Err(EscrowError::ArbitratorPoolFull.into());
         }
@@ -113,6 +193,8 @@ impl ArbitratorPool {
             return Err(EscrowError::AlreadyArbitrator.into());
         }
         self.arbitrators[self.arbitrator_count as usize] = pubkey;
+        self.categories[self.arbitrator_count as usize] = categories;
+        self.accuracy_bps[self.arbitrator_count as usize] = NEUTRAL_ACCURACY_BPS;
         self.arbitrator_count += 1;
         Ok(())
     }
@@ -124,14 +206,167 @@ impl ArbitratorPool {
             let last_idx = self.arbitrator_count as usize - 1;
             if idx != last_idx {
                 self.arbitrators[idx] = self.arbitrators[last_idx];
+                self.categories[idx] = self.categories[last_idx];
+                self.accuracy_bps[idx] = self.accuracy_bps[last_idx];
             }
             self.arbitrators[last_idx] = Self::DEFAULT_PUBKEY;
+            self.categories[last_idx] = 0;
+            self.accuracy_bps[last_idx] = 0;
             self.arbitrator_count -= 1;
             Ok(())
         } else {
             Err(EscrowError::NotSelectedArbitrator.into())
         }
     }
+
+    /// Refresh the cached accuracy of an arbitrator (no-op if they left the pool)
+    pub fn set_accuracy(&mut self, pubkey: &Pubkey, accuracy_bps: u16) {
+        if let Some(idx) = self.find_index(pubkey) {
+            self.accuracy_bps[idx] = accuracy_bps;
+        }
+    }
+
+    /// Selection weight of the arbitrator in pool slot `idx`
+    #[inline(always)]
+    fn selection_weight(&self, idx: usize) -> u64 {
+        SELECTION_BASE_WEIGHT + self.accuracy_bps[idx] as u64
+    }
+
+    /// Replace an arbitrator's key in place, keeping its slot and category mask
+    pub fn replace(&mut self, old: &Pubkey, new: Pubkey) -> Result<(), ProgramError> {
+        if self.contains(&new) {
+            return Err(EscrowError::AlreadyArbitrator.into());
+        }
+        let idx = require_some!(self.find_index(old), EscrowError::NotSelectedArbitrator);
+        self.arbitrators[idx] = new;
+        Ok(())
+    }
+
+    /// Update the category mask of an arbitrator already in the pool
+    pub fn set_categories(&mut self, pubkey: &Pubkey, categories: u32) -> Result<(), ProgramError> {
+        let idx = require_some!(self.find_index(pubkey), EscrowError::NotSelectedArbitrator);
+        self.categories[idx] = categories;
+        Ok(())
+    }
+
+    /// Check if the arbitrator in pool slot `idx` passes every selection filter
+    ///
+    /// Selection and the selectable-count view share this, so new filters
+    /// belong here.
+    #[inline(always)]
+    fn is_selectable(&self, idx: usize, category: u8, excluded: &[Pubkey]) -> bool {
+        self.categories[idx] & category_bit(category) != 0
+            && !excluded.contains(&self.arbitrators[idx])
+    }
+
+    /// Check if the extension segment exists
+    #[inline(always)]
+    pub fn is_extended(&self) -> bool {
+        self.has_extension != 0
+    }
+
+    /// Number of arbitrators `select_arbitrators` could pick from
+    pub fn selectable_count(&self, category: u8, excluded: &[Pubkey]) -> u32 {
+        (0..self.arbitrator_count as usize)
+            .filter(|&i| self.is_selectable(i, category, excluded))
+            .count() as u32
+    }
+
+    /// Select arbitrators for a dispute from this segment alone
+    ///
+    /// See `select_from_segments`.
+    pub fn select_arbitrators(
+        &self,
+        category: u8,
+        excluded: &[Pubkey],
+        seed: u64,
+        panel_size: usize,
+    ) -> Result<[Pubkey; MAX_PANEL_SIZE], ProgramError> {
+        select_from_segments(&[self], category, excluded, seed, panel_size)
+    }
+}
+
+/// Select arbitrators for a dispute in the given escrow category
+///
+/// Only arbitrators whose category mask includes `category` are eligible,
+/// and never anyone in `excluded` (the parties to the dispute).
+///
+/// Candidates are gathered from each segment in order. Each pick derives a
+/// target in `[0, total weight)` from `seed` and starts at the candidate
+/// whose cumulative weight range covers it (see SELECTION_BASE_WEIGHT), then
+/// linearly probes to avoid duplicates. The same seed and segments always
+/// give the same panel.
+pub fn select_from_segments(
+    segments: &[&ArbitratorPool],
+    category: u8,
+    excluded: &[Pubkey],
+    seed: u64,
+    panel_size: usize,
+) -> Result<[Pubkey; MAX_PANEL_SIZE], ProgramError> {
+    let panel_size = panel_size.min(MAX_PANEL_SIZE);
+    // Collect (segment, index) of eligible arbitrators
+    // and the running total of their weights
+    let mut candidates = [(0u8, 0u8); MAX_ARBITRATORS * MAX_POOL_SEGMENTS];
+    let mut cumulative_weights = [0u64; MAX_ARBITRATORS * MAX_POOL_SEGMENTS];
+    let mut total_weight = 0u64;
+    let mut candidate_count = 0usize;
+    let mut in_category = 0usize;
+    for (s, pool) in segments.iter().take(MAX_POOL_SEGMENTS).enumerate() {
+        for i in 0..(pool.arbitrator_count as usize).min(MAX_ARBITRATORS) {
+            if pool.categories[i] & category_bit(category) == 0 {
+                continue;
+            }
+            in_category += 1;
+            if pool.is_selectable(i, category, excluded) {
+                total_weight += pool.selection_weight(i);
+                candidates[candidate_count] = (s as u8, i as u8);
+                cumulative_weights[candidate_count] = total_weight;
+                candidate_count += 1;
+            }
+        }
+    }
+    if in_category < panel_size {
+        return Err(EscrowError::NoEligibleArbitratorsForCategory.into());
+    }
+    if candidate_count < panel_size {
+        return Err(EscrowError::NotEnoughArbitrators.into());
+    }
+
+    let mut selected: [Pubkey; MAX_PANEL_SIZE] = [[0u8; 32]; MAX_PANEL_SIZE];
+    let mut used_indices: [usize; MAX_PANEL_SIZE] = [usize::MAX; MAX_PANEL_SIZE];
+
+    for i in 0..panel_size {
+        let target = seed.wrapping_add(i as u64).wrapping_mul(31337) % total_weight;
+        let start = cumulative_weights[..candidate_count]
+            .iter()
+            .position(|&cumulative| target < cumulative)
+            .unwrap_or(0);
+        let idx = probe_unused(start, candidate_count, &used_indices)?;
+        used_indices[i] = idx;
+        let (segment, slot) = candidates[idx];
+        selected[i] = segments[segment as usize].arbitrators[slot as usize];
+    }
+
+    Ok(selected)
+}
+
+/// Linear probe from `start` for a candidate index not yet in `used`
+///
+/// Visits each of the `candidate_count` slots at most once, so it fails with
+/// NotEnoughArbitrators instead of spinning when every candidate is taken.
+fn probe_unused(
+    start: usize,
+    candidate_count: usize,
+    used: &[usize],
+) -> Result<usize, ProgramError> {
+    let mut idx = start;
+    for _ in 0..candidate_count {
+        if !used.contains(&idx) {
+            return Ok(idx);
+        }
+        idx = (idx + 1) % candidate_count;
+    }
+    Err(EscrowError::NotEnoughArbitrators.into())
 }
 
 /// Individual arbitrator entry
@@ -151,10 +386,16 @@ pub struct ArbitratorEntry {
     pub is_active: u8,
     /// Unix timestamp when registered
     pub registered_at: i64,
+    /// Bitmask of escrow categories this arbitrator will judge
+    pub categories: u32,
     /// PDA bump seed
     pub bump: u8,
     /// Padding for alignment
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 1],
+    /// Cases voted on whose vote has not yet been judged for accuracy
+    pub open_cases: u16,
+    /// Resolved cases this arbitrator was selected for but never voted on
+    pub cases_missed: u64,
 }
 
 impl ArbitratorEntry {
@@ -172,6 +413,9 @@ impl ArbitratorEntry {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -183,6 +427,9 @@ impl ArbitratorEntry {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -194,6 +441,9 @@ impl ArbitratorEntry {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
         }
@@ -207,6 +457,140 @@ impl ArbitratorEntry {
     pub fn is_active(&self) -> bool {
         self.is_active != 0
     }
+
+    /// Check if arbitrator has opted in to judge the given escrow category
+    #[inline(always)]
+    pub fn accepts_category(&self, category: u8) -> bool {
+        self.categories & category_bit(category) != 0
+    }
+
+    /// Slash for a wrong vote on a dispute over `disputed_amount`
+    ///
+    /// Proportional to the disputed amount so wrong votes on large disputes
+    /// cost more, capped at the remaining stake.
+    #[inline(always)]
+    pub fn slash_amount(&self, disputed_amount: u64) -> u64 {
+        let proportional = (disputed_amount as u128 * ARBITRATOR_SLASH_BPS as u128 / 10_000) as u64;
+        proportional.min(self.stake)
+    }
+
+    /// Deduct the slash for a wrong vote from the recorded stake
+    ///
+    /// Returns the lamports the caller must move out of the entry account,
+    /// at most `available` so the account stays rent exempt. Never takes the
+    /// stake below zero.
+    #[inline(always)]
+    pub fn apply_slash(&mut self, disputed_amount: u64, available: u64) -> u64 {
+        let slash = self.slash_amount(disputed_amount).min(available);
+        self.stake -= slash;
+        slash
+    }
+
+    /// Judge one recorded vote against the final outcome
+    ///
+    /// Initializes the accuracy claim in `claim_data` first, so a vote that
+    /// was already judged fails without touching the entry. Releases the
+    /// vote's stake reserve and returns the lamports slashed for a wrong vote.
+    pub fn judge_vote(
+        &mut self,
+        claim_data: &mut [u8],
+        voted_correctly: bool,
+        disputed_amount: u64,
+        available: u64,
+    ) -> Result<u64, ProgramError> {
+        AccuracyClaim::init(claim_data)?;
+
+        self.open_cases = self.open_cases.saturating_sub(1);
+
+        if voted_correctly {
+            // SECURITY FIX H-05: Use saturating arithmetic
+            self.cases_correct = self.cases_correct.saturating_add(1);
+            return Ok(0);
+        }
+        // Wrong votes lose stake in proportion to what was at stake
+        Ok(self.apply_slash(disputed_amount, available))
+    }
+
+    /// Cases voted on whose vote has been judged against the outcome
+    #[inline(always)]
+    pub fn cases_judged(&self) -> u64 {
+        self.cases_voted.saturating_sub(self.open_cases as u64)
+    }
+
+    /// Share of judged cases voted correctly, in basis points
+    ///
+    /// NEUTRAL_ACCURACY_BPS until a vote has been judged.
+    #[inline(always)]
+    pub fn accuracy_bps(&self) -> u16 {
+        let judged = self.cases_judged();
+        if judged == 0 {
+            return NEUTRAL_ACCURACY_BPS;
+        }
+        let correct = self.cases_correct.min(judged);
+        (correct as u128 * 10_000 / judged as u128) as u16
+    }
+
+    /// Check if the arbitrator has been judged often enough and voted with
+    /// the outcome less than MIN_ACCURACY_PCT of the time
+    #[inline(always)]
+    pub fn below_accuracy_threshold(&self) -> bool {
+        let judged = self.cases_judged();
+        if judged < MIN_CASES_FOR_EVAL {
+            return false;
+        }
+        let correct = self.cases_correct.min(judged);
+        correct.saturating_mul(100) / judged < MIN_ACCURACY_PCT
+    }
+
+    /// Vote fee reward multiplier in basis points
+    ///
+    /// 1x up to NEUTRAL_ACCURACY_BPS, rising linearly to
+    /// MAX_REWARD_MULTIPLIER_BPS for a perfect record.
+    #[inline(always)]
+    pub fn reward_multiplier_bps(&self) -> u16 {
+        let bonus = self.accuracy_bps().saturating_sub(NEUTRAL_ACCURACY_BPS);
+        (10_000 + bonus).min(MAX_REWARD_MULTIPLIER_BPS)
+    }
+
+    /// Stake that must stay locked: `min_stake` plus a reserve per open case
+    #[inline(always)]
+    pub fn locked_stake(&self, min_stake: u64) -> u64 {
+        min_stake.saturating_add(STAKE_RESERVE_PER_CASE.saturating_mul(self.open_cases as u64))
+    }
+
+    /// Stake that can be withdrawn while staying active
+    #[inline(always)]
+    pub fn excess_stake(&self, min_stake: u64) -> u64 {
+        self.stake.saturating_sub(self.locked_stake(min_stake))
+    }
+
+    /// Deduct a withdrawal of `amount` excess stake from the recorded stake
+    pub fn withdraw_excess_stake(&mut self, amount: u64, min_stake: u64) -> Result<(), ProgramError> {
+        if amount == 0 || amount > self.excess_stake(min_stake) {
+            return Err(EscrowError::InsufficientExcessStake.into());
+        }
+        self.stake -= amount;
+        Ok(())
+    }
+
+    /// Take everything but the open-case reserve out of the recorded stake,
+    /// up to `available` lamports, and return the amount taken
+    pub fn take_unlocked_stake(&mut self, available: u64) -> u64 {
+        let amount = core::cmp::min(self.excess_stake(0), available);
+        self.stake -= amount;
+        amount
+    }
+
+    /// Count a resolved case this arbitrator never voted on
+    ///
+    /// Initializes the absence record in `record_data` first, so an absence
+    /// that was already counted fails without touching the entry.
+    pub fn record_missed_case(&mut self, record_data: &mut [u8]) -> Result<(), ProgramError> {
+        AbsenceRecord::init(record_data)?;
+        // SECURITY FIX H-05: Use saturating arithmetic
+        self.cases_missed = self.cases_missed.saturating_add(1);
+        Ok(())
+    }
 }
 
 /// Tracks accuracy claims to prevent duplicate calls
@@ -224,6 +608,8 @@ pub struct AccuracyClaim {
     pub bump: u8,
     /// Padding for alignment
     pub _padding: [u8; 7],
+    /// Account that paid the rent; it gets the rent back when the claim is closed
+    pub rent_payer: Pubkey,
 }
 
 impl AccuracyClaim {
@@ -241,6 +627,9 @@ impl AccuracyClaim {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -252,6 +641,9 @@ impl AccuracyClaim {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -263,6 +655,9 @@ impl AccuracyClaim {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
         }
@@ -271,3 +666,471 @@ impl AccuracyClaim {
         Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
     }
 }
+
+/// Tracks vote fee claims to prevent duplicate payouts
+///
+/// Same layout as AccuracyClaim; only the seeds differ.
+/// Seeds: ["vote_fee_claim", dispute_case, arbitrator]
+pub type VoteFeeClaim = AccuracyClaim;
+
+/// Records that an arbitrator's missed vote on a case has been counted
+///
+/// Same layout as AccuracyClaim; only the seeds differ.
+/// Seeds: ["absence", dispute_case, arbitrator]
+pub type AbsenceRecord = AccuracyClaim;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::MIN_PANEL_SIZE;
+
+    fn pool_with(masks: &[u32]) -> std::vec::Vec<u8> {
+        let mut data = std::vec![0u8; ArbitratorPool::SPACE];
+        let pool = ArbitratorPool::init(&mut data).unwrap();
+        for (i, mask) in masks.iter().enumerate() {
+            pool.add([i as u8 + 1; 32], *mask).unwrap();
+        }
+        data
+    }
+
+    #[test]
+    fn test_withdraw_excess_stake_keeps_arbitrator_selectable() {
+        let mut data = pool_with(&[ALL_CATEGORIES; ARBITRATORS_PER_DISPUTE]);
+        let pool = ArbitratorPool::load_mut(&mut data).unwrap();
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        arb.agent = [1u8; 32];
+        arb.is_active = 1;
+        arb.stake = MIN_ARBITRATOR_STAKE * 2;
+        arb.open_cases = 1;
+
+        // One open case keeps a reserve locked on top of the minimum
+        let excess = MIN_ARBITRATOR_STAKE - STAKE_RESERVE_PER_CASE;
+        assert_eq!(arb.excess_stake(MIN_ARBITRATOR_STAKE), excess);
+        assert!(arb.withdraw_excess_stake(excess + 1, MIN_ARBITRATOR_STAKE).is_err());
+        assert!(arb.withdraw_excess_stake(0, MIN_ARBITRATOR_STAKE).is_err());
+
+        arb.withdraw_excess_stake(excess, MIN_ARBITRATOR_STAKE).unwrap();
+        assert_eq!(arb.stake, MIN_ARBITRATOR_STAKE + STAKE_RESERVE_PER_CASE);
+        assert_eq!(arb.excess_stake(MIN_ARBITRATOR_STAKE), 0);
+
+        // Still active and in the pool
+        assert!(arb.is_active());
+        assert!(pool.contains(&arb.agent));
+        assert_eq!(pool.selectable_count(0, &[]), ARBITRATORS_PER_DISPUTE as u32);
+
+        // Judging the case releases its reserve
+        arb.open_cases = 0;
+        assert_eq!(arb.excess_stake(MIN_ARBITRATOR_STAKE), STAKE_RESERVE_PER_CASE);
+    }
+
+    #[test]
+    fn test_leaving_keeps_open_case_reserve_locked() {
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        arb.stake = MIN_ARBITRATOR_STAKE;
+        arb.open_cases = 2;
+
+        // Only the stake above the reserve leaves, and it leaves the record too
+        let reserve = STAKE_RESERVE_PER_CASE * 2;
+        assert_eq!(arb.take_unlocked_stake(u64::MAX), MIN_ARBITRATOR_STAKE - reserve);
+        assert_eq!(arb.stake, reserve);
+        assert_eq!(arb.take_unlocked_stake(u64::MAX), 0);
+
+        // Capped by what the account actually holds
+        arb.open_cases = 0;
+        assert_eq!(arb.take_unlocked_stake(1), 1);
+        assert_eq!(arb.stake, reserve - 1);
+    }
+
+    #[test]
+    fn test_remove_keeps_categories_in_sync() {
+        let mut data = pool_with(&[0b01, 0b10, 0b100]);
+        let pool = ArbitratorPool::load_mut(&mut data).unwrap();
+        pool.remove(&[1u8; 32]).unwrap();
+        // Last entry was swapped into slot 0 along with its mask
+        assert_eq!(pool.arbitrators[0], [3u8; 32]);
+        assert_eq!(pool.categories[0], 0b100);
+        assert_eq!(pool.categories[2], 0);
+    }
+
+    #[test]
+    fn test_pool_health() {
+        let data = pool_with(&[ALL_CATEGORIES; 4]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        assert!(!pool.meets_dispute_minimum());
+        assert_eq!(pool.remaining_capacity(), MAX_ARBITRATORS as u32 - 4);
+
+        let data = pool_with(&[ALL_CATEGORIES; ARBITRATORS_PER_DISPUTE]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        assert!(pool.meets_dispute_minimum());
+    }
+
+    #[test]
+    fn test_replace_keeps_slot_and_categories() {
+        let mut data = pool_with(&[0b01, 0b10]);
+        let pool = ArbitratorPool::load_mut(&mut data).unwrap();
+        pool.replace(&[1u8; 32], [9u8; 32]).unwrap();
+        assert_eq!(pool.arbitrators[0], [9u8; 32]);
+        assert_eq!(pool.categories[0], 0b01);
+        assert_eq!(pool.arbitrator_count, 2);
+        assert!(!pool.contains(&[1u8; 32]));
+
+        // Cannot migrate onto a key that is already registered
+        assert_eq!(
+            pool.replace(&[9u8; 32], [2u8; 32]),
+            Err(ProgramError::from(EscrowError::AlreadyArbitrator))
+        );
+    }
+
+    #[test]
+    fn test_selection_respects_category() {
+        let mut masks = [ALL_CATEGORIES; 8];
+        masks[1] = category_bit(0);
+        masks[4] = category_bit(0);
+        let data = pool_with(&masks);
+        let pool = ArbitratorPool::load(&data).unwrap();
+
+        for seed in 0..50u64 {
+            let selected = pool.select_arbitrators(3, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            assert!(!selected.contains(&[2u8; 32]));
+            assert!(!selected.contains(&[5u8; 32]));
+        }
+    }
+
+    #[test]
+    fn test_selection_requires_enough_eligible() {
+        let data = pool_with(&[category_bit(1), category_bit(1), ALL_CATEGORIES, ALL_CATEGORIES, ALL_CATEGORIES, ALL_CATEGORIES]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        assert!(pool.select_arbitrators(1, &[], 7, ARBITRATORS_PER_DISPUTE).is_ok());
+        assert_eq!(
+            pool.select_arbitrators(2, &[], 7, ARBITRATORS_PER_DISPUTE).unwrap_err(),
+            ProgramError::from(EscrowError::NoEligibleArbitratorsForCategory)
+        );
+    }
+
+    #[test]
+    fn test_slash_scales_with_dispute_value() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+        arb.stake = MIN_ARBITRATOR_STAKE;
+
+        assert_eq!(arb.slash_amount(0), 0);
+        assert_eq!(arb.slash_amount(10_000_000), 1_000_000);
+        assert_eq!(arb.slash_amount(500_000_000), 50_000_000);
+        // Capped at the stake
+        assert_eq!(arb.slash_amount(5_000_000_000), MIN_ARBITRATOR_STAKE);
+        assert_eq!(arb.slash_amount(u64::MAX), MIN_ARBITRATOR_STAKE);
+    }
+
+    #[test]
+    fn test_selection_probing_is_bounded() {
+        // Only 5 of 40 arbitrators are eligible: every pick must be distinct
+        let mut masks = [category_bit(1); 40];
+        for i in [3, 11, 19, 27, 39] {
+            masks[i] = ALL_CATEGORIES;
+        }
+        let data = pool_with(&masks);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        for seed in 0..50u64 {
+            let selected = pool.select_arbitrators(0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            let selected = &selected[..ARBITRATORS_PER_DISPUTE];
+            for (i, a) in selected.iter().enumerate() {
+                assert!(!selected[i + 1..].contains(a));
+            }
+        }
+
+        // With every candidate already taken the probe gives up
+        assert_eq!(probe_unused(2, 3, &[0, 1, 2]), Err(ProgramError::from(EscrowError::NotEnoughArbitrators)));
+        assert_eq!(probe_unused(2, 3, &[0, 2, usize::MAX]), Ok(1));
+    }
+
+    #[test]
+    fn test_selection_excludes_dispute_parties() {
+        // The poster (agent 3) is also a registered arbitrator
+        let poster = [3u8; 32];
+        let worker = [42u8; 32];
+        let data = pool_with(&[ALL_CATEGORIES; 7]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        for seed in 0..100u64 {
+            let selected = pool.select_arbitrators(0, &[poster, worker], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            assert!(!selected.contains(&poster));
+        }
+
+        // Excluding both parties leaves only 4 of 5 eligible arbitrators
+        let data = pool_with(&[ALL_CATEGORIES; 6]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        assert_eq!(
+            pool.select_arbitrators(0, &[poster, [1u8; 32]], 7, ARBITRATORS_PER_DISPUTE).unwrap_err(),
+            ProgramError::from(EscrowError::NotEnoughArbitrators)
+        );
+    }
+
+    #[test]
+    fn test_excluded_arbitrator_never_selected() {
+        let poster = [42u8; 32];
+        let worker = [43u8; 32];
+        let biased = [4u8; 32];
+        let data = pool_with(&[ALL_CATEGORIES; 8]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        for seed in 0..200u64 {
+            let selected = pool.select_arbitrators(0, &[poster, worker, biased], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            assert!(!selected.contains(&biased));
+        }
+    }
+
+    #[test]
+    fn test_selectable_count_applies_filters() {
+        let poster = [2u8; 32];
+        let mut masks = [ALL_CATEGORIES; 7];
+        masks[4] = category_bit(1);
+        masks[5] = category_bit(1);
+        let data = pool_with(&masks);
+        let pool = ArbitratorPool::load(&data).unwrap();
+
+        assert_eq!(pool.arbitrator_count, 7);
+        assert_eq!(pool.selectable_count(1, &[]), 7);
+        // Two arbitrators do not take category 0, and the poster is a party
+        assert_eq!(pool.selectable_count(0, &[]), 5);
+        assert_eq!(pool.selectable_count(0, &[poster]), 4);
+        assert!(pool.select_arbitrators(0, &[poster], 7, ARBITRATORS_PER_DISPUTE).is_err());
+    }
+
+    #[test]
+    fn test_wrong_vote_slashes_stake_once() {
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        arb.stake = MIN_ARBITRATOR_STAKE;
+        arb.open_cases = 1;
+        let mut claim_data = std::vec![0u8; AccuracyClaim::SPACE];
+
+        // First judgement creates the claim and slashes
+        assert_eq!(arb.judge_vote(&mut claim_data, false, 200_000_000, u64::MAX), Ok(20_000_000));
+        assert_eq!(arb.stake, MIN_ARBITRATOR_STAKE - 20_000_000);
+        assert_eq!(arb.open_cases, 0);
+
+        // Judging the same vote again is rejected and leaves the entry alone
+        arb.open_cases = 1;
+        assert_eq!(
+            arb.judge_vote(&mut claim_data, false, 200_000_000, u64::MAX),
+            Err(ProgramError::from(EscrowError::AccountAlreadyInitialized))
+        );
+        assert_eq!(arb.stake, MIN_ARBITRATOR_STAKE - 20_000_000);
+        assert_eq!(arb.open_cases, 1);
+
+        // A correct vote is never slashed
+        let mut claim_data = std::vec![0u8; AccuracyClaim::SPACE];
+        assert_eq!(arb.judge_vote(&mut claim_data, true, 200_000_000, u64::MAX), Ok(0));
+        assert_eq!(arb.cases_correct, 1);
+        assert_eq!(arb.stake, MIN_ARBITRATOR_STAKE - 20_000_000);
+    }
+
+    #[test]
+    fn test_slash_never_dips_into_rent() {
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        arb.stake = MIN_ARBITRATOR_STAKE;
+
+        // Capped by the lamports above the rent-exempt minimum
+        assert_eq!(arb.apply_slash(200_000_000, 5_000_000), 5_000_000);
+        assert_eq!(arb.stake, MIN_ARBITRATOR_STAKE - 5_000_000);
+        assert_eq!(arb.apply_slash(200_000_000, 0), 0);
+
+        // Never below zero
+        assert_eq!(arb.apply_slash(u64::MAX, u64::MAX), MIN_ARBITRATOR_STAKE - 5_000_000);
+        assert_eq!(arb.stake, 0);
+    }
+
+    #[test]
+    fn test_missed_case_counted_once_per_absence_record() {
+        let mut arb_data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut arb_data).unwrap();
+        let mut record_data = std::vec![0u8; AbsenceRecord::SPACE];
+
+        arb.record_missed_case(&mut record_data).unwrap();
+        assert_eq!(arb.cases_missed, 1);
+
+        // A second penalty for the same absence is rejected and not counted
+        assert_eq!(
+            arb.record_missed_case(&mut record_data).err(),
+            Some(ProgramError::from(EscrowError::AccountAlreadyInitialized))
+        );
+        assert_eq!(arb.cases_missed, 1);
+
+        // A different case's absence still counts
+        let mut other_record = std::vec![0u8; AbsenceRecord::SPACE];
+        arb.record_missed_case(&mut other_record).unwrap();
+        assert_eq!(arb.cases_missed, 2);
+    }
+
+    #[test]
+    fn test_selection_fills_requested_panel() {
+        let data = pool_with(&[ALL_CATEGORIES; 10]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        for panel_size in [MIN_PANEL_SIZE, ARBITRATORS_PER_DISPUTE, MAX_PANEL_SIZE] {
+            let selected = pool.select_arbitrators(0, &[], 11, panel_size).unwrap();
+            assert!(selected[..panel_size].iter().all(|a| *a != [0u8; 32]));
+            assert!(selected[panel_size..].iter().all(|a| *a == [0u8; 32]));
+        }
+
+        // Six eligible arbitrators seat a panel of five but not of seven
+        let data = pool_with(&[ALL_CATEGORIES; 6]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        assert!(pool.select_arbitrators(0, &[], 11, ARBITRATORS_PER_DISPUTE).is_ok());
+        assert_eq!(
+            pool.select_arbitrators(0, &[], 11, MAX_PANEL_SIZE).unwrap_err(),
+            ProgramError::from(EscrowError::NoEligibleArbitratorsForCategory)
+        );
+    }
+
+    #[test]
+    fn test_selection_weighted_by_accuracy() {
+        let mut data = pool_with(&[ALL_CATEGORIES; 20]);
+        let pool = ArbitratorPool::load_mut(&mut data).unwrap();
+        assert_eq!(pool.accuracy_bps[0], NEUTRAL_ACCURACY_BPS);
+        for i in 0..20u8 {
+            pool.set_accuracy(&[i + 1; 32], if i < 5 { 10_000 } else { 0 });
+        }
+
+        let mut accurate_picks = 0;
+        for seed in 0..200u64 {
+            let selected = pool.select_arbitrators(0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            accurate_picks += selected[..ARBITRATORS_PER_DISPUTE].iter().filter(|a| a[0] <= 5).count();
+            // Deterministic from the seed
+            assert_eq!(pool.select_arbitrators(0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap(), selected);
+        }
+        // A quarter of the pool, but well over half of the seats
+        assert!(accurate_picks > 200 * ARBITRATORS_PER_DISPUTE / 2);
+    }
+
+    #[test]
+    fn test_proven_arbitrator_outdraws_newcomer() {
+        let mut data = pool_with(&[ALL_CATEGORIES; 20]);
+        let pool = ArbitratorPool::load_mut(&mut data).unwrap();
+        // Slot 1 has a perfect record, slot 2 is brand new (neutral accuracy)
+        pool.set_accuracy(&[1u8; 32], 10_000);
+
+        let (mut proven, mut newcomer) = (0, 0);
+        for seed in 0..500u64 {
+            let selected = pool.select_arbitrators(0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            proven += selected.iter().filter(|a| a[0] == 1).count();
+            newcomer += selected.iter().filter(|a| a[0] == 2).count();
+        }
+        assert!(proven > newcomer);
+        // The base weight keeps newcomers in the draw
+        assert!(newcomer > 0);
+    }
+
+    #[test]
+    fn test_selection_spans_extension_segment() {
+        let main_data = pool_with(&[ALL_CATEGORIES; 3]);
+        let main = ArbitratorPool::load(&main_data).unwrap();
+        let mut ext_data = std::vec![0u8; ArbitratorPool::SPACE];
+        let ext = ArbitratorPool::init(&mut ext_data).unwrap();
+        for i in 0..3u8 {
+            ext.add([100 + i; 32], ALL_CATEGORIES).unwrap();
+        }
+        let ext = ArbitratorPool::load(&ext_data).unwrap();
+
+        // Neither segment alone can seat a panel
+        assert!(main.select_arbitrators(0, &[], 7, ARBITRATORS_PER_DISPUTE).is_err());
+        let mut from_extension = 0;
+        for seed in 0..50u64 {
+            let selected = select_from_segments(&[main, ext], 0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap();
+            from_extension += selected.iter().filter(|a| a[0] >= 100).count();
+        }
+        assert!(from_extension > 0);
+
+        // A single segment draws exactly as before
+        let data = pool_with(&[ALL_CATEGORIES; 8]);
+        let pool = ArbitratorPool::load(&data).unwrap();
+        for seed in 0..20u64 {
+            assert_eq!(
+                select_from_segments(&[pool], 0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap(),
+                pool.select_arbitrators(0, &[], seed, ARBITRATORS_PER_DISPUTE).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_accuracy_bps() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+        assert_eq!(arb.accuracy_bps(), NEUTRAL_ACCURACY_BPS);
+        arb.cases_voted = 4;
+        arb.cases_correct = 3;
+        assert_eq!(arb.accuracy_bps(), 7_500);
+    }
+
+    #[test]
+    fn test_accuracy_ignores_pending_votes() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+
+        // Votes cast but not yet judged leave accuracy neutral
+        arb.cases_voted = 3;
+        arb.open_cases = 3;
+        assert_eq!(arb.accuracy_bps(), NEUTRAL_ACCURACY_BPS);
+
+        // One correct judged vote is a perfect record so far
+        arb.open_cases = 2;
+        arb.cases_correct = 1;
+        assert_eq!(arb.accuracy_bps(), 10_000);
+    }
+
+    #[test]
+    fn test_reward_multiplier_bounded() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+        assert_eq!(arb.reward_multiplier_bps(), 10_000);
+
+        arb.cases_voted = 10;
+        arb.cases_correct = 2;
+        assert_eq!(arb.reward_multiplier_bps(), 10_000);
+        arb.cases_correct = 8;
+        assert_eq!(arb.reward_multiplier_bps(), 13_000);
+        arb.cases_correct = 10;
+        assert_eq!(arb.reward_multiplier_bps(), MAX_REWARD_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn test_accuracy_threshold() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+
+        // Too few judged votes to evaluate, however bad
+        arb.cases_voted = MIN_CASES_FOR_EVAL - 1;
+        assert!(!arb.below_accuracy_threshold());
+
+        // Exactly at the threshold stays
+        arb.cases_voted = MIN_CASES_FOR_EVAL;
+        arb.cases_correct = 4;
+        assert!(!arb.below_accuracy_threshold());
+
+        // Just below it goes
+        arb.cases_voted = 20;
+        arb.cases_correct = 7;
+        assert!(arb.below_accuracy_threshold());
+        arb.cases_correct = 8;
+        assert!(!arb.below_accuracy_threshold());
+    }
+
+    #[test]
+    fn test_accuracy_threshold_ignores_pending_votes() {
+        let mut data = std::vec![0u8; ArbitratorEntry::SPACE];
+        let arb = ArbitratorEntry::init(&mut data).unwrap();
+
+        // Enough votes cast, but too few judged to evaluate
+        arb.cases_voted = MIN_CASES_FOR_EVAL;
+        arb.open_cases = 1;
+        assert!(!arb.below_accuracy_threshold());
+
+        // Pending votes don't count against a good judged record
+        arb.cases_voted = 20;
+        arb.open_cases = 10;
+        arb.cases_correct = 5;
+        assert!(!arb.below_accuracy_threshold());
+        arb.cases_correct = 3;
+        assert!(arb.below_accuracy_threshold());
+    }
+}
@@ -0,0 +1,386 @@
+//! PlatformConfig account state
+//!
+//! Singleton holding platform-tunable parameters.
+
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use core::mem::size_of;
+use crate::{errors::EscrowError, PLATFORM_WALLET};
+use super::{DisputeResolution, CLOSED_ACCOUNT_DISCRIMINATOR, MAX_PLATFORM_FEE_BPS};
+
+/// Highest crank bounty that may be configured: half of the platform fee
+pub const MAX_CRANK_BOUNTY_BPS: u16 = 5_000;
+
+/// Highest referral share that may be configured: half of the platform fee,
+/// so a referral and a crank bounty together never exceed the fee
+pub const MAX_REFERRAL_BPS: u16 = 5_000;
+
+/// How long a simple dispute may sit unpursued before the worker can claim payment (30 days)
+pub const DEFAULT_ABANDONED_DISPUTE_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Shortest abandoned-dispute window the platform may configure (7 days)
+pub const MIN_ABANDONED_DISPUTE_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Platform-wide configuration
+///
+/// Seeds: ["platform_config"]
+#[repr(C)]
+pub struct PlatformConfig {
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+    /// Share of the platform fee paid to whoever cranks a stuck review, in basis points
+    pub crank_bounty_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Emergency stop: while set, no escrows are created or released
+    pub paused: u8,
+    /// Votes that must be cast before a dispute may be finalized ahead of its
+    /// deadline (0 = a majority is enough)
+    pub min_early_votes: u8,
+    /// Break tied dispute votes in favour of the party with the higher reputation (0 = off, split)
+    pub reputation_tie_break: u8,
+    /// Platform fee on SOL releases, in basis points (at most MAX_PLATFORM_FEE_BPS)
+    pub fee_bps: u16,
+    /// Seconds a simple dispute may go unpursued before the worker can claim
+    /// payment (0 = DEFAULT_ABANDONED_DISPUTE_SECONDS)
+    pub abandoned_dispute_seconds: i64,
+    /// Share of the platform fee paid to an escrow's referrer, in basis points
+    pub referral_bps: u16,
+    /// Resolution of a dispute that reached its deadline without a quorum of
+    /// votes (0 = PosterWins; see no_quorum_resolution)
+    pub no_quorum_resolution: u8,
+    /// Platform authority (zero = PLATFORM_WALLET); rotated by set_authority
+    /// and accept_authority
+    pub authority: Pubkey,
+    /// Authority proposed by set_authority, not yet accepted (zero = none)
+    pub pending_authority: Pubkey,
+    /// Set while the program is being wound down; the config can only be
+    /// closed while set
+    pub retiring: u8,
+}
+
+impl PlatformConfig {
+    /// Account discriminator (first 8 bytes)
+    pub const DISCRIMINATOR: [u8; 8] = [0x50, 0x6c, 0x61, 0x74, 0x43, 0x66, 0x67, 0x00]; // "PlatCfg\0"
+
+    /// Size of the account data (without discriminator)
+    pub const LEN: usize = size_of::<Self>();
+
+    /// Total size including 8-byte discriminator
+    pub const SPACE: usize = 8 + Self::LEN;
+
+    /// Load from account data
+    #[inline(always)]
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] != Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountNotInitialized.into());
+        }
+        Ok(unsafe { &*(data[8..].as_ptr() as *const Self) })
+    }
+
+    /// Load mutable reference from account data
+    #[inline(always)]
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] != Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountNotInitialized.into());
+        }
+        Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
+    }
+
+    /// Initialize account data with discriminator
+    #[inline(always)]
+    pub fn init(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] == Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountAlreadyInitialized.into());
+        }
+        data[..8].copy_from_slice(&Self::DISCRIMINATOR);
+        data[8..Self::SPACE].fill(0);
+        Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
+    }
+
+    /// Set the SOL release fee, rejecting anything above MAX_PLATFORM_FEE_BPS
+    #[inline(always)]
+    pub fn set_fee_bps(&mut self, fee_bps: u16) -> Result<(), ProgramError> {
+        if fee_bps > MAX_PLATFORM_FEE_BPS {
+            return Err(EscrowError::InvalidFeeBps.into());
+        }
+        self.fee_bps = fee_bps;
+        Ok(())
+    }
+
+    /// Set the referral share, rejecting anything above MAX_REFERRAL_BPS
+    #[inline(always)]
+    pub fn set_referral_bps(&mut self, referral_bps: u16) -> Result<(), ProgramError> {
+        if referral_bps > MAX_REFERRAL_BPS {
+            return Err(EscrowError::InvalidFeeBps.into());
+        }
+        self.referral_bps = referral_bps;
+        Ok(())
+    }
+
+    /// Set the abandoned-dispute window; 0 restores the default, anything
+    /// else must be at least MIN_ABANDONED_DISPUTE_SECONDS
+    #[inline(always)]
+    pub fn set_abandoned_dispute_seconds(&mut self, seconds: i64) -> Result<(), ProgramError> {
+        if seconds != 0 && seconds < MIN_ABANDONED_DISPUTE_SECONDS {
+            return Err(EscrowError::InvalidExpiry.into());
+        }
+        self.abandoned_dispute_seconds = seconds;
+        Ok(())
+    }
+
+    /// Abandoned-dispute window in effect
+    #[inline(always)]
+    pub fn abandoned_dispute_window(&self) -> i64 {
+        match self.abandoned_dispute_seconds {
+            0 => DEFAULT_ABANDONED_DISPUTE_SECONDS,
+            seconds => seconds,
+        }
+    }
+
+    /// Set the no-quorum resolution: PosterWins or Split
+    ///
+    /// WorkerWins is refused, since no arbitrator endorsed paying the worker.
+    #[inline(always)]
+    pub fn set_no_quorum_resolution(&mut self, resolution: u8) -> Result<(), ProgramError> {
+        match DisputeResolution::from_u8(resolution) {
+            Some(DisputeResolution::PosterWins | DisputeResolution::Split) => {
+                self.no_quorum_resolution = resolution;
+                Ok(())
+            }
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    /// Resolution in effect for a dispute that ended without a quorum
+    #[inline(always)]
+    pub fn no_quorum_resolution(&self) -> DisputeResolution {
+        match DisputeResolution::from_u8(self.no_quorum_resolution) {
+            Some(DisputeResolution::Split) => DisputeResolution::Split,
+            _ => DisputeResolution::PosterWins,
+        }
+    }
+
+    /// Platform authority in effect
+    #[inline(always)]
+    pub fn authority(&self) -> Pubkey {
+        if self.authority == Pubkey::default() {
+            PLATFORM_WALLET
+        } else {
+            self.authority
+        }
+    }
+
+    /// Propose `new_authority`, which takes over once it accepts; the zero
+    /// key withdraws a pending proposal
+    #[inline(always)]
+    pub fn propose_authority(&mut self, new_authority: &Pubkey) {
+        self.pending_authority = *new_authority;
+    }
+
+    /// Hand the authority to `signer`, which must be the pending authority
+    #[inline(always)]
+    pub fn accept_authority(&mut self, signer: &Pubkey) -> Result<(), ProgramError> {
+        if self.pending_authority == Pubkey::default() || &self.pending_authority != signer {
+            return Err(EscrowError::NotPendingAuthority.into());
+        }
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    /// Fail with ProgramPaused while the platform is paused
+    #[inline(always)]
+    pub fn require_not_paused(&self) -> Result<(), ProgramError> {
+        if self.is_paused() {
+            return Err(EscrowError::ProgramPaused.into());
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn is_retiring(&self) -> bool {
+        self.retiring != 0
+    }
+
+    /// The config holds rent with no other recovery path; only let it be
+    /// closed once the platform has deliberately marked it retiring. Closing
+    /// would lift a pause (a missing config pauses nothing) and hand the
+    /// authority back to PLATFORM_WALLET, so a paused or rotated config stays open
+    #[inline(always)]
+    pub fn require_closable(&self) -> Result<(), ProgramError> {
+        if !self.is_retiring() {
+            return Err(EscrowError::PlatformNotRetiring.into());
+        }
+        if self.is_paused() {
+            return Err(EscrowError::ProgramPaused.into());
+        }
+        if self.authority() != PLATFORM_WALLET {
+            return Err(EscrowError::AuthorityRotated.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_closable_only_while_retiring() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+
+        assert_eq!(
+            config.require_closable(),
+            Err(ProgramError::from(EscrowError::PlatformNotRetiring))
+        );
+
+        config.retiring = 1;
+        assert!(config.require_closable().is_ok());
+
+        // Closing must not lift a pause
+        config.paused = 1;
+        assert_eq!(
+            config.require_closable(),
+            Err(ProgramError::from(EscrowError::ProgramPaused))
+        );
+        config.paused = 0;
+
+        // Closing must not undo a rotation
+        config.propose_authority(&[7u8; 32]);
+        config.accept_authority(&[7u8; 32]).unwrap();
+        assert_eq!(
+            config.require_closable(),
+            Err(ProgramError::from(EscrowError::AuthorityRotated))
+        );
+    }
+
+    #[test]
+    fn test_fee_bps_bounded() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+
+        assert!(config.set_fee_bps(MAX_PLATFORM_FEE_BPS).is_ok());
+        assert_eq!(config.fee_bps, 1_000);
+        assert_eq!(
+            config.set_fee_bps(MAX_PLATFORM_FEE_BPS + 1),
+            Err(ProgramError::from(EscrowError::InvalidFeeBps))
+        );
+        assert_eq!(config.fee_bps, 1_000);
+    }
+
+    #[test]
+    fn test_abandoned_dispute_window() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        assert_eq!(config.abandoned_dispute_window(), DEFAULT_ABANDONED_DISPUTE_SECONDS);
+
+        config.set_abandoned_dispute_seconds(MIN_ABANDONED_DISPUTE_SECONDS).unwrap();
+        assert_eq!(config.abandoned_dispute_window(), MIN_ABANDONED_DISPUTE_SECONDS);
+        assert_eq!(
+            config.set_abandoned_dispute_seconds(MIN_ABANDONED_DISPUTE_SECONDS - 1),
+            Err(ProgramError::from(EscrowError::InvalidExpiry))
+        );
+        assert!(config.set_abandoned_dispute_seconds(-1).is_err());
+
+        config.set_abandoned_dispute_seconds(0).unwrap();
+        assert_eq!(config.abandoned_dispute_window(), DEFAULT_ABANDONED_DISPUTE_SECONDS);
+    }
+
+    #[test]
+    fn test_referral_bps_bounded() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        assert_eq!(config.referral_bps, 0);
+
+        config.set_referral_bps(MAX_REFERRAL_BPS).unwrap();
+        assert_eq!(config.referral_bps, MAX_REFERRAL_BPS);
+        assert_eq!(
+            config.set_referral_bps(MAX_REFERRAL_BPS + 1),
+            Err(ProgramError::from(EscrowError::InvalidFeeBps))
+        );
+        assert_eq!(config.referral_bps, MAX_REFERRAL_BPS);
+    }
+
+    #[test]
+    fn test_no_quorum_resolution_defaults_to_refund() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        assert_eq!(config.no_quorum_resolution(), DisputeResolution::PosterWins);
+
+        config.set_no_quorum_resolution(DisputeResolution::Split as u8).unwrap();
+        assert_eq!(config.no_quorum_resolution(), DisputeResolution::Split);
+        assert!(config.set_no_quorum_resolution(DisputeResolution::WorkerWins as u8).is_err());
+        assert!(config.set_no_quorum_resolution(9).is_err());
+        assert_eq!(config.no_quorum_resolution(), DisputeResolution::Split);
+    }
+
+    #[test]
+    fn test_pause_blocks_until_unpaused() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        assert!(config.require_not_paused().is_ok());
+
+        config.paused = 1;
+        assert_eq!(
+            config.require_not_paused(),
+            Err(ProgramError::from(EscrowError::ProgramPaused))
+        );
+
+        config.paused = 0;
+        assert!(config.require_not_paused().is_ok());
+    }
+
+    #[test]
+    fn test_authority_rotation_needs_acceptance() {
+        let mut data = std::vec![0u8; PlatformConfig::SPACE];
+        let config = PlatformConfig::init(&mut data).unwrap();
+        assert_eq!(config.authority(), PLATFORM_WALLET);
+
+        let new_authority = [7u8; 32];
+        assert_eq!(
+            config.accept_authority(&new_authority),
+            Err(ProgramError::from(EscrowError::NotPendingAuthority))
+        );
+
+        config.propose_authority(&new_authority);
+        assert_eq!(config.authority(), PLATFORM_WALLET);
+        assert_eq!(
+            config.accept_authority(&[8u8; 32]),
+            Err(ProgramError::from(EscrowError::NotPendingAuthority))
+        );
+
+        config.accept_authority(&new_authority).unwrap();
+        assert_eq!(config.authority(), new_authority);
+        assert_eq!(config.pending_authority, Pubkey::default());
+
+        // A withdrawn proposal cannot be accepted
+        config.propose_authority(&[9u8; 32]);
+        config.propose_authority(&Pubkey::default());
+        assert!(config.accept_authority(&Pubkey::default()).is_err());
+        assert_eq!(config.authority(), new_authority);
+    }
+}
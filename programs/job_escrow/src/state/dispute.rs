@@ -3,14 +3,82 @@
 //! Tracks dispute resolution with multi-arbitrator voting.
 
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use core::mem::size_of;
 use crate::errors::EscrowError;
+use super::{
+    CLOSED_ACCOUNT_DISCRIMINATOR, ARBITRATOR_VOTE_FEE, ARBITRATOR_VOTE_FEE_BPS, MAX_ARBITRATOR_VOTE_FEE,
+};
 
-/// Number of arbitrators per dispute
+/// Number of arbitrators on a mid-sized dispute's panel (the default)
 pub const ARBITRATORS_PER_DISPUTE: usize = 5;
 
-/// Majority needed to win (3 of 5)
+/// Arbitrators on a low-value dispute's panel
+pub const MIN_PANEL_SIZE: usize = 3;
+
+/// Arbitrators on a high-value dispute's panel; sizes the per-arbitrator arrays
+pub const MAX_PANEL_SIZE: usize = 7;
+
+/// Escrows below this amount get the smallest panel (1 SOL)
+pub const SMALL_PANEL_MAX_AMOUNT: u64 = 1_000_000_000;
+
+/// Escrows of at least this amount get the largest panel (100 SOL)
+pub const LARGE_PANEL_MIN_AMOUNT: u64 = 100_000_000_000;
+
+/// Majority needed to win on the default panel (3 of 5)
 pub const ARBITRATION_MAJORITY: u8 = 3;
 
+/// Votes a case without a majority needs to be decided at its deadline
+///
+/// Capped at the seated panel, so a 3-arbitrator panel needs all three.
+/// Below it, finalization falls back to the platform's no-quorum resolution
+/// (a refund by default) instead of letting one or two votes decide.
+pub const MIN_QUORUM: u8 = 3;
+
+/// Bytes of the plaintext job_id kept on a dispute case for arbitrators
+pub const JOB_ID_PREFIX_LEN: usize = 32;
+
+/// Slots between raising a dispute and the slot whose hash selects its panel
+///
+/// The initiator cannot know that hash when raising the dispute, so cannot
+/// grind for favourable arbitrators. Must stay well under the 512 slots
+/// SlotHashes keeps.
+pub const DISPUTE_REVEAL_DELAY_SLOTS: u64 = 8;
+
+/// Most arbitrators a party may exclude from a dispute's panel
+pub const MAX_EXCLUDED_ARBITRATORS: usize = 2;
+
+/// Longest one-time voting extension: 24 hours
+pub const MAX_VOTING_EXTENSION_SECONDS: i64 = 24 * 60 * 60;
+
+/// Final stretch of voting reserved for revealing committed votes: 12 hours
+///
+/// Commitments close this long before the voting deadline, and reveals are
+/// accepted from then until the deadline. A vote not revealed in time is
+/// not counted.
+pub const VOTE_REVEAL_SECONDS: i64 = 12 * 60 * 60;
+
+/// How long the losing party has to appeal after finalization: 24 hours
+///
+/// The resolution cannot be executed, and arbitrators cannot be judged or
+/// paid, until this window has passed without an appeal.
+pub const APPEAL_WINDOW_SECONDS: i64 = 24 * 60 * 60;
+
+/// Appeal rounds allowed per dispute
+pub const MAX_APPEAL_ROUNDS: u8 = 1;
+
+/// Appeal bond as a share of the disputed amount (5%)
+pub const APPEAL_BOND_BPS: u64 = 500;
+
+/// Smallest appeal bond: 0.01 SOL
+pub const MIN_APPEAL_BOND: u64 = 10_000_000;
+
+/// Largest appeal bond: 1 SOL
+pub const MAX_APPEAL_BOND: u64 = 1_000_000_000;
+
+/// How long voters have to claim their vote fee before the case can be
+/// closed with shares still unclaimed: 30 days after the voting deadline
+pub const VOTE_FEE_CLAIM_WINDOW_SECONDS: i64 = 30 * 24 * 60 * 60;
+
 /// Vote options
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u8)]
@@ -58,6 +126,76 @@ impl DisputeResolution {
             _ => None,
         }
     }
+
+    /// Resolution for a final vote count
+    ///
+    /// A tie with votes cast splits the funds, unless `party_scores` (worker,
+    /// poster reputation) is given, in which case it goes to the party with the
+    /// higher score. Equal scores still split.
+    pub fn from_votes(for_worker: u8, for_poster: u8, party_scores: Option<(i64, i64)>) -> Self {
+        if for_worker > for_poster {
+            return Self::WorkerWins;
+        }
+        if for_poster > for_worker {
+            return Self::PosterWins;
+        }
+        match party_scores {
+            Some((worker, poster)) if for_worker > 0 && worker > poster => Self::WorkerWins,
+            Some((worker, poster)) if for_worker > 0 && poster > worker => Self::PosterWins,
+            _ => Self::Split,
+        }
+    }
+
+    /// Whether the worker gets a posted deposit back (forfeited to the poster on a loss)
+    #[inline(always)]
+    pub fn worker_keeps_deposit(&self) -> bool {
+        !matches!(self, Self::PosterWins)
+    }
+
+    /// Whether the worker (or else the poster) lost and may appeal
+    ///
+    /// A split leaves both parties short, so either may appeal it.
+    #[inline(always)]
+    pub fn appealable_by(&self, by_worker: bool) -> bool {
+        match self {
+            Self::WorkerWins => !by_worker,
+            Self::PosterWins => by_worker,
+            Self::Split => true,
+            Self::Pending => false,
+        }
+    }
+}
+
+/// Dispute reason categories
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum DisputeReason {
+    /// No category given
+    Unspecified = 0,
+    /// Work was never delivered
+    NonDelivery = 1,
+    /// Delivered work does not meet the agreed quality
+    Quality = 2,
+    /// Poster asked for work beyond the agreed scope
+    ScopeCreep = 3,
+    /// Payment or deadline terms were not honoured
+    Terms = 4,
+    /// Anything else (see free-text reason)
+    Other = 5,
+}
+
+impl DisputeReason {
+    pub fn from_u16(value: u16) -> Option<Self> {
+        match value {
+            0 => Some(Self::Unspecified),
+            1 => Some(Self::NonDelivery),
+            2 => Some(Self::Quality),
+            3 => Some(Self::ScopeCreep),
+            4 => Some(Self::Terms),
+            5 => Some(Self::Other),
+            _ => None,
+        }
+    }
 }
 
 /// Dispute case account
@@ -69,24 +207,81 @@ pub struct DisputeCase {
     pub escrow: Pubkey,
     /// Who raised the dispute (poster or worker)
     pub raised_by: Pubkey,
-    /// The 5 selected arbitrators
-    pub arbitrators: [Pubkey; ARBITRATORS_PER_DISPUTE],
+    /// The selected arbitrators (the first `panel_size` are used)
+    pub arbitrators: [Pubkey; MAX_PANEL_SIZE],
     /// Votes from each arbitrator (indexed by position)
-    pub votes: [u8; ARBITRATORS_PER_DISPUTE],
+    pub votes: [u8; MAX_PANEL_SIZE],
     /// Unix timestamp deadline for voting
     pub voting_deadline: i64,
     /// Resolution outcome
     pub resolution: u8,
     /// Unix timestamp when dispute was created
     pub created_at: i64,
+    /// Portion of the escrow contested in the dispute; the rest goes to the worker
+    pub disputed_amount: u64,
     /// PDA bump seed
     pub bump: u8,
-    /// Padding for alignment
-    pub _padding: [u8; 5],
+    /// Whether the voting deadline has been extended (allowed once)
+    pub voting_extended: u8,
+    /// Dispute reason category (see DisputeReason)
+    pub reason_category: u16,
+    /// Number of arbitrators the initiator excluded from selection
+    pub excluded_count: u8,
+    /// Whether the arbitrators have been revealed; votes are only accepted after
+    pub arbitrators_revealed: u8,
     /// Reason for dispute (variable length, stored as fixed buffer)
     /// First 2 bytes = length, then up to 500 bytes of reason
     pub reason_len: u16,
     pub reason: [u8; 500],
+    /// Vote delegates per arbitrator position (zeroed = not delegated)
+    pub delegates: [Pubkey; MAX_PANEL_SIZE],
+    /// First bytes of the verified plaintext job_id (zero-padded)
+    pub job_id_prefix: [u8; JOB_ID_PREFIX_LEN],
+    /// Full length of the verified job_id
+    pub job_id_len: u16,
+    /// Whether a party has submitted a job_id matching the escrow's job_id_hash
+    pub job_id_verified: u8,
+    /// Number of voters who have claimed their share of the vote fee pot
+    pub vote_fees_paid: u8,
+    /// Fee paid to each voting arbitrator, set from the disputed amount at
+    /// creation (at most MAX_ARBITRATOR_VOTE_FEE, so it fits in 32 bits)
+    pub vote_fee: u32,
+    /// Vote fees paid in by the initiator, held in this account's lamports
+    pub vote_fee_pot: u64,
+    /// Arbitrators the initiator excluded from selection (first `excluded_count` are used)
+    pub excluded_arbitrators: [Pubkey; MAX_EXCLUDED_ARBITRATORS],
+    /// Slot whose hash seeds arbitrator selection
+    pub reveal_slot: u64,
+    /// sha256(escrow || reveal_slot), fixed when the dispute is raised
+    pub selection_commitment: [u8; 32],
+    /// Number of arbitrators on the panel, set from the escrow amount when raised
+    /// (0 = ARBITRATORS_PER_DISPUTE)
+    pub panel_size: u8,
+    /// Hidden vote commitments per arbitrator position (zeroed = not committed)
+    pub commitments: [[u8; 32]; MAX_PANEL_SIZE],
+    /// Whether votes must be committed and then revealed (set for cases raised
+    /// after commit-reveal voting was introduced)
+    pub commit_reveal: u8,
+    /// Appeal rounds started on this case (at most MAX_APPEAL_ROUNDS)
+    pub appeal_round: u8,
+    /// Resolution the appeal challenges (meaningful once appeal_round > 0)
+    pub original_resolution: u8,
+    /// Panel size of the round before the appeal (0 = not appealed)
+    pub prior_panel_size: u8,
+    /// Arbitrators of the round before the appeal, judged against the final outcome
+    pub prior_arbitrators: [Pubkey; MAX_PANEL_SIZE],
+    /// Votes of the round before the appeal (indexed by position)
+    pub prior_votes: [u8; MAX_PANEL_SIZE],
+    /// Unix timestamp the current resolution was finalized (0 = not yet)
+    pub finalized_at: i64,
+    /// Party who appealed
+    pub appellant: Pubkey,
+    /// Appeal bond held in this account's lamports until settled (0 = none)
+    pub appeal_bond: u64,
+    /// Bitmap of panel positions whose arbitrator recused (bit i = position i)
+    pub recused: u8,
+    /// Recorded votes judged against the final outcome
+    pub votes_judged: u8,
 }
 
 impl DisputeCase {
@@ -94,7 +289,7 @@ impl DisputeCase {
     pub const DISCRIMINATOR: [u8; 8] = [0x44, 0x69, 0x73, 0x70, 0x43, 0x61, 0x73, 0x65]; // "DispCase"
     
     /// Size of the account data
-    pub const LEN: usize = 32 + 32 + (32 * ARBITRATORS_PER_DISPUTE) + ARBITRATORS_PER_DISPUTE + 8 + 1 + 8 + 1 + 5 + 2 + 500;
+    pub const LEN: usize = size_of::<Self>();
     
     /// Total size including discriminator
     pub const SPACE: usize = 8 + Self::LEN;
@@ -107,6 +302,9 @@ impl DisputeCase {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -118,6 +316,9 @@ impl DisputeCase {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -129,6 +330,9 @@ impl DisputeCase {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
         }
@@ -137,10 +341,83 @@ impl DisputeCase {
         Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
     }
 
+    /// Panel size for a dispute over an escrow of `amount`
+    ///
+    /// Small jobs do not need five arbitrators, and large ones deserve more.
+    #[inline(always)]
+    pub fn panel_size_for(amount: u64) -> usize {
+        if amount < SMALL_PANEL_MAX_AMOUNT {
+            MIN_PANEL_SIZE
+        } else if amount >= LARGE_PANEL_MIN_AMOUNT {
+            MAX_PANEL_SIZE
+        } else {
+            ARBITRATORS_PER_DISPUTE
+        }
+    }
+
+    /// Number of arbitrators on this case's panel
+    #[inline(always)]
+    pub fn panel_len(&self) -> usize {
+        match self.panel_size as usize {
+            0 => ARBITRATORS_PER_DISPUTE,
+            size => size.min(MAX_PANEL_SIZE),
+        }
+    }
+
+    /// Arbitrators on the panel who have not recused
+    #[inline(always)]
+    pub fn seated_len(&self) -> usize {
+        self.panel_len() - self.recused_count()
+    }
+
+    /// Votes one side needs to win: a strict majority of the seated panel
+    #[inline(always)]
+    pub fn majority(&self) -> u8 {
+        (self.seated_len() / 2 + 1) as u8
+    }
+
+    /// Check if the arbitrator at `position` recused
+    #[inline(always)]
+    pub fn is_recused(&self, position: usize) -> bool {
+        position < MAX_PANEL_SIZE && self.recused & (1 << position) != 0
+    }
+
+    /// Number of panel positions whose arbitrator recused
+    #[inline(always)]
+    pub fn recused_count(&self) -> usize {
+        (0..self.panel_len()).filter(|&position| self.is_recused(position)).count()
+    }
+
+    /// Step `arbitrator` down from the case before they vote
+    ///
+    /// The slot leaves the majority denominator and can no longer vote,
+    /// commit or be delegated. It is not replaced.
+    pub fn recuse(&mut self, arbitrator: &Pubkey, now: i64) -> Result<(), ProgramError> {
+        if self.is_resolved() {
+            return Err(EscrowError::DisputeAlreadyResolved.into());
+        }
+        if !self.is_voting_open(now) {
+            return Err(EscrowError::VotingDeadlinePassed.into());
+        }
+        let position = self.find_arbitrator_position(arbitrator)
+            .ok_or(EscrowError::NotSelectedArbitrator)?;
+        if self.is_recused(position) {
+            return Err(EscrowError::ArbitratorRecused.into());
+        }
+        if self.votes[position] != Vote::None as u8 || self.commitments[position] != [0u8; 32] {
+            return Err(EscrowError::AlreadyVoted.into());
+        }
+        if self.delegates[position] != [0u8; 32] {
+            return Err(EscrowError::VoteAlreadyDelegated.into());
+        }
+        self.recused |= 1 << position;
+        Ok(())
+    }
+
     /// Get the vote for a specific arbitrator position
     #[inline(always)]
     pub fn get_vote(&self, position: usize) -> Option<Vote> {
-        if position >= ARBITRATORS_PER_DISPUTE {
+        if position >= self.panel_len() {
             return None;
         }
         Vote::from_u8(self.votes[position])
@@ -149,7 +426,7 @@ impl DisputeCase {
     /// Set vote for a position
     #[inline(always)]
     pub fn set_vote(&mut self, position: usize, vote: Vote) {
-        if position < ARBITRATORS_PER_DISPUTE {
+        if position < self.panel_len() {
             self.votes[position] = vote as u8;
         }
     }
@@ -161,27 +438,222 @@ impl DisputeCase {
     }
 
     /// Check if dispute is resolved
+    ///
+    /// Any value other than Pending counts, including ones this build does
+    /// not recognise, so finalize_dispute_case can never overwrite an outcome.
     #[inline(always)]
     pub fn is_resolved(&self) -> bool {
         self.resolution != DisputeResolution::Pending as u8
     }
 
+    /// Check if votes are still accepted at `now`
+    #[inline(always)]
+    pub fn is_voting_open(&self, now: i64) -> bool {
+        now < self.voting_deadline
+    }
+
+    /// Check if votes on this case go through commit and reveal
+    #[inline(always)]
+    pub fn requires_commit_reveal(&self) -> bool {
+        self.commit_reveal != 0
+    }
+
+    /// End of the commit phase; reveals are accepted from here to the deadline
+    #[inline(always)]
+    pub fn commit_deadline(&self) -> i64 {
+        self.voting_deadline.saturating_sub(VOTE_REVEAL_SECONDS)
+    }
+
+    /// Store a hidden vote for a position until it is revealed
+    ///
+    /// Commitments close at the commit deadline, and also once any vote has
+    /// been revealed (after an extension reopens the window), so no one can
+    /// commit after seeing how others voted.
+    pub fn commit_vote(&mut self, position: usize, commitment: [u8; 32], now: i64) -> Result<(), ProgramError> {
+        if self.is_resolved() {
+            return Err(EscrowError::DisputeAlreadyResolved.into());
+        }
+        if position >= self.panel_len() {
+            return Err(EscrowError::NotSelectedArbitrator.into());
+        }
+        if now >= self.commit_deadline() || self.voter_count() > 0 {
+            return Err(EscrowError::CommitPhaseClosed.into());
+        }
+        if self.is_recused(position) {
+            return Err(EscrowError::ArbitratorRecused.into());
+        }
+        if self.commitments[position] != [0u8; 32] {
+            return Err(EscrowError::AlreadyVoted.into());
+        }
+        if commitment == [0u8; 32] {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        self.commitments[position] = commitment;
+        Ok(())
+    }
+
+    /// Record a committed vote once `commitment` (recomputed from the revealed
+    /// vote and salt) matches the stored one
+    pub fn reveal_vote(
+        &mut self,
+        position: usize,
+        vote: Vote,
+        commitment: &[u8; 32],
+        now: i64,
+    ) -> Result<(), ProgramError> {
+        if self.is_resolved() {
+            return Err(EscrowError::DisputeAlreadyResolved.into());
+        }
+        if now < self.commit_deadline() {
+            return Err(EscrowError::RevealPhaseNotOpen.into());
+        }
+        if !self.is_voting_open(now) {
+            return Err(EscrowError::VotingDeadlinePassed.into());
+        }
+        let stored = self.commitments.get(position).ok_or(EscrowError::NotSelectedArbitrator)?;
+        if stored == &[0u8; 32] {
+            return Err(EscrowError::VoteNotCommitted.into());
+        }
+        if stored != commitment {
+            return Err(EscrowError::VoteCommitmentMismatch.into());
+        }
+        self.record_vote(position, vote)
+    }
+
+    /// Record a job_id that has been verified against the escrow's job_id_hash
+    ///
+    /// Only a prefix is stored; arbitrators can match it against the job
+    /// listing, and the hash check already pins the full value.
+    pub fn record_job_id(&mut self, job_id: &[u8]) {
+        let len = job_id.len().min(JOB_ID_PREFIX_LEN);
+        self.job_id_prefix = [0u8; JOB_ID_PREFIX_LEN];
+        self.job_id_prefix[..len].copy_from_slice(&job_id[..len]);
+        self.job_id_len = job_id.len().min(u16::MAX as usize) as u16;
+        self.job_id_verified = 1;
+    }
+
+    /// Earliest time the poster may reclaim funds from an unresolved case
+    ///
+    /// Normally `voting_deadline + grace`, but an escrow-level `max_duration`
+    /// (0 = none) caps it at `created_at + max_duration` so extensions cannot
+    /// keep funds locked indefinitely.
+    #[inline(always)]
+    pub fn emergency_deadline(&self, grace: i64, max_duration: i64) -> i64 {
+        let deadline = self.voting_deadline.saturating_add(grace);
+        if max_duration > 0 {
+            deadline.min(self.created_at.saturating_add(max_duration))
+        } else {
+            deadline
+        }
+    }
+
+    /// Push the voting deadline forward once, by at most MAX_VOTING_EXTENSION_SECONDS
+    ///
+    /// The emergency-claim deadline is derived from `voting_deadline`, so it
+    /// moves with it (up to the escrow's max_dispute_duration cap).
+    pub fn extend_voting(&mut self, seconds: i64, now: i64) -> Result<(), ProgramError> {
+        if self.is_resolved() {
+            return Err(EscrowError::DisputeAlreadyResolved.into());
+        }
+        if !self.is_voting_open(now) {
+            return Err(EscrowError::VotingDeadlinePassed.into());
+        }
+        if self.voting_extended != 0 {
+            return Err(EscrowError::VotingAlreadyExtended.into());
+        }
+        if seconds <= 0 || seconds > MAX_VOTING_EXTENSION_SECONDS {
+            return Err(EscrowError::InvalidVotingExtension.into());
+        }
+        self.voting_deadline = self.voting_deadline
+            .checked_add(seconds)
+            .ok_or(EscrowError::ArithmeticOverflow)?;
+        self.voting_extended = 1;
+        Ok(())
+    }
+
     /// Find arbitrator position in the array
     #[inline(always)]
     pub fn find_arbitrator_position(&self, arbitrator: &Pubkey) -> Option<usize> {
-        for i in 0..ARBITRATORS_PER_DISPUTE {
-            if &self.arbitrators[i] == arbitrator {
-                return Some(i);
-            }
+        self.arbitrators[..self.panel_len()].iter().position(|a| a == arbitrator)
+    }
+
+    /// Find the position of an active delegate
+    #[inline(always)]
+    pub fn find_delegate_position(&self, delegate: &Pubkey) -> Option<usize> {
+        if delegate == &[0u8; 32] {
+            return None;
         }
-        None
+        self.delegates.iter().position(|d| d == delegate)
+    }
+
+    /// Position a voter may vote on: their own slot, or the slot delegated to them
+    #[inline(always)]
+    pub fn voting_position(&self, voter: &Pubkey) -> Option<usize> {
+        self.find_arbitrator_position(voter)
+            .or_else(|| self.find_delegate_position(voter))
+    }
+
+    /// Delegate a selected arbitrator's vote on this case to another arbitrator
+    ///
+    /// The delegate must not be selected for this case and may hold only one
+    /// delegation, so delegation chains (and thus cycles) cannot form and no
+    /// one controls more than one vote. Anyone barred from the panel is
+    /// barred from voting through a delegation too: the escrow's `parties`,
+    /// the arbitrators the initiator excluded, and an appealed case's
+    /// first-round arbitrators.
+    pub fn delegate_vote(
+        &mut self,
+        delegator: &Pubkey,
+        delegate: &Pubkey,
+        parties: &[Pubkey],
+    ) -> Result<(), ProgramError> {
+        if self.is_resolved() {
+            return Err(EscrowError::DisputeAlreadyResolved.into());
+        }
+        let position = self.find_arbitrator_position(delegator)
+            .ok_or(EscrowError::NotSelectedArbitrator)?;
+        if self.votes[position] != Vote::None as u8 || self.commitments[position] != [0u8; 32] {
+            return Err(EscrowError::AlreadyVoted.into());
+        }
+        if self.delegates[position] != [0u8; 32] {
+            return Err(EscrowError::VoteAlreadyDelegated.into());
+        }
+        if self.is_recused(position) {
+            return Err(EscrowError::ArbitratorRecused.into());
+        }
+        if delegate == &[0u8; 32]
+            || self.find_arbitrator_position(delegate).is_some()
+            || self.find_delegate_position(delegate).is_some()
+            || parties.contains(delegate)
+            || self.exclusions().contains(delegate)
+            || self.prior_arbitrators[..self.prior_panel_len()].contains(delegate)
+        {
+            return Err(EscrowError::InvalidDelegate.into());
+        }
+        self.delegates[position] = *delegate;
+        Ok(())
+    }
+
+    /// Record a vote for a position, rejecting a second vote on the same slot
+    pub fn record_vote(&mut self, position: usize, vote: Vote) -> Result<(), ProgramError> {
+        if position >= self.panel_len() || vote == Vote::None {
+            return Err(EscrowError::NotSelectedArbitrator.into());
+        }
+        if self.votes[position] != Vote::None as u8 {
+            return Err(EscrowError::AlreadyVoted.into());
+        }
+        if self.is_recused(position) {
+            return Err(EscrowError::ArbitratorRecused.into());
+        }
+        self.set_vote(position, vote);
+        Ok(())
     }
 
     /// Count votes for each side
     pub fn count_votes(&self) -> (u8, u8) {
         let mut for_worker = 0u8;
         let mut for_poster = 0u8;
-        for &vote in &self.votes {
+        for &vote in &self.votes[..self.panel_len()] {
             match Vote::from_u8(vote) {
                 Some(Vote::ForWorker) => for_worker += 1,
                 Some(Vote::ForPoster) => for_poster += 1,
@@ -193,8 +665,313 @@ impl DisputeCase {
 
     /// Check if majority has been reached
     pub fn has_majority(&self) -> bool {
+        self.summary().2
+    }
+
+    /// Vote tally for off-chain clients and finalization
+    ///
+    /// Returns (for_worker, for_poster, has_majority, projected_resolution).
+    /// The projection is the stored resolution once resolved, otherwise what
+    /// the current votes would resolve to without a reputation tie-break, or
+    /// None while nobody has voted.
+    pub fn summary(&self) -> (u8, u8, bool, Option<DisputeResolution>) {
         let (for_worker, for_poster) = self.count_votes();
-        for_worker >= ARBITRATION_MAJORITY || for_poster >= ARBITRATION_MAJORITY
+        let majority = self.majority();
+        let has_majority = for_worker >= majority || for_poster >= majority;
+        let projected = if self.is_resolved() {
+            DisputeResolution::from_u8(self.resolution)
+        } else if for_worker + for_poster > 0 {
+            Some(DisputeResolution::from_votes(for_worker, for_poster, None))
+        } else {
+            None
+        };
+        (for_worker, for_poster, has_majority, projected)
+    }
+
+    /// Number of arbitrators who cast a vote
+    #[inline(always)]
+    pub fn voter_count(&self) -> u8 {
+        let (for_worker, for_poster) = self.count_votes();
+        for_worker + for_poster
+    }
+
+    /// Votes needed for a quorum: MIN_QUORUM, capped at the seated panel
+    #[inline(always)]
+    pub fn quorum(&self) -> u8 {
+        MIN_QUORUM.min(self.seated_len() as u8)
+    }
+
+    /// Whether the votes cast may decide the case
+    ///
+    /// A majority always does; otherwise at least `quorum()` votes must have
+    /// been cast. A case with no votes never has a quorum.
+    #[inline(always)]
+    pub fn has_quorum(&self) -> bool {
+        let voters = self.voter_count();
+        voters > 0 && (self.has_majority() || voters >= self.quorum())
+    }
+
+    /// Check if the case can be finalized at `now`
+    ///
+    /// Before the deadline a majority is required, plus at least
+    /// `min_early_votes` votes cast in total (0 = majority alone, capped at
+    /// the seated panel) so the fastest voters cannot decide before the rest
+    /// have seen the case.
+    #[inline(always)]
+    pub fn can_finalize(&self, now: i64, min_early_votes: u8) -> bool {
+        let min_votes = min_early_votes.min(self.seated_len() as u8);
+        !self.is_voting_open(now)
+            || (self.has_majority() && self.voter_count() >= min_votes)
+    }
+
+    /// Per-vote fee for a dispute over `disputed_amount`
+    ///
+    /// ARBITRATOR_VOTE_FEE_BPS of the disputed amount, so high-stakes cases pay
+    /// more, bounded by ARBITRATOR_VOTE_FEE and MAX_ARBITRATOR_VOTE_FEE.
+    #[inline(always)]
+    pub fn vote_fee_for(disputed_amount: u64) -> u64 {
+        let scaled = (disputed_amount as u128 * ARBITRATOR_VOTE_FEE_BPS as u128 / 10_000) as u64;
+        scaled.clamp(ARBITRATOR_VOTE_FEE, MAX_ARBITRATOR_VOTE_FEE)
+    }
+
+    /// Total vote fees the initiator pays in for a dispute over
+    /// `disputed_amount` judged by `panel_size` arbitrators
+    #[inline(always)]
+    pub fn vote_fee_pot_for(disputed_amount: u64, panel_size: usize) -> u64 {
+        panel_size as u64 * Self::vote_fee_for(disputed_amount)
+    }
+
+    /// Set up the vote fee pot from the disputed amount and return what the
+    /// initiator must pay in
+    #[inline(always)]
+    pub fn fund_vote_fees(&mut self) -> u64 {
+        self.vote_fee = Self::vote_fee_for(self.disputed_amount) as u32;
+        self.vote_fee_pot = Self::vote_fee_pot_for(self.disputed_amount, self.panel_len());
+        self.vote_fee_pot
+    }
+
+    /// Each arbitrator's share of the vote fee pot
+    ///
+    /// After an appeal the pot holds a fee for every seat of both rounds.
+    #[inline(always)]
+    pub fn vote_fee_share(&self) -> u64 {
+        self.vote_fee_pot / (self.panel_len() + self.prior_panel_len()) as u64
+    }
+
+    /// Pay out the vote fee share of `arbitrator`, from either round
+    ///
+    /// Only arbitrators who voted are paid, and only once the case is resolved;
+    /// the shares of those who never voted stay in the account. Double claims
+    /// are stopped by the caller's claim PDA.
+    pub fn claim_vote_fee(&mut self, arbitrator: &Pubkey) -> Result<u64, ProgramError> {
+        if !self.is_resolved() {
+            return Err(EscrowError::DisputeNotResolved.into());
+        }
+        match self.recorded_vote(arbitrator) {
+            Some(Vote::ForWorker) | Some(Vote::ForPoster) => {}
+            Some(Vote::None) => return Err(EscrowError::ArbitratorDidNotVote.into()),
+            None => return Err(EscrowError::NotSelectedArbitrator.into()),
+        }
+        if self.vote_fees_paid >= self.total_voter_count() {
+            return Err(EscrowError::PayoutMismatch.into());
+        }
+        self.vote_fees_paid += 1;
+        Ok(self.vote_fee_share())
+    }
+
+    /// Reward for a vote fee claim just recorded by `claim_vote_fee`
+    ///
+    /// The share is scaled by `multiplier_bps`, but the bonus only comes out
+    /// of the pot's surplus: `available` less a full share for every voter yet
+    /// to claim. Later voters are always paid, so the bonus only appears when
+    /// some arbitrators forfeited their share.
+    pub fn vote_reward(&self, multiplier_bps: u16, available: u64) -> u64 {
+        let share = self.vote_fee_share();
+        let boosted = (share as u128 * multiplier_bps as u128 / 10_000) as u64;
+        let unclaimed = self.total_voter_count().saturating_sub(self.vote_fees_paid) as u64;
+        let surplus_cap = available.saturating_sub(share.saturating_mul(unclaimed));
+        boosted.min(surplus_cap).max(share)
+    }
+
+    /// Record the arbitrators the initiator excluded from selection
+    pub fn set_exclusions(&mut self, excluded: &[Pubkey]) -> Result<(), ProgramError> {
+        if excluded.len() > MAX_EXCLUDED_ARBITRATORS {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+        self.excluded_arbitrators = [[0u8; 32]; MAX_EXCLUDED_ARBITRATORS];
+        self.excluded_arbitrators[..excluded.len()].copy_from_slice(excluded);
+        self.excluded_count = excluded.len() as u8;
+        Ok(())
+    }
+
+    /// Arbitrators the initiator excluded from selection
+    #[inline(always)]
+    pub fn exclusions(&self) -> &[Pubkey] {
+        &self.excluded_arbitrators[..self.excluded_count as usize]
+    }
+
+    #[inline(always)]
+    pub fn is_revealed(&self) -> bool {
+        self.arbitrators_revealed != 0
+    }
+
+    /// Fail until the arbitrators are revealed; there is no panel to vote or finalize before
+    #[inline(always)]
+    pub fn require_revealed(&self) -> Result<(), ProgramError> {
+        if !self.is_revealed() {
+            return Err(EscrowError::ArbitratorsNotRevealed.into());
+        }
+        Ok(())
+    }
+
+    /// Commit to selecting arbitrators from the hash of `reveal_slot`
+    pub fn commit_selection(&mut self, reveal_slot: u64, commitment: [u8; 32]) {
+        self.reveal_slot = reveal_slot;
+        self.selection_commitment = commitment;
+    }
+
+    /// Seat the selected arbitrators and open voting until `voting_deadline`
+    pub fn reveal_arbitrators(
+        &mut self,
+        arbitrators: [Pubkey; MAX_PANEL_SIZE],
+        voting_deadline: i64,
+    ) -> Result<(), ProgramError> {
+        if self.is_revealed() {
+            return Err(EscrowError::ArbitratorsAlreadyRevealed.into());
+        }
+        self.arbitrators = arbitrators;
+        self.voting_deadline = voting_deadline;
+        self.arbitrators_revealed = 1;
+        Ok(())
+    }
+
+    /// Check that the arbitrator at `position` let a resolved case pass without voting
+    pub fn require_absent(&self, position: usize) -> Result<(), ProgramError> {
+        if !self.is_resolved() {
+            return Err(EscrowError::DisputeNotResolved.into());
+        }
+        if self.is_recused(position) {
+            return Err(EscrowError::ArbitratorRecused.into());
+        }
+        match self.get_vote(position) {
+            Some(Vote::None) => Ok(()),
+            Some(_) => Err(EscrowError::ArbitratorVoted.into()),
+            None => Err(EscrowError::NotSelectedArbitrator.into()),
+        }
+    }
+
+    /// Check if the case can be closed without taking voters' unclaimed fees
+    #[inline(always)]
+    pub fn vote_fees_settled(&self, now: i64) -> bool {
+        self.vote_fees_paid >= self.total_voter_count()
+            || now >= self.voting_deadline.saturating_add(VOTE_FEE_CLAIM_WINDOW_SECONDS)
+    }
+
+    /// Count one recorded vote as judged against the final outcome
+    #[inline(always)]
+    pub fn record_judged_vote(&mut self) {
+        self.votes_judged = self.votes_judged.saturating_add(1);
+    }
+
+    /// Check if every recorded vote has been judged, so no voter is left
+    /// with an open case once this one closes
+    #[inline(always)]
+    pub fn all_votes_judged(&self) -> bool {
+        self.votes_judged >= self.total_voter_count()
+    }
+
+    /// Panel size of the round before the appeal (0 = not appealed)
+    #[inline(always)]
+    pub fn prior_panel_len(&self) -> usize {
+        (self.prior_panel_size as usize).min(MAX_PANEL_SIZE)
+    }
+
+    /// Vote `arbitrator` cast on either round (None = on neither panel)
+    pub fn recorded_vote(&self, arbitrator: &Pubkey) -> Option<Vote> {
+        if let Some(position) = self.find_arbitrator_position(arbitrator) {
+            return self.get_vote(position);
+        }
+        let prior = self.prior_panel_len();
+        let position = self.prior_arbitrators[..prior].iter().position(|a| a == arbitrator)?;
+        Vote::from_u8(self.prior_votes[position])
+    }
+
+    /// Votes cast across both rounds, each owed a vote fee share
+    pub fn total_voter_count(&self) -> u8 {
+        let prior = self.prior_votes[..self.prior_panel_len()]
+            .iter()
+            .filter(|&&vote| vote != Vote::None as u8)
+            .count() as u8;
+        self.voter_count() + prior
+    }
+
+    /// Bond the appellant pays for an appeal of `disputed_amount`
+    #[inline(always)]
+    pub fn appeal_bond_for(disputed_amount: u64) -> u64 {
+        let bond = (disputed_amount as u128 * APPEAL_BOND_BPS as u128 / 10_000) as u64;
+        bond.clamp(MIN_APPEAL_BOND, MAX_APPEAL_BOND)
+    }
+
+    /// Panel size for an appeal of a case judged by `panel_size` arbitrators
+    ///
+    /// One size up, except that a full panel stays at MAX_PANEL_SIZE.
+    #[inline(always)]
+    pub fn appeal_panel_size(panel_size: usize) -> usize {
+        (panel_size + 2).min(MAX_PANEL_SIZE)
+    }
+
+    /// Check if the resolution may still be appealed at `now`
+    #[inline(always)]
+    pub fn is_appeal_open(&self, now: i64) -> bool {
+        self.is_resolved()
+            && self.appeal_round < MAX_APPEAL_ROUNDS
+            && now < self.finalized_at.saturating_add(APPEAL_WINDOW_SECONDS)
+    }
+
+    /// Check if the resolution is final: resolved and no longer appealable
+    #[inline(always)]
+    pub fn is_settled(&self, now: i64) -> bool {
+        self.is_resolved() && !self.is_appeal_open(now)
+    }
+
+    /// Reopen a resolved case for a fresh, larger panel
+    ///
+    /// The current panel and its votes move to the prior round, where they
+    /// are still paid and judged against the final outcome. The caller
+    /// commits the new selection and funds the new panel's vote fees.
+    pub fn start_appeal(&mut self, appellant: &Pubkey, bond: u64, now: i64) -> Result<(), ProgramError> {
+        if !self.is_appeal_open(now) {
+            return Err(EscrowError::NotAppealable.into());
+        }
+        let panel = self.panel_len();
+        self.prior_arbitrators = self.arbitrators;
+        self.prior_votes = self.votes;
+        self.prior_panel_size = panel as u8;
+        self.original_resolution = self.resolution;
+        self.appeal_round += 1;
+        self.appellant = *appellant;
+        self.appeal_bond = bond;
+
+        self.panel_size = Self::appeal_panel_size(panel) as u8;
+        self.arbitrators = [[0u8; 32]; MAX_PANEL_SIZE];
+        self.votes = [Vote::None as u8; MAX_PANEL_SIZE];
+        self.delegates = [[0u8; 32]; MAX_PANEL_SIZE];
+        self.commitments = [[0u8; 32]; MAX_PANEL_SIZE];
+        self.recused = 0;
+        self.arbitrators_revealed = 0;
+        self.voting_extended = 0;
+        self.finalized_at = 0;
+        self.resolution = DisputeResolution::Pending as u8;
+        Ok(())
+    }
+
+    /// Check if the appeal changed the outcome it challenged
+    ///
+    /// Any different resolution counts, so an appeal that turns a loss into a
+    /// split has succeeded.
+    #[inline(always)]
+    pub fn appeal_overturned(&self) -> bool {
+        self.appeal_round > 0 && self.is_resolved() && self.resolution != self.original_resolution
     }
 
     /// Set reason from a string slice
@@ -213,4 +990,685 @@ impl DisputeCase {
     pub fn get_reason(&self) -> &[u8] {
         &self.reason[..self.reason_len as usize]
     }
+
+    /// Get reason category as enum
+    #[inline(always)]
+    pub fn get_reason_category(&self) -> Option<DisputeReason> {
+        DisputeReason::from_u16(self.reason_category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_reason_fits_in_space() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        let reason = core::str::from_utf8(&[b'x'; DisputeCase::MAX_REASON_LEN]).unwrap();
+        dispute.set_reason(reason).unwrap();
+        assert_eq!(dispute.get_reason().len(), DisputeCase::MAX_REASON_LEN);
+    }
+
+    #[test]
+    fn test_reason_category_persists() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        {
+            let dispute = DisputeCase::init(&mut data).unwrap();
+            dispute.reason_category = DisputeReason::ScopeCreep as u16;
+            dispute.set_reason("asked for three extra pages").unwrap();
+        }
+        let dispute = DisputeCase::load(&data).unwrap();
+        assert_eq!(dispute.get_reason_category(), Some(DisputeReason::ScopeCreep));
+        assert_eq!(dispute.get_reason(), b"asked for three extra pages");
+    }
+
+    #[test]
+    fn test_extend_voting_keeps_votes_open() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        dispute.voting_deadline = 1_000;
+
+        dispute.extend_voting(600, 900).unwrap();
+        assert_eq!(dispute.voting_deadline, 1_600);
+        assert_eq!(dispute.voting_extended, 1);
+        // Past the original deadline, votes are still accepted
+        assert!(dispute.is_voting_open(1_200));
+        assert!(!dispute.is_voting_open(1_600));
+
+        // Only one extension
+        assert_eq!(
+            dispute.extend_voting(600, 1_200),
+            Err(ProgramError::from(EscrowError::VotingAlreadyExtended))
+        );
+    }
+
+    fn case_with_arbitrators(data: &mut [u8]) -> &mut DisputeCase {
+        let dispute = DisputeCase::init(data).unwrap();
+        for i in 0..ARBITRATORS_PER_DISPUTE {
+            dispute.arbitrators[i] = [i as u8 + 1; 32];
+        }
+        dispute
+    }
+
+    #[test]
+    fn test_delegate_votes_on_delegator_slot() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        let delegate = [42u8; 32];
+
+        dispute.delegate_vote(&[2u8; 32], &delegate, &[]).unwrap();
+        let position = dispute.voting_position(&delegate).unwrap();
+        assert_eq!(position, 1);
+        dispute.record_vote(position, Vote::ForWorker).unwrap();
+        assert_eq!(dispute.get_vote(1), Some(Vote::ForWorker));
+
+        // The delegator can no longer vote on the same slot
+        let own = dispute.voting_position(&[2u8; 32]).unwrap();
+        assert_eq!(
+            dispute.record_vote(own, Vote::ForPoster),
+            Err(ProgramError::from(EscrowError::AlreadyVoted))
+        );
+    }
+
+    #[test]
+    fn test_delegation_rejects_chains_and_double_holding() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        let delegate = [42u8; 32];
+
+        // Cannot delegate to another selected arbitrator (or oneself)
+        assert_eq!(
+            dispute.delegate_vote(&[1u8; 32], &[2u8; 32], &[]),
+            Err(ProgramError::from(EscrowError::InvalidDelegate))
+        );
+        assert_eq!(
+            dispute.delegate_vote(&[1u8; 32], &[1u8; 32], &[]),
+            Err(ProgramError::from(EscrowError::InvalidDelegate))
+        );
+
+        dispute.delegate_vote(&[1u8; 32], &delegate, &[]).unwrap();
+        // A delegate holds at most one slot
+        assert_eq!(
+            dispute.delegate_vote(&[3u8; 32], &delegate, &[]),
+            Err(ProgramError::from(EscrowError::InvalidDelegate))
+        );
+        // A slot is delegated at most once
+        assert_eq!(
+            dispute.delegate_vote(&[1u8; 32], &[43u8; 32], &[]),
+            Err(ProgramError::from(EscrowError::VoteAlreadyDelegated))
+        );
+        // Outsiders who are not delegates cannot vote
+        assert_eq!(dispute.voting_position(&[43u8; 32]), None);
+    }
+
+    #[test]
+    fn test_delegation_rejects_anyone_barred_from_the_panel() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        let poster = [50u8; 32];
+        let worker = [51u8; 32];
+        let parties = [poster, worker];
+        dispute.set_exclusions(&[[60u8; 32]]).unwrap();
+        dispute.prior_arbitrators[0] = [70u8; 32];
+        dispute.prior_panel_size = 1;
+
+        for barred in [poster, worker, [60u8; 32], [70u8; 32]] {
+            assert_eq!(
+                dispute.delegate_vote(&[1u8; 32], &barred, &parties),
+                Err(ProgramError::from(EscrowError::InvalidDelegate))
+            );
+        }
+        assert_eq!(dispute.delegates[0], [0u8; 32]);
+
+        dispute.delegate_vote(&[1u8; 32], &[42u8; 32], &parties).unwrap();
+    }
+
+    #[test]
+    fn test_worker_loss_forfeits_deposit() {
+        assert!(DisputeResolution::WorkerWins.worker_keeps_deposit());
+        assert!(DisputeResolution::Split.worker_keeps_deposit());
+        assert!(!DisputeResolution::PosterWins.worker_keeps_deposit());
+    }
+
+    #[test]
+    fn test_extend_voting_is_bounded() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        dispute.voting_deadline = 1_000;
+
+        assert_eq!(
+            dispute.extend_voting(MAX_VOTING_EXTENSION_SECONDS + 1, 900),
+            Err(ProgramError::from(EscrowError::InvalidVotingExtension))
+        );
+        assert_eq!(
+            dispute.extend_voting(600, 1_000),
+            Err(ProgramError::from(EscrowError::VotingDeadlinePassed))
+        );
+        assert_eq!(dispute.voting_extended, 0);
+    }
+
+    #[test]
+    fn test_max_dispute_duration_caps_emergency_deadline() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        let grace = 48 * 60 * 60;
+        dispute.created_at = 1_000;
+        dispute.voting_deadline = 1_000 + 48 * 60 * 60;
+
+        // No cap: voting deadline plus grace
+        assert_eq!(dispute.emergency_deadline(grace, 0), dispute.voting_deadline + grace);
+
+        // A 3-day cap triggers before the grace period would
+        let cap = 3 * 24 * 60 * 60;
+        assert_eq!(dispute.emergency_deadline(grace, cap), 1_000 + cap);
+
+        // Extensions cannot push past the cap
+        dispute.extend_voting(MAX_VOTING_EXTENSION_SECONDS, 2_000).unwrap();
+        assert_eq!(dispute.emergency_deadline(grace, cap), 1_000 + cap);
+
+        // A cap longer than the normal path changes nothing
+        assert_eq!(dispute.emergency_deadline(grace, 30 * 24 * 60 * 60), dispute.voting_deadline + grace);
+    }
+
+    #[test]
+    fn test_vote_fees_paid_only_to_voters() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.voting_deadline = 1_000;
+
+        // Raise: the initiator funds one fee per arbitrator
+        assert_eq!(dispute.fund_vote_fees(), 5 * ARBITRATOR_VOTE_FEE);
+
+        // Vote: three of five arbitrators vote
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+        dispute.record_vote(1, Vote::ForWorker).unwrap();
+        dispute.record_vote(3, Vote::ForPoster).unwrap();
+        assert_eq!(
+            dispute.claim_vote_fee(&[1u8; 32]),
+            Err(ProgramError::from(EscrowError::DisputeNotResolved))
+        );
+
+        // Finalize
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        assert!(!dispute.vote_fees_settled(1_000));
+
+        // Claim: voters get one share each, non-voters forfeit theirs
+        let mut paid = 0;
+        for position in [0, 1, 3] {
+            paid += dispute.claim_vote_fee(&[position as u8 + 1; 32]).unwrap();
+        }
+        assert_eq!(paid, 3 * ARBITRATOR_VOTE_FEE);
+        assert_eq!(
+            dispute.claim_vote_fee(&[3u8; 32]),
+            Err(ProgramError::from(EscrowError::ArbitratorDidNotVote))
+        );
+        assert!(dispute.vote_fees_settled(1_000));
+    }
+
+    #[test]
+    fn test_high_value_dispute_pays_higher_vote_fee() {
+        // Small disputes pay the minimum, large ones are capped
+        assert_eq!(DisputeCase::vote_fee_for(100_000_000), ARBITRATOR_VOTE_FEE);
+        assert_eq!(DisputeCase::vote_fee_for(5_000_000_000), 5_000_000);
+        assert_eq!(DisputeCase::vote_fee_for(u64::MAX), MAX_ARBITRATOR_VOTE_FEE);
+
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.disputed_amount = 5_000_000_000;
+        assert_eq!(dispute.fund_vote_fees(), 5 * 5_000_000);
+        assert_eq!(dispute.vote_fee, 5_000_000);
+
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+        dispute.record_vote(1, Vote::ForPoster).unwrap();
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+
+        // Each voter is paid the elevated fee
+        assert_eq!(dispute.claim_vote_fee(&[1u8; 32]).unwrap(), 5_000_000);
+        assert_eq!(dispute.claim_vote_fee(&[2u8; 32]).unwrap(), 5_000_000);
+    }
+
+    #[test]
+    fn test_unclaimed_vote_fees_block_close_until_window_ends() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.voting_deadline = 1_000;
+        dispute.fund_vote_fees();
+        dispute.record_vote(0, Vote::ForPoster).unwrap();
+        dispute.resolution = DisputeResolution::PosterWins as u8;
+
+        assert!(!dispute.vote_fees_settled(1_000 + VOTE_FEE_CLAIM_WINDOW_SECONDS - 1));
+        assert!(dispute.vote_fees_settled(1_000 + VOTE_FEE_CLAIM_WINDOW_SECONDS));
+    }
+
+    #[test]
+    fn test_close_waits_for_every_vote_to_be_judged() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.record_vote(0, Vote::ForPoster).unwrap();
+        dispute.record_vote(1, Vote::ForWorker).unwrap();
+        dispute.resolution = DisputeResolution::PosterWins as u8;
+
+        assert!(!dispute.all_votes_judged());
+        dispute.record_judged_vote();
+        assert!(!dispute.all_votes_judged());
+        dispute.record_judged_vote();
+        assert!(dispute.all_votes_judged());
+    }
+
+    #[test]
+    fn test_only_non_voters_of_resolved_cases_are_absent() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+
+        assert_eq!(
+            dispute.require_absent(1),
+            Err(ProgramError::from(EscrowError::DisputeNotResolved))
+        );
+
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        assert!(dispute.require_absent(1).is_ok());
+        assert_eq!(
+            dispute.require_absent(0),
+            Err(ProgramError::from(EscrowError::ArbitratorVoted))
+        );
+    }
+
+    #[test]
+    fn test_early_finalize_needs_minimum_votes() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.voting_deadline = 1_000;
+        for position in 0..3 {
+            dispute.record_vote(position, Vote::ForWorker).unwrap();
+        }
+
+        // A 3-0 majority is enough unless the platform requires four votes
+        assert!(dispute.can_finalize(500, 0));
+        assert!(!dispute.can_finalize(500, 4));
+
+        dispute.record_vote(3, Vote::ForPoster).unwrap();
+        assert!(dispute.can_finalize(500, 4));
+
+        // After the deadline the minimum no longer applies
+        assert!(dispute.can_finalize(1_000, 5));
+    }
+
+    #[test]
+    fn test_tie_resolves_toward_higher_reputation() {
+        // 2-2 with one abstention
+        assert_eq!(DisputeResolution::from_votes(2, 2, None), DisputeResolution::Split);
+        assert_eq!(DisputeResolution::from_votes(2, 2, Some((40, 15))), DisputeResolution::WorkerWins);
+        assert_eq!(DisputeResolution::from_votes(2, 2, Some((-5, 15))), DisputeResolution::PosterWins);
+        assert_eq!(DisputeResolution::from_votes(2, 2, Some((15, 15))), DisputeResolution::Split);
+
+        // Reputation never overrides a majority, and nobody voting still splits
+        assert_eq!(DisputeResolution::from_votes(1, 2, Some((40, 15))), DisputeResolution::PosterWins);
+        assert_eq!(DisputeResolution::from_votes(0, 0, Some((40, 15))), DisputeResolution::Split);
+    }
+
+    #[test]
+    fn test_votes_wait_for_reveal() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        dispute.commit_selection(108, [1u8; 32]);
+        assert_eq!(
+            dispute.require_revealed(),
+            Err(ProgramError::from(EscrowError::ArbitratorsNotRevealed))
+        );
+
+        let arbitrators = [[4u8; 32]; MAX_PANEL_SIZE];
+        dispute.reveal_arbitrators(arbitrators, 2_000).unwrap();
+        assert!(dispute.require_revealed().is_ok());
+        assert_eq!(dispute.voting_deadline, 2_000);
+
+        // The panel cannot be redrawn
+        assert_eq!(
+            dispute.reveal_arbitrators([[5u8; 32]; MAX_PANEL_SIZE], 3_000),
+            Err(ProgramError::from(EscrowError::ArbitratorsAlreadyRevealed))
+        );
+        assert_eq!(dispute.arbitrators, arbitrators);
+    }
+
+    #[test]
+    fn test_accuracy_bonus_only_from_forfeited_shares() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        let pot = dispute.fund_vote_fees();
+        let share = dispute.vote_fee_share();
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+        dispute.record_vote(1, Vote::ForWorker).unwrap();
+        dispute.record_vote(3, Vote::ForPoster).unwrap();
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+
+        // Two forfeited shares fund a 1.5x reward for the first claimant
+        dispute.claim_vote_fee(&[1u8; 32]).unwrap();
+        let first = dispute.vote_reward(15_000, pot);
+        assert_eq!(first, share * 3 / 2);
+
+        // Once the surplus is gone, later voters still get their full share
+        let mut available = pot - first;
+        for position in [1, 3] {
+            dispute.claim_vote_fee(&[position as u8 + 1; 32]).unwrap();
+            let reward = dispute.vote_reward(15_000, available);
+            assert!(reward >= share);
+            available -= reward;
+        }
+        // Whatever is left of the forfeited shares stays in the case
+        assert_eq!(available, share / 2);
+    }
+
+    #[test]
+    fn test_summary_tracks_votes() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        assert_eq!(dispute.summary(), (0, 0, false, None));
+
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+        dispute.record_vote(1, Vote::ForPoster).unwrap();
+        assert_eq!(dispute.summary(), (1, 1, false, Some(DisputeResolution::Split)));
+
+        dispute.record_vote(2, Vote::ForPoster).unwrap();
+        dispute.record_vote(3, Vote::ForPoster).unwrap();
+        assert_eq!(dispute.summary(), (1, 3, true, Some(DisputeResolution::PosterWins)));
+
+        // Once resolved the stored outcome wins over the projection
+        dispute.resolution = DisputeResolution::Split as u8;
+        assert_eq!(dispute.summary().3, Some(DisputeResolution::Split));
+    }
+
+    #[test]
+    fn test_every_non_pending_resolution_blocks_finalize() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        assert!(!dispute.is_resolved());
+
+        for value in 1..=u8::MAX {
+            dispute.resolution = value;
+            assert!(dispute.is_resolved());
+            // Unknown outcomes are never mapped onto a payout
+            let known = matches!(value, 1..=3);
+            assert_eq!(DisputeResolution::from_u8(value).is_some(), known);
+            assert_eq!(dispute.get_resolution().is_some(), known);
+        }
+    }
+
+    #[test]
+    fn test_panel_size_scales_with_amount() {
+        assert_eq!(DisputeCase::panel_size_for(10_000_000), MIN_PANEL_SIZE);
+        assert_eq!(DisputeCase::panel_size_for(SMALL_PANEL_MAX_AMOUNT - 1), MIN_PANEL_SIZE);
+        assert_eq!(DisputeCase::panel_size_for(SMALL_PANEL_MAX_AMOUNT), ARBITRATORS_PER_DISPUTE);
+        assert_eq!(DisputeCase::panel_size_for(LARGE_PANEL_MIN_AMOUNT - 1), ARBITRATORS_PER_DISPUTE);
+        assert_eq!(DisputeCase::panel_size_for(LARGE_PANEL_MIN_AMOUNT), MAX_PANEL_SIZE);
+    }
+
+    #[test]
+    fn test_majority_follows_panel_size() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = DisputeCase::init(&mut data).unwrap();
+        // Cases without a recorded size keep the default panel
+        assert_eq!(dispute.panel_len(), ARBITRATORS_PER_DISPUTE);
+        assert_eq!(dispute.majority(), ARBITRATION_MAJORITY);
+
+        for (size, majority) in [(MIN_PANEL_SIZE, 2), (ARBITRATORS_PER_DISPUTE, 3), (MAX_PANEL_SIZE, 4)] {
+            let mut data = std::vec![0u8; DisputeCase::SPACE];
+            let dispute = DisputeCase::init(&mut data).unwrap();
+            dispute.panel_size = size as u8;
+            dispute.reveal_arbitrators(core::array::from_fn(|i| [i as u8 + 1; 32]), 1_000).unwrap();
+            assert_eq!(dispute.majority(), majority);
+
+            for position in 0..majority as usize - 1 {
+                dispute.record_vote(position, Vote::ForPoster).unwrap();
+            }
+            assert!(!dispute.has_majority());
+            dispute.record_vote(majority as usize - 1, Vote::ForPoster).unwrap();
+            assert!(dispute.has_majority());
+            assert!(dispute.can_finalize(0, 0));
+
+            // Seats beyond the panel are never voting positions
+            assert_eq!(dispute.find_arbitrator_position(&[size as u8 + 1; 32]), None);
+            assert!(dispute.record_vote(size, Vote::ForWorker).is_err());
+
+            // Vote fees are split across the panel
+            dispute.disputed_amount = 1_000_000_000;
+            dispute.fund_vote_fees();
+            assert_eq!(dispute.vote_fee_pot, size as u64 * DisputeCase::vote_fee_for(1_000_000_000));
+            assert_eq!(dispute.vote_fee_share(), DisputeCase::vote_fee_for(1_000_000_000));
+        }
+    }
+
+    fn commit_reveal_case(data: &mut [u8]) -> &mut DisputeCase {
+        let dispute = DisputeCase::init(data).unwrap();
+        dispute.commit_reveal = 1;
+        dispute.reveal_arbitrators(core::array::from_fn(|i| [i as u8 + 1; 32]), 100_000).unwrap();
+        dispute
+    }
+
+    #[test]
+    fn test_commit_then_reveal_counts_vote() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = commit_reveal_case(&mut data);
+        let reveal_at = dispute.commit_deadline();
+        assert_eq!(reveal_at, 100_000 - VOTE_REVEAL_SECONDS);
+
+        dispute.commit_vote(0, [7u8; 32], 0).unwrap();
+        // Nothing is visible until the reveal
+        assert_eq!(dispute.count_votes(), (0, 0));
+        assert_eq!(dispute.commit_vote(0, [8u8; 32], 0), Err(EscrowError::AlreadyVoted.into()));
+        assert_eq!(
+            dispute.reveal_vote(0, Vote::ForWorker, &[7u8; 32], reveal_at - 1),
+            Err(EscrowError::RevealPhaseNotOpen.into())
+        );
+        assert_eq!(dispute.commit_vote(1, [9u8; 32], reveal_at), Err(EscrowError::CommitPhaseClosed.into()));
+
+        dispute.reveal_vote(0, Vote::ForWorker, &[7u8; 32], reveal_at).unwrap();
+        assert_eq!(dispute.count_votes(), (1, 0));
+        assert_eq!(
+            dispute.reveal_vote(0, Vote::ForWorker, &[7u8; 32], reveal_at),
+            Err(EscrowError::AlreadyVoted.into())
+        );
+
+        // An extension reopens the window, but not for commitments once votes are visible
+        dispute.extend_voting(MAX_VOTING_EXTENSION_SECONDS, reveal_at).unwrap();
+        assert_eq!(dispute.commit_vote(1, [9u8; 32], reveal_at), Err(EscrowError::CommitPhaseClosed.into()));
+        // A committed vote can no longer be handed to a delegate
+        dispute.commitments[2] = [3u8; 32];
+        assert_eq!(dispute.delegate_vote(&[3u8; 32], &[42u8; 32], &[]), Err(EscrowError::AlreadyVoted.into()));
+    }
+
+    #[test]
+    fn test_mismatched_reveal_rejected() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = commit_reveal_case(&mut data);
+        let reveal_at = dispute.commit_deadline();
+
+        dispute.commit_vote(1, [7u8; 32], 0).unwrap();
+        assert_eq!(
+            dispute.reveal_vote(1, Vote::ForPoster, &[8u8; 32], reveal_at),
+            Err(EscrowError::VoteCommitmentMismatch.into())
+        );
+        assert_eq!(
+            dispute.reveal_vote(2, Vote::ForPoster, &[7u8; 32], reveal_at),
+            Err(EscrowError::VoteNotCommitted.into())
+        );
+        assert_eq!(dispute.count_votes(), (0, 0));
+    }
+
+    #[test]
+    fn test_missed_reveal_not_counted() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = commit_reveal_case(&mut data);
+        let reveal_at = dispute.commit_deadline();
+
+        for position in 0..3 {
+            dispute.commit_vote(position, [position as u8 + 10; 32], 0).unwrap();
+        }
+        dispute.reveal_vote(0, Vote::ForPoster, &[10u8; 32], reveal_at).unwrap();
+        dispute.reveal_vote(1, Vote::ForWorker, &[11u8; 32], reveal_at).unwrap();
+
+        // The third arbitrator misses the deadline, so the case ends tied
+        assert_eq!(
+            dispute.reveal_vote(2, Vote::ForWorker, &[12u8; 32], dispute.voting_deadline),
+            Err(EscrowError::VotingDeadlinePassed.into())
+        );
+        assert_eq!(dispute.voter_count(), 2);
+        assert_eq!(dispute.summary(), (1, 1, false, Some(DisputeResolution::Split)));
+    }
+
+    /// Resolved case finalized at `finalized_at` by a 5-seat panel, 2-1 for the worker
+    fn finalized_case(data: &mut [u8], finalized_at: i64) -> &mut DisputeCase {
+        let dispute = case_with_arbitrators(data);
+        dispute.record_vote(0, Vote::ForWorker).unwrap();
+        dispute.record_vote(1, Vote::ForWorker).unwrap();
+        dispute.record_vote(2, Vote::ForPoster).unwrap();
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        dispute.finalized_at = finalized_at;
+        dispute
+    }
+
+    #[test]
+    fn test_appeal_window_and_round_cap() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = finalized_case(&mut data, 1_000);
+        let closes = 1_000 + APPEAL_WINDOW_SECONDS;
+
+        // Only the losing side may appeal; a split may be appealed by either
+        assert!(DisputeResolution::WorkerWins.appealable_by(false));
+        assert!(!DisputeResolution::WorkerWins.appealable_by(true));
+        assert!(DisputeResolution::Split.appealable_by(true) && DisputeResolution::Split.appealable_by(false));
+
+        assert!(dispute.is_appeal_open(closes - 1) && !dispute.is_settled(closes - 1));
+        assert!(!dispute.is_appeal_open(closes) && dispute.is_settled(closes));
+        assert_eq!(
+            dispute.start_appeal(&[9u8; 32], MIN_APPEAL_BOND, closes),
+            Err(EscrowError::NotAppealable.into())
+        );
+
+        dispute.start_appeal(&[9u8; 32], MIN_APPEAL_BOND, closes - 1).unwrap();
+        assert_eq!(dispute.panel_len(), 7);
+        assert_eq!(dispute.prior_panel_len(), 5);
+        assert!(!dispute.is_resolved() && !dispute.is_revealed());
+
+        // The appeal round's result is final once finalized
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        dispute.finalized_at = closes;
+        assert!(!dispute.is_appeal_open(closes) && dispute.is_settled(closes));
+        assert_eq!(
+            dispute.start_appeal(&[9u8; 32], MIN_APPEAL_BOND, closes),
+            Err(EscrowError::NotAppealable.into())
+        );
+    }
+
+    #[test]
+    fn test_appeal_bond_is_clamped() {
+        assert_eq!(DisputeCase::appeal_bond_for(0), MIN_APPEAL_BOND);
+        assert_eq!(DisputeCase::appeal_bond_for(10_000_000_000), 500_000_000);
+        assert_eq!(DisputeCase::appeal_bond_for(1_000_000_000_000), MAX_APPEAL_BOND);
+        assert_eq!(DisputeCase::appeal_panel_size(3), 5);
+        assert_eq!(DisputeCase::appeal_panel_size(7), 7);
+    }
+
+    #[test]
+    fn test_successful_appeal_overturns_and_pays_both_panels() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = finalized_case(&mut data, 1_000);
+        dispute.fund_vote_fees();
+        dispute.start_appeal(&[9u8; 32], MIN_APPEAL_BOND, 1_000).unwrap();
+        dispute.vote_fee_pot += dispute.vote_fee as u64 * dispute.panel_len() as u64;
+        dispute.reveal_arbitrators(core::array::from_fn(|i| [i as u8 + 20; 32]), 100_000).unwrap();
+
+        for position in 0..4 {
+            dispute.record_vote(position, Vote::ForPoster).unwrap();
+        }
+        dispute.resolution = DisputeResolution::from_votes(0, 4, None) as u8;
+        assert!(dispute.appeal_overturned());
+
+        // Prior voters keep their votes, judged against the new outcome
+        assert_eq!(dispute.recorded_vote(&[1u8; 32]), Some(Vote::ForWorker));
+        assert_eq!(dispute.recorded_vote(&[3u8; 32]), Some(Vote::ForPoster));
+        assert_eq!(dispute.recorded_vote(&[20u8; 32]), Some(Vote::ForPoster));
+        assert_eq!(dispute.recorded_vote(&[99u8; 32]), None);
+
+        // Seven votes across both rounds, each paid one fee
+        assert_eq!(dispute.total_voter_count(), 7);
+        assert_eq!(dispute.vote_fee_share(), ARBITRATOR_VOTE_FEE);
+        assert_eq!(dispute.claim_vote_fee(&[1u8; 32]), Ok(ARBITRATOR_VOTE_FEE));
+        assert_eq!(dispute.claim_vote_fee(&[20u8; 32]), Ok(ARBITRATOR_VOTE_FEE));
+        assert_eq!(
+            dispute.claim_vote_fee(&[4u8; 32]),
+            Err(ProgramError::from(EscrowError::ArbitratorDidNotVote))
+        );
+    }
+
+    #[test]
+    fn test_failed_appeal_keeps_outcome() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = finalized_case(&mut data, 1_000);
+        assert!(!dispute.appeal_overturned());
+        dispute.start_appeal(&[9u8; 32], MIN_APPEAL_BOND, 1_000).unwrap();
+        assert!(!dispute.appeal_overturned());
+
+        dispute.resolution = DisputeResolution::WorkerWins as u8;
+        assert!(!dispute.appeal_overturned());
+    }
+
+    #[test]
+    fn test_recusal_shrinks_majority_and_blocks_voting() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.voting_deadline = 1_000;
+        assert_eq!(dispute.majority(), 3);
+
+        dispute.recuse(&[1u8; 32], 0).unwrap();
+        dispute.recuse(&[2u8; 32], 0).unwrap();
+        assert_eq!((dispute.seated_len(), dispute.majority()), (3, 2));
+        assert_eq!(dispute.recuse(&[1u8; 32], 0), Err(EscrowError::ArbitratorRecused.into()));
+        assert_eq!(dispute.record_vote(0, Vote::ForWorker), Err(EscrowError::ArbitratorRecused.into()));
+        assert_eq!(dispute.delegate_vote(&[2u8; 32], &[42u8; 32], &[]), Err(EscrowError::ArbitratorRecused.into()));
+
+        // Voters cannot recuse, and nobody can once voting closes
+        dispute.record_vote(2, Vote::ForPoster).unwrap();
+        assert_eq!(dispute.recuse(&[3u8; 32], 0), Err(EscrowError::AlreadyVoted.into()));
+        assert_eq!(dispute.recuse(&[4u8; 32], 1_000), Err(EscrowError::VotingDeadlinePassed.into()));
+
+        // Two of the three seated arbitrators are a majority; a minimum of
+        // early votes is capped at the three still seated
+        dispute.record_vote(3, Vote::ForPoster).unwrap();
+        assert!(dispute.has_majority() && dispute.can_finalize(0, 2));
+        assert!(!dispute.can_finalize(0, 5));
+
+        // A recused arbitrator is not absent
+        dispute.resolution = DisputeResolution::PosterWins as u8;
+        assert_eq!(dispute.require_absent(0), Err(EscrowError::ArbitratorRecused.into()));
+        assert!(dispute.require_absent(4).is_ok());
+    }
+
+    #[test]
+    fn test_quorum_needed_without_majority() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        assert_eq!(dispute.quorum(), MIN_QUORUM);
+        assert!(!dispute.has_quorum());
+
+        // One vote, or a 1-1 tie, cannot decide a 5-arbitrator case
+        dispute.set_vote(0, Vote::ForWorker);
+        assert!(!dispute.has_quorum());
+        dispute.set_vote(1, Vote::ForPoster);
+        assert!(!dispute.has_quorum());
+        dispute.set_vote(2, Vote::ForPoster);
+        assert!(dispute.has_quorum());
+
+        // A 3-arbitrator panel needs everyone unless two agree
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        let dispute = case_with_arbitrators(&mut data);
+        dispute.panel_size = MIN_PANEL_SIZE as u8;
+        assert_eq!(dispute.quorum(), 3);
+        dispute.set_vote(0, Vote::ForWorker);
+        dispute.set_vote(1, Vote::ForPoster);
+        assert!(!dispute.has_quorum());
+        dispute.set_vote(1, Vote::ForWorker);
+        assert!(dispute.has_quorum());
+    }
 }
@@ -5,6 +5,28 @@
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 use core::mem::size_of;
 use crate::errors::EscrowError;
+use super::CLOSED_ACCOUNT_DISCRIMINATOR;
+
+/// Number of escrow categories (one bit each in an arbitrator's category mask)
+pub const MAX_CATEGORIES: u8 = 32;
+
+/// Maximum number of milestones an escrow can be split into
+pub const MAX_MILESTONES: usize = 8;
+
+/// Times a poster can send submitted work back before approving or disputing
+pub const MAX_REVISIONS: u8 = 3;
+
+/// Longest plaintext job_id stored on an escrow
+pub const MAX_JOB_ID_LEN: usize = 64;
+
+/// Longest an escrow can stay open, counted from creation: 365 days
+pub const MAX_ESCROW_LIFETIME_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+/// Bond a worker locks on assignment, in basis points of a SOL escrow's amount (1%)
+pub const WORKER_BOND_BPS: u64 = 100;
+
+/// Smallest worker bond, and the flat bond on token escrows (0.001 SOL)
+pub const MIN_WORKER_BOND: u64 = 1_000_000;
 
 /// Escrow status values
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -42,6 +64,27 @@ impl EscrowStatus {
     }
 }
 
+/// Who initiated a simple dispute
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum DisputeInitiator {
+    /// No simple dispute initiated
+    None = 0,
+    Poster = 1,
+    Platform = 2,
+    Worker = 3,
+}
+
+/// An agent's part in an escrow, as reported by the get_my_role view
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EscrowRole {
+    /// Neither poster nor worker
+    None = 0,
+    Poster = 1,
+    Worker = 2,
+}
+
 /// Main escrow account
 /// 
 /// Seeds: ["escrow", job_id_hash, poster]
@@ -81,8 +124,49 @@ pub struct JobEscrow {
     pub token_mint: Pubkey,
     /// Escrow token account PDA (zeroed for SOL escrows)
     pub escrow_token_account: Pubkey,
-    /// Padding for alignment
-    pub _padding: [u8; 2],
+    /// Job category, used to match disputes with opted-in arbitrators
+    pub category: u8,
+    /// Number of times work has been submitted for review
+    pub review_cycles: u8,
+    /// Bond the worker must lock on acceptance (lamports, 0 = none)
+    pub worker_deposit: u64,
+    /// Has the worker posted their deposit?
+    pub worker_deposit_posted: u8,
+    /// Longest a dispute case may stay open before the poster can reclaim (seconds, 0 = no cap)
+    pub max_dispute_duration: i64,
+    /// Total already paid out of the escrow (milestone releases and refunds)
+    pub released_amount: u64,
+    /// Amount of each milestone (only the first `milestone_count` are used)
+    pub milestone_amounts: [u64; MAX_MILESTONES],
+    /// Number of milestones (0 = single release of the full amount)
+    pub milestone_count: u8,
+    /// Bitmask of milestones already released
+    pub milestones_released: u8,
+    /// Unix timestamp of the first bid on the job (0 = no bids)
+    pub first_bid_at: i64,
+    /// Worker opted to escalate to arbitration instead of auto-release (0 = off)
+    pub auto_arbitrate: u8,
+    /// Number of times the poster sent submitted work back for revision
+    pub revision_count: u8,
+    /// Unix timestamp by which the worker must submit work (0 = no deadline)
+    pub delivery_deadline: i64,
+    /// Who initiated the simple dispute (DisputeInitiator)
+    pub disputed_by: u8,
+    /// Referrer credited with a share of the platform fee on SOL releases (default = none)
+    pub referrer: Pubkey,
+    /// Extra reputation penalty for abandoning this job, in basis points of
+    /// MAX_CANCELLATION_PENALTY_POINTS (0 = only the standard abandonment penalty)
+    pub worker_cancellation_penalty_bps: u16,
+    /// Lowest reputation score a worker needs to be assigned (0 = no minimum)
+    pub min_worker_score: i64,
+    /// Plaintext job_id, checked against job_id_hash at creation (zero-padded)
+    pub job_id: [u8; MAX_JOB_ID_LEN],
+    /// Length of `job_id` (0 = not stored)
+    pub job_id_len: u8,
+    /// Bond the worker locked on assignment (lamports, 0 = none)
+    pub bond_amount: u64,
+    /// Has the bond left the escrow, back to the worker or forfeited to the poster?
+    pub bond_returned: u8,
 }
 
 impl JobEscrow {
@@ -104,6 +188,9 @@ impl JobEscrow {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         // Verify discriminator
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
@@ -117,6 +204,9 @@ impl JobEscrow {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         // Verify discriminator
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
@@ -130,6 +220,9 @@ impl JobEscrow {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         // Check not already initialized
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
@@ -153,6 +246,38 @@ impl JobEscrow {
         self.worker != Self::DEFAULT_PUBKEY
     }
 
+    /// Plaintext job_id, empty if the poster did not store it
+    #[inline(always)]
+    pub fn job_id(&self) -> &[u8] {
+        &self.job_id[..(self.job_id_len as usize).min(MAX_JOB_ID_LEN)]
+    }
+
+    /// Role `agent` plays in this escrow
+    ///
+    /// An agent who assigned themselves as worker is reported as the poster.
+    #[inline(always)]
+    pub fn role_of(&self, agent: &Pubkey) -> EscrowRole {
+        if agent == &self.poster {
+            EscrowRole::Poster
+        } else if self.has_worker() && agent == &self.worker {
+            EscrowRole::Worker
+        } else {
+            EscrowRole::None
+        }
+    }
+
+    /// Check if a referrer is attributed to this escrow
+    #[inline(always)]
+    pub fn has_referrer(&self) -> bool {
+        self.referrer != Self::DEFAULT_PUBKEY
+    }
+
+    /// Check `score` against the escrow's minimum worker score
+    #[inline(always)]
+    pub fn meets_min_worker_score(&self, score: i64) -> bool {
+        self.min_worker_score == 0 || score >= self.min_worker_score
+    }
+
     /// Check if escrow is in an active state
     #[inline(always)]
     pub fn is_active(&self) -> bool {
@@ -191,6 +316,221 @@ impl JobEscrow {
         }
     }
 
+    /// Check if the worker still owes a required deposit
+    #[inline(always)]
+    pub fn awaiting_worker_deposit(&self) -> bool {
+        self.worker_deposit > 0 && self.worker_deposit_posted == 0
+    }
+
+    /// Take the posted worker deposit for payout, clearing it so it is paid once
+    #[inline(always)]
+    pub fn take_worker_deposit(&mut self) -> u64 {
+        if self.worker_deposit_posted == 0 {
+            return 0;
+        }
+        self.worker_deposit_posted = 0;
+        self.worker_deposit
+    }
+
+    /// Bond a worker must lock to be assigned to this escrow
+    ///
+    /// WORKER_BOND_BPS of a SOL escrow's amount, at least MIN_WORKER_BOND.
+    /// Token amounts are not lamports, so token escrows take the flat minimum.
+    pub fn worker_bond_for_assignment(&self) -> u64 {
+        if self.is_token_escrow() {
+            return MIN_WORKER_BOND;
+        }
+        let share = (self.amount as u128 * WORKER_BOND_BPS as u128 / 10_000) as u64;
+        share.max(MIN_WORKER_BOND)
+    }
+
+    /// Check if the escrow still holds a worker bond
+    #[inline(always)]
+    pub fn holds_worker_bond(&self) -> bool {
+        self.bond_amount > 0 && self.bond_returned == 0
+    }
+
+    /// Record the bond the newly assigned worker locked
+    #[inline(always)]
+    pub fn lock_worker_bond(&mut self, amount: u64) {
+        self.bond_amount = amount;
+        self.bond_returned = 0;
+    }
+
+    /// Take the worker bond for payout, marking it returned so it is paid once
+    #[inline(always)]
+    pub fn take_worker_bond(&mut self) -> u64 {
+        if !self.holds_worker_bond() {
+            return 0;
+        }
+        self.bond_returned = 1;
+        self.bond_amount
+    }
+
+    /// Unassign `worker` when they decline the job before starting it
+    ///
+    /// Only possible while Active and before a deposit was posted; a worker
+    /// who has committed a deposit must abandon instead.
+    pub fn decline_assignment(&mut self, worker: &Pubkey) -> Result<(), ProgramError> {
+        if !self.is_active() {
+            return Err(EscrowError::EscrowNotActive.into());
+        }
+        if !self.has_worker() || &self.worker != worker {
+            return Err(EscrowError::WorkerMismatch.into());
+        }
+        if self.worker_deposit_posted != 0 {
+            return Err(EscrowError::WorkerDepositAlreadyPosted.into());
+        }
+        self.worker = Self::DEFAULT_PUBKEY;
+        Ok(())
+    }
+
+    /// Push the expiry back by `additional_seconds` and return the new expiry
+    ///
+    /// Allowed while Active or PendingReview; the escrow may not outlive
+    /// MAX_ESCROW_LIFETIME_SECONDS from creation.
+    pub fn extend_expiry(&mut self, additional_seconds: i64) -> Result<i64, ProgramError> {
+        if !self.is_active() && !self.is_pending_review() {
+            return Err(EscrowError::EscrowNotActive.into());
+        }
+        if additional_seconds <= 0 {
+            return Err(EscrowError::InvalidExpiry.into());
+        }
+        let expires_at = self
+            .expires_at
+            .checked_add(additional_seconds)
+            .ok_or(EscrowError::ArithmeticOverflow)?;
+        if expires_at > self.created_at.saturating_add(MAX_ESCROW_LIFETIME_SECONDS) {
+            return Err(EscrowError::InvalidExpiry.into());
+        }
+        self.expires_at = expires_at;
+        Ok(expires_at)
+    }
+
+    /// Move to Disputed on behalf of `by`
+    ///
+    /// The poster and platform may dispute while Active or PendingReview. The
+    /// worker may only dispute before submitting; once in review, auto-release
+    /// already protects them.
+    pub fn initiate_dispute(&mut self, by: DisputeInitiator, now: i64) -> Result<(), ProgramError> {
+        let allowed = match by {
+            DisputeInitiator::Worker => self.is_active() && self.has_worker(),
+            DisputeInitiator::Poster | DisputeInitiator::Platform => {
+                self.is_active() || self.is_pending_review()
+            }
+            DisputeInitiator::None => return Err(EscrowError::Unauthorized.into()),
+        };
+        if !allowed {
+            return Err(EscrowError::EscrowNotActive.into());
+        }
+        self.status = EscrowStatus::Disputed as u8;
+        self.dispute_initiated_at = now;
+        self.disputed_by = by as u8;
+        Ok(())
+    }
+
+    /// Check whether a worker raised the current simple dispute
+    ///
+    /// The poster cannot simply wait out the refund timelock on such a
+    /// dispute; it has to be settled by arbitration.
+    #[inline(always)]
+    pub fn disputed_by_worker(&self) -> bool {
+        self.status == EscrowStatus::Disputed as u8
+            && self.disputed_by == DisputeInitiator::Worker as u8
+    }
+
+    /// Check whether a dispute case may be raised on this escrow
+    #[inline(always)]
+    pub fn can_raise_dispute_case(&self) -> bool {
+        self.is_active() || self.is_pending_review() || self.disputed_by_worker()
+    }
+
+    /// Check whether a simple dispute has gone unpursued for `window` seconds
+    ///
+    /// Raising a dispute case moves the escrow to InArbitration, so an escrow
+    /// still Disputed here was never escalated.
+    pub fn dispute_abandoned(&self, now: i64, window: i64) -> bool {
+        self.status == EscrowStatus::Disputed as u8
+            && self.has_worker()
+            && self
+                .get_dispute_initiated_at()
+                .is_some_and(|initiated_at| now >= initiated_at.saturating_add(window))
+    }
+
+    /// Check whether the worker let the delivery deadline pass without submitting
+    ///
+    /// Only an Active escrow that never reached review qualifies; once work
+    /// is submitted the review flow takes over.
+    pub fn missed_delivery(&self, now: i64) -> bool {
+        self.delivery_deadline != 0
+            && self.is_active()
+            && self.review_cycles == 0
+            && now >= self.delivery_deadline
+    }
+
+    /// Add `amount` to the funds held by an active escrow
+    ///
+    /// Not available once a milestone schedule is set, since the milestone
+    /// amounts must sum to the escrow amount.
+    pub fn top_up(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if !self.is_active() {
+            return Err(EscrowError::EscrowNotActive.into());
+        }
+        if amount == 0 {
+            return Err(EscrowError::AmountTooLow.into());
+        }
+        if self.has_milestones() {
+            return Err(EscrowError::InvalidMilestone.into());
+        }
+        self.amount = self.amount.checked_add(amount).ok_or(EscrowError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Clear the assigned worker so the job can be re-listed
+    ///
+    /// Only possible while Active and before the worker has submitted work or
+    /// received a partial release.
+    pub fn unassign_worker(&mut self) -> Result<(), ProgramError> {
+        if !self.is_active() {
+            return Err(EscrowError::EscrowNotActive.into());
+        }
+        if !self.has_worker() {
+            return Err(EscrowError::NoWorkerAssigned.into());
+        }
+        if self.review_cycles != 0 || self.released_amount != 0 {
+            return Err(EscrowError::WorkAlreadySubmitted.into());
+        }
+        self.worker = Self::DEFAULT_PUBKEY;
+        Ok(())
+    }
+
+    /// Move to PendingReview after a work submission
+    #[inline(always)]
+    pub fn mark_submitted(&mut self, now: i64) {
+        self.status = EscrowStatus::PendingReview as u8;
+        self.submitted_at = now;
+        self.review_cycles = self.review_cycles.saturating_add(1);
+    }
+
+    /// Send submitted work back to the worker, reopening the escrow
+    ///
+    /// Clears the submission so the worker submits again; limited to
+    /// MAX_REVISIONS per escrow.
+    pub fn request_revision(&mut self) -> Result<(), ProgramError> {
+        if !self.is_pending_review() {
+            return Err(EscrowError::NotPendingReview.into());
+        }
+        if self.revision_count >= MAX_REVISIONS {
+            return Err(EscrowError::RevisionLimitReached.into());
+        }
+        self.status = EscrowStatus::Active as u8;
+        self.submitted_at = 0;
+        self.proof_hash = [0u8; 32];
+        self.has_proof_hash = 0;
+        self.revision_count += 1;
+        Ok(())
+    }
+
     /// Get submitted_at as Option
     #[inline(always)]
     pub fn get_submitted_at(&self) -> Option<i64> {
@@ -200,6 +540,127 @@ impl JobEscrow {
             Some(self.submitted_at)
         }
     }
+
+    /// Record a bid; only the first one starts the cancel grace
+    #[inline(always)]
+    pub fn record_bid(&mut self, now: i64) {
+        if self.first_bid_at == 0 {
+            self.first_bid_at = now;
+        }
+    }
+
+    /// Check if the poster is still barred from cancelling after the first bid
+    #[inline(always)]
+    pub fn in_cancel_grace(&self, now: i64, grace: i64) -> bool {
+        self.first_bid_at != 0 && now < self.first_bid_at.saturating_add(grace)
+    }
+
+    /// Check if an unanswered review can be escalated to arbitration by anyone
+    ///
+    /// Only when the worker opted in and the poster let the review window lapse.
+    #[inline(always)]
+    pub fn can_auto_escalate(&self, now: i64, review_window: i64) -> bool {
+        self.auto_arbitrate != 0
+            && self.is_pending_review()
+            && self.submitted_at != 0
+            && now >= self.submitted_at.saturating_add(review_window)
+    }
+
+    /// Check if the escrow is split into milestones
+    #[inline(always)]
+    pub fn has_milestones(&self) -> bool {
+        self.milestone_count > 0
+    }
+
+    /// Amount still held by the escrow after milestone releases
+    #[inline(always)]
+    pub fn remaining_amount(&self) -> u64 {
+        self.amount.saturating_sub(self.released_amount)
+    }
+
+    /// Account for paying out everything the escrow still holds
+    ///
+    /// Returns the amount to transfer; any later payout sees nothing remaining.
+    #[inline(always)]
+    pub fn settle_remaining(&mut self) -> u64 {
+        let remaining = self.remaining_amount();
+        self.released_amount = self.amount;
+        remaining
+    }
+
+    /// Split the escrow into milestones; the amounts must sum to the escrow amount
+    pub fn set_milestones(&mut self, amounts: &[u64]) -> Result<(), ProgramError> {
+        if amounts.is_empty() || amounts.len() > MAX_MILESTONES || self.milestones_released != 0 {
+            return Err(EscrowError::InvalidMilestone.into());
+        }
+        let mut total: u64 = 0;
+        for &amount in amounts {
+            if amount == 0 {
+                return Err(EscrowError::InvalidMilestone.into());
+            }
+            total = total.checked_add(amount).ok_or(EscrowError::ArithmeticOverflow)?;
+        }
+        if total != self.amount {
+            return Err(EscrowError::InvalidMilestone.into());
+        }
+
+        self.milestone_amounts = [0; MAX_MILESTONES];
+        self.milestone_amounts[..amounts.len()].copy_from_slice(amounts);
+        self.milestone_count = amounts.len() as u8;
+        Ok(())
+    }
+
+    /// Mark a milestone released and return its amount
+    ///
+    /// The escrow moves to `Released` once every milestone has been paid.
+    pub fn release_milestone(&mut self, index: u8) -> Result<u64, ProgramError> {
+        if index >= self.milestone_count {
+            return Err(EscrowError::InvalidMilestone.into());
+        }
+        let bit = 1u8 << index;
+        if self.milestones_released & bit != 0 {
+            return Err(EscrowError::MilestoneAlreadyReleased.into());
+        }
+
+        let amount = self.milestone_amounts[index as usize];
+        self.released_amount = self
+            .released_amount
+            .checked_add(amount)
+            .ok_or(EscrowError::ArithmeticOverflow)?;
+        self.milestones_released |= bit;
+
+        let all_released = if self.milestone_count as usize == MAX_MILESTONES {
+            u8::MAX
+        } else {
+            (1u8 << self.milestone_count) - 1
+        };
+        if self.milestones_released == all_released {
+            self.status = EscrowStatus::Released as u8;
+        }
+        Ok(amount)
+    }
+
+    /// Record a partial release of `amount` without a preset schedule
+    ///
+    /// The escrow stays `Active` until the full amount has been paid out.
+    pub fn release_partial(&mut self, amount: u64) -> Result<(), ProgramError> {
+        if amount == 0 || self.has_milestones() {
+            return Err(EscrowError::InvalidMilestone.into());
+        }
+        let released = self
+            .released_amount
+            .checked_add(amount)
+            .ok_or(EscrowError::ArithmeticOverflow)?;
+        if released > self.amount {
+            return Err(EscrowError::ReleaseExceedsEscrow.into());
+        }
+
+        self.released_amount = released;
+        if released == self.amount {
+            self.status = EscrowStatus::Released as u8;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -214,4 +675,397 @@ mod tests {
         // Total with discriminator
         assert_eq!(JobEscrow::SPACE, 8 + size_of::<JobEscrow>());
     }
+
+    #[test]
+    fn test_min_worker_score_gate() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        // Unset: anyone can be assigned, even with a negative score
+        assert!(escrow.meets_min_worker_score(-50));
+
+        escrow.min_worker_score = 30;
+        assert!(!escrow.meets_min_worker_score(29));
+        assert!(escrow.meets_min_worker_score(30));
+        assert!(escrow.meets_min_worker_score(100));
+    }
+
+    #[test]
+    fn test_role_of_agent() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.poster = [1u8; 32];
+        assert_eq!(escrow.role_of(&[1u8; 32]), EscrowRole::Poster);
+        // An unassigned escrow has no worker, even for the default key
+        assert_eq!(escrow.role_of(&JobEscrow::DEFAULT_PUBKEY), EscrowRole::None);
+
+        escrow.worker = [2u8; 32];
+        assert_eq!(escrow.role_of(&[2u8; 32]), EscrowRole::Worker);
+        assert_eq!(escrow.role_of(&[3u8; 32]), EscrowRole::None);
+    }
+
+    #[test]
+    fn test_worker_deposit_paid_out_once() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.worker_deposit = 5_000;
+        assert!(escrow.awaiting_worker_deposit());
+        assert_eq!(escrow.take_worker_deposit(), 0);
+
+        escrow.worker_deposit_posted = 1;
+        assert!(!escrow.awaiting_worker_deposit());
+        // Successful completion hands the bond back exactly once
+        assert_eq!(escrow.take_worker_deposit(), 5_000);
+        assert_eq!(escrow.take_worker_deposit(), 0);
+    }
+
+    #[test]
+    fn test_worker_bond_sized_and_paid_out_once() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+
+        // 1% of a SOL escrow, never below the minimum
+        escrow.amount = 2_000_000_000;
+        assert_eq!(escrow.worker_bond_for_assignment(), 20_000_000);
+        escrow.amount = 10_000_000;
+        assert_eq!(escrow.worker_bond_for_assignment(), MIN_WORKER_BOND);
+        escrow.is_token_escrow = 1;
+        escrow.amount = 2_000_000_000;
+        assert_eq!(escrow.worker_bond_for_assignment(), MIN_WORKER_BOND);
+
+        assert!(!escrow.holds_worker_bond());
+        assert_eq!(escrow.take_worker_bond(), 0);
+
+        escrow.lock_worker_bond(MIN_WORKER_BOND);
+        assert!(escrow.holds_worker_bond());
+        assert_eq!(escrow.take_worker_bond(), MIN_WORKER_BOND);
+        assert_eq!(escrow.bond_returned, 1);
+        assert_eq!(escrow.take_worker_bond(), 0);
+
+        // The next assignment locks a fresh bond
+        escrow.lock_worker_bond(MIN_WORKER_BOND);
+        assert!(escrow.holds_worker_bond());
+    }
+
+    #[test]
+    fn test_submissions_count_review_cycles() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        assert_eq!(escrow.review_cycles, 0);
+
+        escrow.mark_submitted(100);
+        assert!(escrow.is_pending_review());
+        assert_eq!(escrow.get_submitted_at(), Some(100));
+
+        // Sent back for changes, then resubmitted
+        escrow.status = EscrowStatus::Active as u8;
+        escrow.mark_submitted(200);
+        assert_eq!(escrow.review_cycles, 2);
+        assert_eq!(escrow.get_submitted_at(), Some(200));
+    }
+
+    #[test]
+    fn test_token_milestones_release_partially() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.is_token_escrow = 1;
+        escrow.amount = 1_000_000;
+
+        // Amounts must cover the escrow exactly
+        assert!(escrow.set_milestones(&[300_000, 300_000]).is_err());
+        escrow.set_milestones(&[300_000, 300_000, 400_000]).unwrap();
+        assert!(escrow.has_milestones());
+
+        // Release two of the three milestones
+        assert_eq!(escrow.release_milestone(0).unwrap(), 300_000);
+        assert_eq!(escrow.release_milestone(2).unwrap(), 400_000);
+        assert!(escrow.release_milestone(2).is_err());
+        assert!(escrow.release_milestone(3).is_err());
+        assert!(escrow.is_active());
+        assert_eq!(escrow.released_amount, 700_000);
+        assert_eq!(escrow.remaining_amount(), 300_000);
+
+        // Schedule is locked once anything has been paid
+        assert!(escrow.set_milestones(&[1_000_000]).is_err());
+
+        // Last milestone completes the escrow
+        assert_eq!(escrow.release_milestone(1).unwrap(), 300_000);
+        assert_eq!(escrow.get_status(), Some(EscrowStatus::Released));
+        assert_eq!(escrow.remaining_amount(), 0);
+    }
+
+    #[test]
+    fn test_partial_releases_until_fully_paid() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.amount = 1_000_000;
+
+        // First half keeps the escrow active
+        escrow.release_partial(500_000).unwrap();
+        assert!(escrow.is_active());
+        assert_eq!(escrow.remaining_amount(), 500_000);
+
+        // Cannot release more than what is left
+        assert!(escrow.release_partial(500_001).is_err());
+        assert_eq!(escrow.released_amount, 500_000);
+
+        // Second half completes the escrow
+        escrow.release_partial(500_000).unwrap();
+        assert_eq!(escrow.get_status(), Some(EscrowStatus::Released));
+        assert_eq!(escrow.remaining_amount(), 0);
+        assert!(escrow.release_partial(1).is_err());
+    }
+
+    #[test]
+    fn test_cancel_grace_after_first_bid() {
+        let grace = 6 * 60 * 60;
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        assert!(!escrow.in_cancel_grace(1_000, grace));
+
+        // Later bids do not extend the grace
+        escrow.record_bid(1_000);
+        escrow.record_bid(5_000);
+        assert_eq!(escrow.first_bid_at, 1_000);
+        assert!(escrow.in_cancel_grace(1_000 + grace - 1, grace));
+
+        // Lapsed with no accepted bid: the poster may cancel again
+        assert!(!escrow.has_worker());
+        assert!(!escrow.in_cancel_grace(1_000 + grace, grace));
+    }
+
+    #[test]
+    fn test_auto_escalation_needs_opt_in_and_lapsed_review() {
+        let window = 24 * 60 * 60;
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.mark_submitted(1_000);
+        assert!(!escrow.can_auto_escalate(1_000 + window, window));
+
+        escrow.auto_arbitrate = 1;
+        assert!(!escrow.can_auto_escalate(1_000 + window - 1, window));
+        assert!(escrow.can_auto_escalate(1_000 + window, window));
+
+        // Once the poster acts the escrow leaves PendingReview
+        escrow.status = EscrowStatus::Released as u8;
+        assert!(!escrow.can_auto_escalate(1_000 + window, window));
+    }
+
+    #[test]
+    fn test_token_cancel_zeroes_remaining_amount() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.is_token_escrow = 1;
+        escrow.amount = 250_000;
+
+        // Cancel refunds everything and leaves nothing to refund twice
+        assert_eq!(escrow.settle_remaining(), 250_000);
+        escrow.status = EscrowStatus::Cancelled as u8;
+        assert_eq!(escrow.remaining_amount(), 0);
+        assert_eq!(escrow.settle_remaining(), 0);
+        assert!(!escrow.is_active());
+    }
+
+    #[test]
+    fn test_worker_declines_before_starting() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        let worker = [5u8; 32];
+        escrow.worker = worker;
+
+        assert_eq!(
+            escrow.decline_assignment(&[6u8; 32]),
+            Err(ProgramError::from(EscrowError::WorkerMismatch))
+        );
+        escrow.decline_assignment(&worker).unwrap();
+        assert!(!escrow.has_worker());
+        assert!(escrow.is_active());
+
+        // Once work is submitted the worker can no longer decline
+        escrow.worker = worker;
+        escrow.mark_submitted(1_000);
+        assert_eq!(
+            escrow.decline_assignment(&worker),
+            Err(ProgramError::from(EscrowError::EscrowNotActive))
+        );
+        assert_eq!(escrow.worker, worker);
+    }
+
+    #[test]
+    fn test_extend_expiry_bounded_by_lifetime() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.created_at = 1_000;
+        escrow.expires_at = 1_000 + 30 * 24 * 60 * 60;
+
+        let extended = escrow.extend_expiry(7 * 24 * 60 * 60).unwrap();
+        assert_eq!(extended, 1_000 + 37 * 24 * 60 * 60);
+        assert_eq!(escrow.expires_at, extended);
+        assert!(escrow.extend_expiry(0).is_err());
+        assert!(escrow.extend_expiry(-1).is_err());
+
+        // Still extendable during review, up to the lifetime cap
+        escrow.mark_submitted(2_000);
+        let remaining = escrow.created_at + MAX_ESCROW_LIFETIME_SECONDS - escrow.expires_at;
+        assert!(escrow.extend_expiry(remaining + 1).is_err());
+        assert_eq!(escrow.extend_expiry(remaining).unwrap(), escrow.created_at + MAX_ESCROW_LIFETIME_SECONDS);
+
+        // Not once the escrow is settled
+        escrow.status = EscrowStatus::Released as u8;
+        assert!(escrow.extend_expiry(1).is_err());
+    }
+
+    #[test]
+    fn test_worker_initiated_dispute() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+
+        // Nobody to raise it yet
+        assert!(escrow.initiate_dispute(DisputeInitiator::Worker, 1_000).is_err());
+
+        escrow.worker = [7u8; 32];
+        escrow.initiate_dispute(DisputeInitiator::Worker, 1_000).unwrap();
+        assert_eq!(escrow.status, EscrowStatus::Disputed as u8);
+        assert_eq!(escrow.dispute_initiated_at, 1_000);
+        assert!(escrow.disputed_by_worker());
+        assert!(escrow.can_raise_dispute_case());
+
+        // A poster-initiated dispute keeps the timelock refund and cannot be escalated
+        escrow.status = EscrowStatus::Active as u8;
+        escrow.initiate_dispute(DisputeInitiator::Poster, 2_000).unwrap();
+        assert!(!escrow.disputed_by_worker());
+        assert!(!escrow.can_raise_dispute_case());
+
+        // In review only the poster or platform may dispute
+        escrow.status = EscrowStatus::Active as u8;
+        escrow.mark_submitted(3_000);
+        assert_eq!(
+            escrow.initiate_dispute(DisputeInitiator::Worker, 3_100),
+            Err(ProgramError::from(EscrowError::EscrowNotActive))
+        );
+        assert!(escrow.initiate_dispute(DisputeInitiator::Platform, 3_100).is_ok());
+    }
+
+    #[test]
+    fn test_dispute_abandoned_after_window() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.worker = [7u8; 32];
+        escrow.status = EscrowStatus::Disputed as u8;
+        escrow.dispute_initiated_at = 1_000;
+
+        assert!(!escrow.dispute_abandoned(1_000 + 99, 100));
+        assert!(escrow.dispute_abandoned(1_000 + 100, 100));
+
+        // Escalated to arbitration: no longer abandoned
+        escrow.status = EscrowStatus::InArbitration as u8;
+        assert!(!escrow.dispute_abandoned(1_000 + 100, 100));
+
+        // Nobody to pay
+        escrow.status = EscrowStatus::Disputed as u8;
+        escrow.worker = JobEscrow::DEFAULT_PUBKEY;
+        assert!(!escrow.dispute_abandoned(1_000 + 100, 100));
+    }
+
+    #[test]
+    fn test_missed_delivery_deadline() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.expires_at = 100_000;
+
+        // No deadline set: never missed before expiry
+        assert!(!escrow.missed_delivery(50_000));
+
+        escrow.delivery_deadline = 10_000;
+        assert!(!escrow.missed_delivery(9_999));
+        assert!(escrow.missed_delivery(10_000));
+
+        // Submitting in time keeps the poster from reclaiming, even after a revision
+        escrow.mark_submitted(9_000);
+        assert!(!escrow.missed_delivery(20_000));
+        escrow.status = EscrowStatus::Active as u8;
+        assert!(!escrow.missed_delivery(20_000));
+    }
+
+    #[test]
+    fn test_top_up_grows_active_escrow() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.amount = 1_000_000;
+
+        escrow.top_up(250_000).unwrap();
+        assert_eq!(escrow.amount, 1_250_000);
+        assert_eq!(escrow.remaining_amount(), 1_250_000);
+        assert!(escrow.top_up(0).is_err());
+
+        // Overflowing u64 is rejected and leaves the amount untouched
+        assert_eq!(
+            escrow.top_up(u64::MAX),
+            Err(ProgramError::from(EscrowError::ArithmeticOverflow))
+        );
+        assert_eq!(escrow.amount, 1_250_000);
+
+        // Rejected once work is under review
+        escrow.mark_submitted(100);
+        assert!(escrow.top_up(250_000).is_err());
+        assert_eq!(escrow.amount, 1_250_000);
+    }
+
+    #[test]
+    fn test_unassign_only_before_submission() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.amount = 1_000_000;
+        assert!(escrow.unassign_worker().is_err());
+
+        // Unassigned worker can be replaced
+        escrow.worker = [7u8; 32];
+        escrow.unassign_worker().unwrap();
+        assert!(!escrow.has_worker());
+
+        // Not once work is under review
+        escrow.worker = [8u8; 32];
+        escrow.mark_submitted(100);
+        assert!(escrow.unassign_worker().is_err());
+
+        // Nor after a revision sent the escrow back to Active
+        escrow.request_revision().unwrap();
+        assert!(escrow.is_active());
+        assert!(escrow.unassign_worker().is_err());
+        assert_eq!(escrow.worker, [8u8; 32]);
+    }
+
+    #[test]
+    fn test_revision_reopens_for_second_submit() {
+        let mut data = std::vec![0u8; JobEscrow::SPACE];
+        let escrow = JobEscrow::init(&mut data).unwrap();
+        escrow.worker = [5u8; 32];
+
+        escrow.mark_submitted(1_000);
+        escrow.proof_hash = [9u8; 32];
+        escrow.has_proof_hash = 1;
+        escrow.request_revision().unwrap();
+        assert!(escrow.is_active());
+        assert_eq!(escrow.get_submitted_at(), None);
+        assert_eq!(escrow.has_proof_hash, 0);
+        assert_eq!(escrow.revision_count, 1);
+
+        // Not pending review any more, so no second request until resubmitted
+        assert_eq!(
+            escrow.request_revision(),
+            Err(ProgramError::from(EscrowError::NotPendingReview))
+        );
+        escrow.mark_submitted(2_000);
+        assert!(escrow.is_pending_review());
+        assert_eq!(escrow.review_cycles, 2);
+
+        // Capped at MAX_REVISIONS
+        for _ in 1..MAX_REVISIONS {
+            escrow.request_revision().unwrap();
+            escrow.mark_submitted(3_000);
+        }
+        assert_eq!(
+            escrow.request_revision(),
+            Err(ProgramError::from(EscrowError::RevisionLimitReached))
+        );
+    }
 }
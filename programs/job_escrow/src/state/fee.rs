@@ -0,0 +1,107 @@
+//! MintFeeConfig account state
+//!
+//! Per-mint override of the platform fee charged on token releases.
+
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+use core::mem::size_of;
+use crate::errors::EscrowError;
+use super::CLOSED_ACCOUNT_DISCRIMINATOR;
+
+/// Platform fee applied when no override is configured: 1%
+pub const DEFAULT_PLATFORM_FEE_BPS: u16 = 100;
+
+/// Highest platform fee that may be configured: 10%
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+/// Basis point denominator
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Platform fee override for one token mint
+///
+/// Seeds: ["mint_fee", mint]
+#[repr(C)]
+pub struct MintFeeConfig {
+    /// Token mint this fee applies to
+    pub mint: Pubkey,
+    /// Unix timestamp of the last update
+    pub updated_at: i64,
+    /// Platform fee in basis points
+    pub fee_bps: u16,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Padding for alignment
+    pub _padding: [u8; 5],
+    /// Smallest fee charged per release, in token base units (0 = none).
+    /// Applied only up to MAX_PLATFORM_FEE_BPS of the released amount
+    pub min_fee: u64,
+}
+
+impl MintFeeConfig {
+    /// Account discriminator (first 8 bytes)
+    pub const DISCRIMINATOR: [u8; 8] = [0x4d, 0x69, 0x6e, 0x74, 0x46, 0x65, 0x65, 0x43]; // "MintFeeC"
+
+    /// Size of the account data (without discriminator)
+    pub const LEN: usize = size_of::<Self>();
+
+    /// Total size including 8-byte discriminator
+    pub const SPACE: usize = 8 + Self::LEN;
+
+    /// Load from account data
+    #[inline(always)]
+    pub fn load(data: &[u8]) -> Result<&Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] != Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountNotInitialized.into());
+        }
+        Ok(unsafe { &*(data[8..].as_ptr() as *const Self) })
+    }
+
+    /// Load mutable reference from account data
+    #[inline(always)]
+    pub fn load_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] != Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountNotInitialized.into());
+        }
+        Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
+    }
+
+    /// Initialize account data with discriminator
+    #[inline(always)]
+    pub fn init(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        if data.len() < Self::SPACE {
+            return Err(EscrowError::InvalidAccountData.into());
+        }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
+        if data[..8] == Self::DISCRIMINATOR {
+            return Err(EscrowError::AccountAlreadyInitialized.into());
+        }
+        data[..8].copy_from_slice(&Self::DISCRIMINATOR);
+        data[8..Self::SPACE].fill(0);
+        Ok(unsafe { &mut *(data[8..].as_mut_ptr() as *mut Self) })
+    }
+
+    /// Fee to charge: the override if one is configured, otherwise the default
+    #[inline(always)]
+    pub fn resolve_fee_bps(config: Option<&Self>) -> u16 {
+        config.map_or(DEFAULT_PLATFORM_FEE_BPS, |c| c.fee_bps)
+    }
+
+    /// Fee floor to apply: the configured one, or none without a config
+    #[inline(always)]
+    pub fn resolve_min_fee(config: Option<&Self>) -> u64 {
+        config.map_or(0, |c| c.min_fee)
+    }
+}
@@ -2,12 +2,72 @@
 //!
 //! All structs use #[repr(C)] for predictable memory layout and zero-copy access.
 
+use pinocchio::program_error::ProgramError;
+use crate::errors::EscrowError;
+
 mod escrow;
 mod reputation;
 mod arbitrator;
 mod dispute;
+mod fee;
+mod config;
 
 pub use escrow::*;
 pub use reputation::*;
 pub use arbitrator::*;
 pub use dispute::*;
+pub use fee::*;
+pub use config::*;
+
+/// Discriminator written into closed accounts
+///
+/// Distinguishes "closed" from "never initialized" (all zeros) when a stale
+/// account is passed back in, and stops `init` from reviving it.
+pub const CLOSED_ACCOUNT_DISCRIMINATOR: [u8; 8] = [0xff; 8];
+
+/// Wipe account data and mark it closed
+#[inline(always)]
+pub fn mark_closed(data: &mut [u8]) -> Result<(), ProgramError> {
+    if data.len() < 8 {
+        return Err(EscrowError::InvalidAccountData.into());
+    }
+    data.fill(0);
+    data[..8].copy_from_slice(&CLOSED_ACCOUNT_DISCRIMINATOR);
+    Ok(())
+}
+
+/// Check whether account data carries the closed sentinel
+#[inline(always)]
+pub fn is_closed(data: &[u8]) -> bool {
+    data.len() >= 8 && data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closed_account_is_distinguishable() {
+        let mut data = std::vec![0u8; DisputeCase::SPACE];
+        DisputeCase::init(&mut data).unwrap();
+        assert!(!is_closed(&data));
+        mark_closed(&mut data).unwrap();
+        assert!(is_closed(&data));
+
+        assert_eq!(
+            DisputeCase::load(&data).err(),
+            Some(ProgramError::from(EscrowError::AccountClosed))
+        );
+        // Closed accounts cannot be revived by re-initializing
+        assert_eq!(
+            DisputeCase::init(&mut data).err(),
+            Some(ProgramError::from(EscrowError::AccountClosed))
+        );
+        // Never-initialized data still reports AccountNotInitialized
+        let fresh = std::vec![0u8; JobEscrow::SPACE];
+        assert_eq!(
+            JobEscrow::load(&fresh).err(),
+            Some(ProgramError::from(EscrowError::AccountNotInitialized))
+        );
+    }
+}
@@ -5,6 +5,22 @@
 use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
 use core::mem::size_of;
 use crate::errors::EscrowError;
+use super::CLOSED_ACCOUNT_DISCRIMINATOR;
+
+/// Idle time before a reputation score starts to decay: 30 days
+pub const REPUTATION_DECAY_GRACE_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Length of one decay period after the grace window: 30 days
+pub const REPUTATION_DECAY_PERIOD_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Share of a positive score kept per idle decay period (90%)
+pub const REPUTATION_DECAY_RETAIN_BPS: i64 = 9_000;
+
+/// Extra score penalty for abandoning a job whose cancellation penalty is 10_000 bps
+pub const MAX_CANCELLATION_PENALTY_POINTS: u64 = 50;
+
+/// Cap on compounded decay periods (a score is effectively zero well before this)
+const MAX_DECAY_PERIODS: i64 = 512;
 
 /// Agent reputation tracking account
 ///
@@ -25,14 +41,28 @@ pub struct AgentReputation {
     pub disputes_won: u64,
     /// Number of disputes lost
     pub disputes_lost: u64,
+    /// Number of jobs assigned as worker
+    pub jobs_assigned: u64,
+    /// Number of assignments abandoned before submitting work
+    pub jobs_abandoned: u64,
     /// Calculated reputation score (can be negative)
     pub reputation_score: i64,
     /// Unix timestamp when reputation was initialized
     pub created_at: i64,
+    /// Unix timestamp of the last completed or posted job (0 = none yet)
+    pub last_active_at: i64,
     /// PDA bump seed
     pub bump: u8,
+    /// Whether the platform paid this account's rent
+    pub is_sponsored: u8,
+    /// Idle decay periods already folded into `reputation_score` by decay_reputation
+    pub decay_periods: u16,
     /// Padding for alignment
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 4],
+    /// Total lamports earned as a referrer from platform fees
+    pub referral_earned: u64,
+    /// Score points lost to escrows' worker cancellation penalties
+    pub cancellation_penalty_points: u64,
 }
 
 impl AgentReputation {
@@ -51,6 +81,9 @@ impl AgentReputation {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -63,6 +96,9 @@ impl AgentReputation {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] != Self::DISCRIMINATOR {
             return Err(EscrowError::AccountNotInitialized.into());
         }
@@ -75,6 +111,9 @@ impl AgentReputation {
         if data.len() < Self::SPACE {
             return Err(EscrowError::InvalidAccountData.into());
         }
+        if data[..8] == CLOSED_ACCOUNT_DISCRIMINATOR {
+            return Err(EscrowError::AccountClosed.into());
+        }
         if data[..8] == Self::DISCRIMINATOR {
             return Err(EscrowError::AccountAlreadyInitialized.into());
         }
@@ -84,7 +123,8 @@ impl AgentReputation {
     }
 
     /// Calculate reputation score based on activity
-    /// Formula: (jobs_completed * 10) + (disputes_won * 5) - (disputes_lost * 10)
+    /// Formula: (jobs_completed * 10) + (disputes_won * 5) - (disputes_lost * 10) - (jobs_abandoned * 5)
+    ///          - cancellation_penalty_points
     /// SECURITY FIX H-05: Use saturating arithmetic to prevent overflow
     #[inline(always)]
     pub fn calculate_score(&self) -> i64 {
@@ -92,14 +132,241 @@ impl AgentReputation {
         let base = (self.jobs_completed as i64).saturating_mul(10);
         let dispute_bonus = (self.disputes_won as i64).saturating_mul(5);
         let dispute_penalty = (self.disputes_lost as i64).saturating_mul(10);
+        let abandon_penalty = (self.jobs_abandoned as i64).saturating_mul(5);
+        let cancellation_penalty = i64::try_from(self.cancellation_penalty_points).unwrap_or(i64::MAX);
         
         // Use saturating arithmetic for the final calculation
-        base.saturating_add(dispute_bonus).saturating_sub(dispute_penalty)
+        base.saturating_add(dispute_bonus)
+            .saturating_sub(dispute_penalty)
+            .saturating_sub(abandon_penalty)
+            .saturating_sub(cancellation_penalty)
+    }
+
+    /// Record a job assignment as worker
+    #[inline(always)]
+    pub fn record_assignment(&mut self) {
+        self.jobs_assigned = self.jobs_assigned.saturating_add(1);
+        self.update_score();
+    }
+
+    /// Record an assignment abandoned before work was submitted
+    ///
+    /// `penalty_bps` is the escrow's worker cancellation penalty, which costs
+    /// that share of MAX_CANCELLATION_PENALTY_POINTS on top of the standard
+    /// abandonment penalty.
+    #[inline(always)]
+    pub fn record_abandonment(&mut self, penalty_bps: u16) {
+        let points = MAX_CANCELLATION_PENALTY_POINTS * penalty_bps.min(10_000) as u64 / 10_000;
+        self.jobs_abandoned = self.jobs_abandoned.saturating_add(1);
+        self.cancellation_penalty_points = self.cancellation_penalty_points.saturating_add(points);
+        self.update_score();
+    }
+
+    /// Share of assigned jobs the worker went on to complete, in basis points
+    ///
+    /// Returns 10_000 (100%) when the agent has never been assigned a job.
+    #[inline(always)]
+    pub fn completion_rate_bps(&self) -> u64 {
+        if self.jobs_assigned == 0 {
+            return 10_000;
+        }
+        let completed = core::cmp::min(self.jobs_completed, self.jobs_assigned);
+        (completed as u128 * 10_000 / self.jobs_assigned as u128) as u64
+    }
+
+    /// Idle decay periods elapsed at `now` since the agent was last active
+    fn idle_decay_periods(&self, now: i64) -> i64 {
+        let last_active = if self.last_active_at != 0 { self.last_active_at } else { self.created_at };
+        let idle = now.saturating_sub(last_active).saturating_sub(REPUTATION_DECAY_GRACE_SECONDS);
+        if idle <= 0 {
+            return 0;
+        }
+        core::cmp::min(idle / REPUTATION_DECAY_PERIOD_SECONDS, MAX_DECAY_PERIODS)
+    }
+
+    /// Score adjusted for inactivity at `now`, without mutating state
+    ///
+    /// After REPUTATION_DECAY_GRACE_SECONDS of inactivity, a positive score
+    /// keeps REPUTATION_DECAY_RETAIN_BPS of its value per elapsed decay
+    /// period, compounded. Periods already applied by decay_reputation are
+    /// not applied again. Negative scores do not decay, and a positive score
+    /// never decays below zero.
+    pub fn effective_score(&self, now: i64) -> i64 {
+        let score = self.reputation_score;
+        if score <= 0 {
+            return score;
+        }
+
+        let periods = self.idle_decay_periods(now).saturating_sub(self.decay_periods as i64);
+        let mut decayed = score as i128;
+        for _ in 0..periods {
+            decayed = decayed * REPUTATION_DECAY_RETAIN_BPS as i128 / 10_000;
+            if decayed == 0 {
+                break;
+            }
+        }
+        decayed as i64
+    }
+
+    /// Fold the inactivity decay at `now` into the stored score
+    ///
+    /// Idempotent within a decay period. The next score update restores the
+    /// undecayed score, as it only happens on activity.
+    pub fn apply_decay(&mut self, now: i64) -> i64 {
+        let periods = self.idle_decay_periods(now);
+        if periods <= self.decay_periods as i64 {
+            return self.reputation_score;
+        }
+        self.reputation_score = self.effective_score(now);
+        self.decay_periods = periods as u16;
+        self.reputation_score
+    }
+
+    /// Check if the platform sponsored this account's rent
+    #[inline(always)]
+    pub fn is_sponsored(&self) -> bool {
+        self.is_sponsored != 0
     }
 
     /// Update the reputation score field
     #[inline(always)]
     pub fn update_score(&mut self) {
         self.reputation_score = self.calculate_score();
+        self.decay_periods = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_reputation(buf: &mut [u8]) -> &mut AgentReputation {
+        AgentReputation::init(buf).unwrap()
+    }
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn test_effective_score_within_grace_matches_stored() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.reputation_score = 100;
+        rep.last_active_at = 1_000;
+        assert_eq!(rep.effective_score(1_000 + REPUTATION_DECAY_GRACE_SECONDS), 100);
+    }
+
+    #[test]
+    fn test_effective_score_decays_after_idle_time() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.reputation_score = 100;
+        rep.last_active_at = 1_000;
+
+        let one_period = 1_000 + REPUTATION_DECAY_GRACE_SECONDS + REPUTATION_DECAY_PERIOD_SECONDS;
+        assert_eq!(rep.effective_score(one_period), 90);
+        assert_eq!(rep.effective_score(one_period + REPUTATION_DECAY_PERIOD_SECONDS), 81);
+        assert_eq!(rep.effective_score(1_000 + 100 * 365 * DAY), 0);
+        // Reading never mutates the stored score
+        assert_eq!(rep.reputation_score, 100);
+    }
+
+    #[test]
+    fn test_effective_score_falls_back_to_created_at_and_skips_negative() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.created_at = 0;
+        rep.reputation_score = 100;
+        let idle = REPUTATION_DECAY_GRACE_SECONDS + REPUTATION_DECAY_PERIOD_SECONDS;
+        assert_eq!(rep.effective_score(idle), 90);
+
+        rep.reputation_score = -20;
+        assert_eq!(rep.effective_score(idle * 10), -20);
+    }
+
+    #[test]
+    fn test_apply_decay_after_six_idle_months() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.jobs_completed = 10;
+        rep.update_score();
+        rep.last_active_at = 1_000;
+        assert_eq!(rep.reputation_score, 100);
+
+        // Six months idle: one grace month, then five decay periods
+        let six_months = 1_000 + 6 * 30 * DAY;
+        assert_eq!(rep.effective_score(six_months), 57);
+        assert_eq!(rep.apply_decay(six_months), 57);
+        assert_eq!(rep.reputation_score, 57);
+        assert_eq!(rep.decay_periods, 5);
+
+        // Cranking again in the same period, or reading, does not decay twice
+        assert_eq!(rep.apply_decay(six_months + DAY), 57);
+        assert_eq!(rep.effective_score(six_months + DAY), 57);
+        // One more month keeps decaying from the stored score
+        assert_eq!(rep.apply_decay(six_months + 30 * DAY), 51);
+
+        // Decay floors at zero, however long the gap
+        assert_eq!(rep.apply_decay(1_000 + 100 * 365 * DAY), 0);
+
+        // Activity restores the undecayed score
+        rep.last_active_at = 1_000 + 100 * 365 * DAY;
+        rep.jobs_completed += 1;
+        rep.update_score();
+        assert_eq!(rep.reputation_score, 110);
+        assert_eq!(rep.effective_score(rep.last_active_at), 110);
+
+        // Negative scores are left alone
+        rep.disputes_lost = 20;
+        rep.update_score();
+        assert_eq!(rep.apply_decay(rep.last_active_at + 365 * DAY), -90);
+    }
+
+    #[test]
+    fn test_completion_rate() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        assert_eq!(rep.completion_rate_bps(), 10_000);
+
+        rep.jobs_assigned = 4;
+        rep.jobs_completed = 3;
+        rep.jobs_abandoned = 1;
+        assert_eq!(rep.completion_rate_bps(), 7_500);
+    }
+
+    #[test]
+    fn test_assignment_and_abandonment_update_counters() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.jobs_completed = 1;
+
+        rep.record_assignment();
+        rep.record_assignment();
+        assert_eq!(rep.jobs_assigned, 2);
+        assert_eq!(rep.reputation_score, 10);
+
+        rep.record_abandonment(0);
+        assert_eq!(rep.jobs_abandoned, 1);
+        assert_eq!(rep.reputation_score, 5);
+        assert_eq!(rep.completion_rate_bps(), 5_000);
+    }
+
+    #[test]
+    fn test_cancellation_penalty_scales_abandonment() {
+        let mut buf = std::vec![0u8; AgentReputation::SPACE];
+        let rep = new_reputation(&mut buf);
+        rep.jobs_completed = 10;
+
+        // A quarter of the maximum penalty on top of the standard 5 points
+        rep.record_abandonment(2_500);
+        assert_eq!(rep.cancellation_penalty_points, 12);
+        assert_eq!(rep.reputation_score, 100 - 5 - 12);
+
+        // The penalty survives later score updates
+        rep.record_assignment();
+        assert_eq!(rep.reputation_score, 83);
+
+        rep.record_abandonment(10_000);
+        assert_eq!(rep.cancellation_penalty_points, 12 + MAX_CANCELLATION_PENALTY_POINTS);
+        assert_eq!(rep.reputation_score, 100 - 10 - 62);
     }
 }
@@ -140,8 +140,10 @@ async function main() {
     .accountsStrict({
       escrow: escrowPDA,
       initiator: poster.publicKey,
+      worker: worker.publicKey,
+      systemProgram: SystemProgram.programId,
     })
-    .signers([poster])
+    .signers([poster, worker])
     .rpc();
   
   // Submit work
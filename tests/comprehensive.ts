@@ -106,6 +106,13 @@ function findEscrowPDA(jobIdHash: Buffer, poster: PublicKey): [PublicKey, number
   );
 }
 
+function findPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    PROGRAM_ID
+  );
+}
+
 function findReputationPDA(agent: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("reputation"), agent.toBuffer()],
@@ -162,6 +169,7 @@ function createEscrowInstruction(
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -181,6 +189,8 @@ function assignWorkerInstruction(
     keys: [
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: initiator, isSigner: true, isWritable: false },
+      { pubkey: worker, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -229,6 +239,7 @@ function releaseToWorkerInstruction(
       { pubkey: platformAuthority, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: platform, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -250,6 +261,7 @@ function approveWorkInstruction(
       { pubkey: poster, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: platform, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -271,6 +283,7 @@ function autoReleaseInstruction(
       { pubkey: cranker, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: platform, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -403,6 +416,7 @@ function releaseWithReputationInstruction(
       { pubkey: platform, isSigner: false, isWritable: true },
       { pubkey: workerReputation, isSigner: false, isWritable: true },
       { pubkey: posterReputation, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -574,7 +588,7 @@ function updateArbitratorAccuracyInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: disputeCase, isSigner: false, isWritable: false },
+      { pubkey: disputeCase, isSigner: false, isWritable: true },
       { pubkey: arbitratorAccount, isSigner: false, isWritable: true },
       { pubkey: accuracyClaim, isSigner: false, isWritable: true },
       { pubkey: caller, isSigner: true, isWritable: true },
@@ -950,7 +964,7 @@ describe("Comprehensive Pinocchio Escrow Tests (All 25 Instructions)", () => {
       tx.feePayer = poster.publicKey;
       tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
 
-      const sig = await sendAndConfirmTransaction(connection, tx, [poster]);
+      const sig = await sendAndConfirmTransaction(connection, tx, [poster, worker]);
       console.log(`      AssignWorker tx: ${sig.slice(0, 20)}...`);
 
       const accountInfo = await connection.getAccountInfo(escrowPDA1);
@@ -996,7 +1010,7 @@ describe("Comprehensive Pinocchio Escrow Tests (All 25 Instructions)", () => {
       const setupTx = new Transaction().add(createIx).add(assignIx);
       setupTx.feePayer = poster.publicKey;
       setupTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      await sendAndConfirmTransaction(connection, setupTx, [poster]);
+      await sendAndConfirmTransaction(connection, setupTx, [poster, worker]);
 
       // ReleaseToWorker
       const ix = releaseToWorkerInstruction(escrowPDA, platformWallet.publicKey, worker.publicKey, PLATFORM_WALLET);
@@ -1184,7 +1198,7 @@ describe("Comprehensive Pinocchio Escrow Tests (All 25 Instructions)", () => {
       const setupTx = new Transaction().add(createIx).add(assignIx);
       setupTx.feePayer = poster.publicKey;
       setupTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      await sendAndConfirmTransaction(connection, setupTx, [poster]);
+      await sendAndConfirmTransaction(connection, setupTx, [poster, worker]);
 
       // Release with reputation update
       const ix = releaseWithReputationInstruction(
@@ -1340,7 +1354,7 @@ describe("Comprehensive Pinocchio Escrow Tests (All 25 Instructions)", () => {
       const assignTx = new Transaction().add(assignIx);
       assignTx.feePayer = poster.publicKey;
       assignTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      await sendAndConfirmTransaction(connection, assignTx, [poster]);
+      await sendAndConfirmTransaction(connection, assignTx, [poster, worker]);
 
       // Step 3: Submit work (puts escrow in PendingReview state)
       const submitIx = submitWorkInstruction(disputeEscrowPDA, worker.publicKey);
@@ -92,6 +92,13 @@ function findEscrowPDA(jobIdHash: Buffer, poster: PublicKey): [PublicKey, number
   );
 }
 
+function findPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    PROGRAM_ID
+  );
+}
+
 function findReputationPDA(agent: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("reputation"), agent.toBuffer()],
@@ -152,6 +159,7 @@ function createEscrowInstruction(
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -175,6 +183,8 @@ function assignWorkerInstruction(
     keys: [
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: initiator, isSigner: true, isWritable: false },
+      { pubkey: worker, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -231,6 +241,7 @@ function approveWorkInstruction(
       { pubkey: poster, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: platform, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -610,7 +621,7 @@ describe("job_escrow (Pinocchio)", () => {
       tx.feePayer = poster.publicKey;
       tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
 
-      const sig = await sendAndConfirmTransaction(connection, tx, [poster]);
+      const sig = await sendAndConfirmTransaction(connection, tx, [poster, worker]);
       console.log(`    AssignWorker tx: ${sig}`);
 
       const accountInfo = await connection.getAccountInfo(escrowPDA);
@@ -684,7 +695,7 @@ describe("job_escrow (Pinocchio)", () => {
       const assignTx = new Transaction().add(assignIx);
       assignTx.feePayer = poster.publicKey;
       assignTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      await sendAndConfirmTransaction(connection, assignTx, [poster]);
+      await sendAndConfirmTransaction(connection, assignTx, [poster, worker]);
 
       console.log(`    Created and assigned worker for Phase 1 escrow`);
     });
@@ -813,7 +824,7 @@ describe("job_escrow (Pinocchio)", () => {
       const assignTx = new Transaction().add(assignIx);
       assignTx.feePayer = poster.publicKey;
       assignTx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
-      await sendAndConfirmTransaction(connection, assignTx, [poster]);
+      await sendAndConfirmTransaction(connection, assignTx, [poster, worker]);
 
       // Submit (without proof for variety)
       const submitIx = submitWorkInstruction(escrowPDA, worker.publicKey);
@@ -48,6 +48,13 @@ function findEscrowPDA(jobIdHash: Buffer, poster: PublicKey): [PublicKey, number
   );
 }
 
+function findPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    PROGRAM_ID
+  );
+}
+
 // ==================== INSTRUCTION BUILDERS ====================
 
 function createTokenEscrowInstruction(
@@ -75,6 +82,7 @@ function createTokenEscrowInstruction(
       { pubkey: escrowTokenAccount, isSigner: false, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -94,6 +102,8 @@ function assignWorkerInstruction(
     keys: [
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: false },
+      { pubkey: worker, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -268,7 +278,7 @@ describe("SPL Token Escrow", function () {
     it("assigns a worker to token escrow", async function () {
       const ix = assignWorkerInstruction(escrow, poster.publicKey, worker.publicKey);
       const tx = new Transaction().add(ix);
-      const sig = await sendAndConfirmTransaction(connection, tx, [poster]);
+      const sig = await sendAndConfirmTransaction(connection, tx, [poster, worker]);
       console.log("    AssignWorker tx:", sig);
     });
 
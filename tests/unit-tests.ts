@@ -61,6 +61,13 @@ function findEscrowPDA(jobIdHash: Buffer, poster: PublicKey): [PublicKey, number
   );
 }
 
+function findPlatformConfigPDA(): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("platform_config")],
+    PROGRAM_ID
+  );
+}
+
 function findReputationPDA(agent: PublicKey): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
     [Buffer.from("reputation"), agent.toBuffer()],
@@ -99,6 +106,7 @@ function createEscrowInstruction(
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: poster, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -118,6 +126,8 @@ function assignWorkerInstruction(
     keys: [
       { pubkey: escrow, isSigner: false, isWritable: true },
       { pubkey: initiator, isSigner: true, isWritable: false },
+      { pubkey: worker, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -166,6 +176,7 @@ function approveWorkInstruction(
       { pubkey: poster, isSigner: true, isWritable: false },
       { pubkey: worker, isSigner: false, isWritable: true },
       { pubkey: platform, isSigner: false, isWritable: true },
+      { pubkey: findPlatformConfigPDA()[0], isSigner: false, isWritable: false },
     ],
     programId: PROGRAM_ID,
     data,
@@ -270,12 +281,14 @@ describe("Unit Tests - Pinocchio Escrow", () => {
       expect(ix.programId.equals(PROGRAM_ID)).to.be.true;
 
       // Check accounts
-      expect(ix.keys.length).to.equal(3);
+      expect(ix.keys.length).to.equal(4);
       expect(ix.keys[0].pubkey.equals(escrowPDA)).to.be.true;
       expect(ix.keys[0].isWritable).to.be.true;
       expect(ix.keys[1].pubkey.equals(poster.publicKey)).to.be.true;
       expect(ix.keys[1].isSigner).to.be.true;
       expect(ix.keys[2].pubkey.equals(SystemProgram.programId)).to.be.true;
+      expect(ix.keys[3].pubkey.equals(findPlatformConfigPDA()[0])).to.be.true;
+      expect(ix.keys[3].isWritable).to.be.false;
 
       // Check data format
       expect(ix.data.length).to.equal(1 + 32 + 8 + 8);
@@ -293,7 +306,10 @@ describe("Unit Tests - Pinocchio Escrow", () => {
       const ix = assignWorkerInstruction(escrowPDA, poster.publicKey, worker.publicKey);
 
       expect(ix.programId.equals(PROGRAM_ID)).to.be.true;
-      expect(ix.keys.length).to.equal(2);
+      expect(ix.keys.length).to.equal(4);
+      expect(ix.keys[2].pubkey.equals(worker.publicKey)).to.be.true;
+      expect(ix.keys[2].isSigner).to.be.true;
+      expect(ix.keys[3].pubkey.equals(SystemProgram.programId)).to.be.true;
       expect(ix.data[0]).to.equal(DISCRIMINATORS.AssignWorker);
       expect(ix.data.length).to.equal(1 + 32);
       
@@ -326,7 +342,7 @@ describe("Unit Tests - Pinocchio Escrow", () => {
       const ix = approveWorkInstruction(escrowPDA, poster.publicKey, worker.publicKey, PLATFORM_WALLET);
 
       expect(ix.programId.equals(PROGRAM_ID)).to.be.true;
-      expect(ix.keys.length).to.equal(4);
+      expect(ix.keys.length).to.equal(5);
       expect(ix.data[0]).to.equal(DISCRIMINATORS.ApproveWork);
       expect(ix.data.length).to.equal(1);
       
@@ -336,6 +352,7 @@ describe("Unit Tests - Pinocchio Escrow", () => {
       expect(ix.keys[1].isSigner).to.be.true;
       expect(ix.keys[2].pubkey.equals(worker.publicKey)).to.be.true;
       expect(ix.keys[3].pubkey.equals(PLATFORM_WALLET)).to.be.true;
+      expect(ix.keys[4].pubkey.equals(findPlatformConfigPDA()[0])).to.be.true;
     });
 
     it("builds CancelEscrow instruction correctly", () => {